@@ -0,0 +1,55 @@
+//! Benchmarks of 128-bit arithmetic operations.
+//!
+//! ```text
+//! cargo bench --bench arithmetic
+//! ```
+
+use decimus::{BID_ROUNDING_TO_NEAREST, BidUint128, IdecFlags, IdecRound, bid128_add, bid128_div, bid128_from_string, bid128_mul};
+use std::hint::black_box;
+use std::time::Instant;
+
+/// Number of passes over all operand pairs in a single round.
+const PASSES: usize = 20_000;
+
+/// Number of measured rounds, the fastest round is reported.
+const ROUNDS: usize = 7;
+
+/// Operands covering short and full-precision coefficients with various exponents.
+const OPERANDS: [&str; 8] = [
+  "1",
+  "-7.50",
+  "123456789012345678",
+  "3.14159265358979323846264338327950",
+  "9999999999999999999999999999999999",
+  "-2.718281828459045235360287471352662E-100",
+  "1.000000000000000000000000000000001E+200",
+  "0.000000000000000000000000000000000000001",
+];
+
+type Operation = fn(BidUint128, BidUint128, IdecRound, &mut IdecFlags) -> BidUint128;
+
+fn bench(name: &str, operands: &[BidUint128], operation: Operation) {
+  let mut flags: IdecFlags = 0;
+  let mut best = f64::MAX;
+  for _ in 0..ROUNDS {
+    let start = Instant::now();
+    for _ in 0..PASSES {
+      for x in operands {
+        for y in operands {
+          black_box(operation(black_box(*x), black_box(*y), BID_ROUNDING_TO_NEAREST, &mut flags));
+        }
+      }
+    }
+    let count = PASSES * operands.len() * operands.len();
+    best = best.min(start.elapsed().as_nanos() as f64 / count as f64);
+  }
+  println!("{name:<12} {best:>8.1} ns/op");
+}
+
+fn main() {
+  let mut flags: IdecFlags = 0;
+  let operands: Vec<BidUint128> = OPERANDS.iter().map(|s| bid128_from_string(s, BID_ROUNDING_TO_NEAREST, &mut flags)).collect();
+  bench("bid128_add", &operands, bid128_add);
+  bench("bid128_mul", &operands, bid128_mul);
+  bench("bid128_div", &operands, bid128_div);
+}
@@ -551,7 +551,7 @@ pub fn bid128_add(mut x: BidUint128, mut y: BidUint128, rounding: IdecRound, fla
                   let c1copy = c1;
                   mul_128x64_to_128!(c1, bid_ten2k64!(P34 - q1), c1copy);
                 }
-                x_exp = x_exp.wrapping_add((scale as u64) << 49);
+                x_exp = x_exp.wrapping_sub((scale as u64) << 49);
                 c1_hi = c1.w[1];
                 c1_lo = c1.w[0];
               }
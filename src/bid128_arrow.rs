@@ -0,0 +1,230 @@
+//! # Conversions between decimal floating-point values and Apache Arrow decimal buffers
+//!
+//! Arrow `Decimal128(precision, scale)` and `Decimal256(precision, scale)` arrays store values
+//! as signed 128-bit and 256-bit integers scaled by `10^scale`, in little-endian two's complement,
+//! with a separate validity bitmap, where bit `i` (least significant bit first) is set when element `i` is not null.
+//! The functions below convert such buffers without depending on the arrow crate,
+//! 256-bit integers are represented as 32 bytes, equal to `i256::to_le_bytes` in the arrow crate.
+
+use crate::BidUint128;
+use crate::bid_conf::{IdecFlags, IdecRound};
+use crate::bid_functions::*;
+use crate::bid_internal::*;
+use crate::bid128_common::*;
+use crate::bid128_parts::{MAX_DIGITS_U128, bid_round_u128, bid128_from_parts, bid128_to_i128_scaled, bid128_to_parts};
+
+/// Maximum precision of Arrow `Decimal128` type.
+const MAX_PRECISION_128: u8 = 38;
+
+/// Maximum precision of Arrow `Decimal256` type.
+const MAX_PRECISION_256: u8 = 76;
+
+/// Sets or clears the bit of element `i` in the validity bitmap.
+fn bid_set_valid(validity: &mut [u8], i: usize, valid: bool) {
+  if valid {
+    validity[i / 8] |= 1 << (i % 8);
+  } else {
+    validity[i / 8] &= !(1 << (i % 8));
+  }
+}
+
+/// Checks the lengths of output buffers.
+fn bid_check_lengths(len: usize, output_len: usize, validity_len: usize) {
+  assert!(output_len >= len, "output buffer is too small");
+  assert!(validity_len >= len.div_ceil(8), "validity bitmap is too small");
+}
+
+/// Divides 256-bit unsigned integer by 10, returns the remainder.
+fn bid_u256_div10(x: &mut [u64; 4]) -> u64 {
+  let mut remainder: u128 = 0;
+  for word in x.iter_mut().rev() {
+    let dividend = remainder << 64 | *word as u128;
+    *word = (dividend / 10) as u64;
+    remainder = dividend % 10;
+  }
+  remainder as u64
+}
+
+/// Multiplies 256-bit unsigned integer by 64-bit unsigned integer, the result must fit in 256 bits.
+fn bid_u256_mul_u64(x: &mut [u64; 4], y: u64) {
+  let mut carry: u128 = 0;
+  for word in x.iter_mut() {
+    let product = *word as u128 * y as u128 + carry;
+    *word = product as u64;
+    carry = product >> 64;
+  }
+}
+
+/// Negates 256-bit integer in two's complement.
+fn bid_u256_negate(x: &mut [u64; 4]) {
+  let mut carry = true;
+  for word in x.iter_mut() {
+    (*word, carry) = (!*word).overflowing_add(carry as u64);
+  }
+}
+
+/// Converts 128-bit decimal floating-point values to Arrow `Decimal128(precision, scale)` buffer,
+/// rounding the values according to the rounding mode.
+///
+/// Infinities, NaNs and values that do not fit in the precision are converted to zero
+/// and marked as null in the validity bitmap, returns the number of null elements.
+///
+/// # Panics
+///
+/// Panics when the precision is not in range 1 to 38, or when the output buffer
+/// or validity bitmap is too small for all input values.
+///
+/// # Examples
+///
+/// ```
+/// use decimus::{BID_ROUNDING_TO_NEAREST, BidUint128, bid128_to_decimal128_array};
+///
+/// let input = [
+///   BidUint128 { w: [12345, 0x303c000000000000] }, // 123.45
+///   BidUint128 { w: [0, 0x7c00000000000000] },     // NaN
+/// ];
+/// let mut output = [0; 2];
+/// let mut validity = [0; 1];
+/// let mut flags = 0;
+/// let nulls = bid128_to_decimal128_array(&input, 10, 3, BID_ROUNDING_TO_NEAREST, &mut output, &mut validity, &mut flags);
+/// assert_eq!(1, nulls);
+/// assert_eq!([123450, 0], output);
+/// assert_eq!([0b01], validity);
+/// ```
+pub fn bid128_to_decimal128_array(input: &[BidUint128], precision: u8, scale: i8, rounding: IdecRound, output: &mut [i128], validity: &mut [u8], flags: &mut IdecFlags) -> usize {
+  assert!((1..=MAX_PRECISION_128).contains(&precision), "invalid precision of Decimal128");
+  bid_check_lengths(input.len(), output.len(), validity.len());
+  let limit = 10_u128.pow(precision as u32);
+  let mut nulls = 0;
+  for (i, x) in input.iter().enumerate() {
+    let value = bid128_to_i128_scaled(*x, scale as i32, rounding, flags).filter(|value| value.unsigned_abs() < limit);
+    if value.is_none() {
+      if cfg!(feature = "bid-set-status-flags") {
+        set_status_flags!(flags, BID_INVALID_EXCEPTION);
+      }
+      nulls += 1;
+    }
+    output[i] = value.unwrap_or(0);
+    bid_set_valid(validity, i, value.is_some());
+  }
+  nulls
+}
+
+/// Converts Arrow `Decimal128(precision, scale)` buffer to 128-bit decimal floating-point values,
+/// values with more than 34 digits are rounded according to the rounding mode.
+///
+/// Null elements are converted like all other elements.
+///
+/// # Panics
+///
+/// Panics when the output buffer is too small for all input values.
+pub fn bid128_from_decimal128_array(input: &[i128], scale: i8, rounding: IdecRound, output: &mut [BidUint128], flags: &mut IdecFlags) {
+  assert!(output.len() >= input.len(), "output buffer is too small");
+  for (x, y) in input.iter().zip(output.iter_mut()) {
+    *y = bid128_from_parts(*x < 0, x.unsigned_abs(), -(scale as i32), rounding, flags);
+  }
+}
+
+/// Converts a 128-bit decimal floating-point value to 256-bit integer scaled by `10^scale`,
+/// returns `None` for infinities, NaNs and values that do not fit in the precision.
+fn bid128_to_i256_scaled(x: BidUint128, precision: u8, scale: i8, rounding: IdecRound, flags: &mut IdecFlags) -> Option<[u8; 32]> {
+  let (sign, coefficient, exponent) = bid128_to_parts(x);
+  if (x.w[1] & MASK_INF) == MASK_INF {
+    return None;
+  }
+  let k = exponent + scale as i32;
+  let mut value = if k >= 0 {
+    if coefficient == 0 {
+      return Some([0; 32]);
+    }
+    if (coefficient.ilog10() + 1) as i32 + k > precision as i32 {
+      return None;
+    }
+    let mut value = [coefficient as u64, (coefficient >> 64) as u64, 0, 0];
+    for _ in 0..k {
+      bid_u256_mul_u64(&mut value, 10);
+    }
+    value
+  } else {
+    let (quotient, inexact) = bid_round_u128(coefficient, (-k).min(MAX_DIGITS_U128 as i32) as u32, sign, rounding);
+    if cfg!(feature = "bid-set-status-flags") && inexact {
+      set_status_flags!(flags, BID_INEXACT_EXCEPTION);
+    }
+    if quotient != 0 && quotient.ilog10() + 1 > precision as u32 {
+      return None;
+    }
+    [quotient as u64, (quotient >> 64) as u64, 0, 0]
+  };
+  if sign {
+    bid_u256_negate(&mut value);
+  }
+  let mut bytes = [0; 32];
+  for (chunk, word) in bytes.chunks_exact_mut(8).zip(value) {
+    chunk.copy_from_slice(&word.to_le_bytes());
+  }
+  Some(bytes)
+}
+
+/// Converts 128-bit decimal floating-point values to Arrow `Decimal256(precision, scale)` buffer
+/// of 256-bit integers stored as 32 bytes in little-endian order, rounding the values according to the rounding mode.
+///
+/// Infinities, NaNs and values that do not fit in the precision are converted to zero
+/// and marked as null in the validity bitmap, returns the number of null elements.
+///
+/// # Panics
+///
+/// Panics when the precision is not in range 1 to 76, or when the output buffer
+/// or validity bitmap is too small for all input values.
+pub fn bid128_to_decimal256_array(input: &[BidUint128], precision: u8, scale: i8, rounding: IdecRound, output: &mut [[u8; 32]], validity: &mut [u8], flags: &mut IdecFlags) -> usize {
+  assert!((1..=MAX_PRECISION_256).contains(&precision), "invalid precision of Decimal256");
+  bid_check_lengths(input.len(), output.len(), validity.len());
+  let mut nulls = 0;
+  for (i, x) in input.iter().enumerate() {
+    let value = bid128_to_i256_scaled(*x, precision, scale, rounding, flags);
+    if value.is_none() {
+      if cfg!(feature = "bid-set-status-flags") {
+        set_status_flags!(flags, BID_INVALID_EXCEPTION);
+      }
+      nulls += 1;
+    }
+    output[i] = value.unwrap_or([0; 32]);
+    bid_set_valid(validity, i, value.is_some());
+  }
+  nulls
+}
+
+/// Converts Arrow `Decimal256(precision, scale)` buffer of 256-bit integers stored as 32 bytes
+/// in little-endian order to 128-bit decimal floating-point values,
+/// values with more than 34 digits are rounded according to the rounding mode.
+///
+/// Null elements are converted like all other elements.
+///
+/// # Panics
+///
+/// Panics when the output buffer is too small for all input values.
+pub fn bid128_from_decimal256_array(input: &[[u8; 32]], scale: i8, rounding: IdecRound, output: &mut [BidUint128], flags: &mut IdecFlags) {
+  assert!(output.len() >= input.len(), "output buffer is too small");
+  for (x, y) in input.iter().zip(output.iter_mut()) {
+    let mut value = [0; 4];
+    for (word, chunk) in value.iter_mut().zip(x.as_chunks::<8>().0) {
+      *word = u64::from_le_bytes(*chunk);
+    }
+    let sign = (value[3] >> 63) != 0;
+    if sign {
+      bid_u256_negate(&mut value);
+    }
+    // Digits that do not fit in 128 bits are removed, keeping at least 38 digits, non-zero removed digits
+    // make the last digit non-zero (sticky digit), so the final rounding to 34 digits is not affected.
+    let mut exponent = -(scale as i32);
+    let mut sticky = false;
+    while value[2] | value[3] != 0 {
+      sticky |= bid_u256_div10(&mut value) != 0;
+      exponent += 1;
+    }
+    let mut coefficient = (value[1] as u128) << 64 | value[0] as u128;
+    if sticky && coefficient.is_multiple_of(10) {
+      coefficient += 1;
+    }
+    *y = bid128_from_parts(sign, coefficient, exponent, rounding, flags);
+  }
+}
@@ -0,0 +1,111 @@
+//! # Interoperability with BSON Decimal128
+//!
+//! BSON Decimal128 type is IEEE 754 decimal128 value in binary encoding (BID)
+//! stored in little-endian byte order, so the conversion of bytes is lossless.
+//! BSON specification defines its own string format, which is the to-scientific-string
+//! conversion of the General Decimal Arithmetic Specification, where all NaNs
+//! are represented as `NaN`, and its own rules for parsing strings,
+//! where values that can not be represented exactly are rejected.
+
+use crate::BidUint128;
+use crate::bid128_common::*;
+use crate::bid128_format::bid128_write_sci_string;
+use crate::bid128_noncomp::{bid128_is_inf, bid128_is_nan};
+use crate::bid128_string::{CharSequence, ParseDecimalError, ParseDecimalErrorKind, bid_from_string_const, bid_special_length, bid_validate_number};
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+/// Converts BSON Decimal128 value stored in 16 bytes in little-endian order
+/// to 128-bit decimal floating-point value (binary encoding).
+///
+/// # Examples
+///
+/// ```
+/// use decimus::{BidUint128, bid128_from_bson};
+///
+/// let bytes = [0xd2, 0x04, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x34, 0x30];
+/// assert_eq!(BidUint128 { w: [1234, 0x3034000000000000] }, bid128_from_bson(bytes));
+/// ```
+pub const fn bid128_from_bson(bytes: [u8; 16]) -> BidUint128 {
+  BidUint128::from_le_bytes(bytes)
+}
+
+/// Converts a 128-bit decimal floating-point value (binary encoding)
+/// to BSON Decimal128 value stored in 16 bytes in little-endian order.
+pub const fn bid128_to_bson(x: BidUint128) -> [u8; 16] {
+  x.to_le_bytes()
+}
+
+/// Writes a 128-bit decimal floating-point value in the string format required by BSON specification,
+/// like `0.001234`, `1.050E+4`, `-Infinity` or `NaN`.
+///
+/// Finite values are written as specified by the to-scientific-string conversion,
+/// see [bid128_write_sci_string], NaNs are written as `NaN` regardless of the sign, payload
+/// and signaling bit, non-canonical values are written as zero.
+///
+/// # Examples
+///
+/// ```
+/// use decimus::{BidUint128, bid128_write_bson_string};
+///
+/// let mut s = String::new();
+/// bid128_write_bson_string(BidUint128 { w: [1234, 0x3034000000000000] }, &mut s).unwrap();
+/// assert_eq!("0.001234", s);
+/// ```
+pub fn bid128_write_bson_string<W: fmt::Write>(x: BidUint128, writer: &mut W) -> fmt::Result {
+  if bid128_is_nan(x) {
+    return writer.write_str("NaN");
+  }
+  if bid128_is_inf(x) {
+    return writer.write_str(if (x.w[1] & MASK_SIGN) != 0 { "-Infinity" } else { "Infinity" });
+  }
+  bid128_write_sci_string(x, writer)
+}
+
+/// Converts a 128-bit decimal floating-point value (binary encoding)
+/// to string in the format required by BSON specification, see [bid128_write_bson_string].
+#[cfg(feature = "alloc")]
+pub fn bid128_to_bson_string(x: BidUint128) -> String {
+  let mut s = String::new();
+  let _ = bid128_write_bson_string(x, &mut s);
+  s
+}
+
+/// Converts a value represented in string format to 128-bit decimal floating-point value (binary encoding)
+/// following the rules of BSON specification.
+///
+/// Accepted are numbers with optional sign, radix point and exponent,
+/// and case-insensitive `Inf`, `Infinity` and `NaN` with optional sign.
+/// Signaling NaNs and NaN payloads are not allowed. Values that can not be represented exactly
+/// are rejected with [ParseDecimalErrorKind::Inexact], exponents out of range are clamped
+/// only when the value does not change, like `1E6112` parsed as `1.0E+6112`.
+///
+/// # Examples
+///
+/// ```
+/// use decimus::{BidUint128, ParseDecimalErrorKind, bid128_from_bson_string};
+///
+/// assert_eq!(Ok(BidUint128 { w: [1234, 0x3034000000000000] }), bid128_from_bson_string("0.001234"));
+/// let err = bid128_from_bson_string("12345678901234567890123456789012345").unwrap_err();
+/// assert_eq!(ParseDecimalErrorKind::Inexact, err.kind());
+/// ```
+pub fn bid128_from_bson_string(input: &str) -> Result<BidUint128, ParseDecimalError> {
+  let s = input.as_bytes();
+  let ps = CharSequence::new(s);
+  bid_validate_number(&ps)?;
+  let p = (s[0] == b'-' || s[0] == b'+') as usize;
+  if let Some((keyword, payload)) = bid_special_length(&ps, p) {
+    if s[p].eq_ignore_ascii_case(&b's') {
+      return Err(ParseDecimalError::new(ParseDecimalErrorKind::InvalidDigit, p));
+    }
+    if payload > 0 {
+      return Err(ParseDecimalError::new(ParseDecimalErrorKind::TrailingCharacters, p + keyword));
+    }
+  }
+  match bid_from_string_const(s) {
+    (_, true) => Err(ParseDecimalError::new(ParseDecimalErrorKind::Inexact, 0)),
+    (value, false) => Ok(value),
+  }
+}
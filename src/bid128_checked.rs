@@ -0,0 +1,254 @@
+//! # Alternate exception handling
+
+use crate::bid_conf::*;
+use crate::bid_decimal_data::*;
+use crate::bid_functions::*;
+use crate::bid_internal::*;
+use crate::bid128_add::{bid128_add, bid128_sub};
+use crate::bid128_common::*;
+use crate::bid128_div::bid128_div;
+use crate::bid128_fma::bid128_fma_with_tininess;
+use crate::bid128_mul::bid128_mul_with_tininess;
+use crate::bid128_noncomp::*;
+use crate::{BidUint64, BidUint128};
+use core::fmt;
+
+/// Operation that raised a trapped exception, together with its operands.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Operation {
+  /// Addition `x + y`.
+  Add(BidUint128, BidUint128),
+  /// Subtraction `x - y`.
+  Sub(BidUint128, BidUint128),
+  /// Multiplication `x * y`.
+  Mul(BidUint128, BidUint128),
+  /// Division `x / y`.
+  Div(BidUint128, BidUint128),
+  /// Fused multiply-add `x * y + z`.
+  Fma(BidUint128, BidUint128, BidUint128),
+}
+
+impl Operation {
+  /// Returns the name of the operation.
+  pub fn name(&self) -> &'static str {
+    match self {
+      Operation::Add(..) => "addition",
+      Operation::Sub(..) => "subtraction",
+      Operation::Mul(..) => "multiplication",
+      Operation::Div(..) => "division",
+      Operation::Fma(..) => "fused multiply-add",
+    }
+  }
+
+  /// Returns `true` if any operand satisfies the predicate.
+  fn any_operand(&self, predicate: fn(BidUint128) -> bool) -> bool {
+    match *self {
+      Operation::Add(x, y) | Operation::Sub(x, y) | Operation::Mul(x, y) | Operation::Div(x, y) => predicate(x) || predicate(y),
+      Operation::Fma(x, y, z) => predicate(x) || predicate(y) || predicate(z),
+    }
+  }
+
+  /// Returns the operation with the exact result multiplied by `10^k`.
+  fn scaled(&self, k: i32) -> Option<Operation> {
+    Some(match *self {
+      Operation::Add(x, y) => Operation::Add(bid_scale(x, k)?, bid_scale(y, k)?),
+      Operation::Sub(x, y) => Operation::Sub(bid_scale(x, k)?, bid_scale(y, k)?),
+      Operation::Mul(x, y) => Operation::Mul(bid_scale(x, k)?, y),
+      Operation::Div(x, y) => Operation::Div(bid_scale(x, k)?, y),
+      Operation::Fma(x, y, z) => Operation::Fma(bid_scale(x, k)?, y, bid_scale(z, k)?),
+    })
+  }
+}
+
+/// Error returned by checked operations when an exception with enabled trap is raised.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DecimalError {
+  /// Operation that raised the exception.
+  pub operation: Operation,
+  /// Default result, returned by the operation when no trap is enabled.
+  pub result: BidUint128,
+  /// All exception flags raised by the operation.
+  pub flags: IdecFlags,
+  /// Raised exceptions with enabled traps.
+  pub trapped: IdecFlags,
+}
+
+impl fmt::Display for DecimalError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    const NAMES: [(IdecFlags, &str); 6] = [
+      (BID_INVALID_EXCEPTION, "invalid operation"),
+      (BID_ZERO_DIVIDE_EXCEPTION, "division by zero"),
+      (BID_OVERFLOW_EXCEPTION, "overflow"),
+      (BID_UNDERFLOW_EXCEPTION, "underflow"),
+      (BID_INEXACT_EXCEPTION, "inexact"),
+      (BID_DENORMAL_EXCEPTION, "denormal"),
+    ];
+    write!(f, "{} raised", self.operation.name())?;
+    let mut separator = " ";
+    for (flag, name) in NAMES {
+      if self.trapped & flag == flag {
+        write!(f, "{separator}{name}")?;
+        separator = ", ";
+      }
+    }
+    Ok(())
+  }
+}
+
+impl core::error::Error for DecimalError {}
+
+/// Returns exceptions signaled by the operation, detected from its operands and the result.
+///
+/// Supplements the flags reported by the operation itself, because invalid operation,
+/// division by zero and overflow are not always reported without `bid-set-status-flags` feature.
+fn bid_detect_exceptions(operation: &Operation, res: BidUint128) -> IdecFlags {
+  if operation.any_operand(bid128_is_signaling) {
+    return BID_INVALID_EXCEPTION;
+  }
+  if operation.any_operand(bid128_is_nan) {
+    return BID_EXACT_STATUS;
+  }
+  if bid128_is_nan(res) {
+    return BID_INVALID_EXCEPTION;
+  }
+  if bid128_is_inf(res) && !operation.any_operand(bid128_is_inf) {
+    if let Operation::Div(_, y) = *operation
+      && bid128_is_zero(y)
+    {
+      return BID_ZERO_DIVIDE_EXCEPTION;
+    }
+    return BID_OVERFLOW_EXCEPTION | BID_INEXACT_EXCEPTION;
+  }
+  BID_EXACT_STATUS
+}
+
+/// Returns `true` if the magnitude of the value is below `10^(k - 6143)`,
+/// so the value is tiny when `k` is zero.
+fn bid_is_tiny(x: BidUint128, k: i32) -> bool {
+  if (x.w[1] & MASK_SPECIAL) == MASK_SPECIAL {
+    return false;
+  }
+  if bid128_is_zero(x) {
+    return true;
+  }
+  let exp = (((x.w[1] & MASK_EXP) >> 49) as i32) - DECIMAL_EXPONENT_BIAS_128;
+  let c = BidUint128 { w: [x.w[0], x.w[1] & MASK_COEFF] };
+  // c * 10^exp < 10^(k - 6143) when c < 10^n
+  let n = P34 - 1 - DECIMAL_EXPONENT_BIAS_128 + k - exp;
+  n > 0 && (n >= P34 || unsigned_compare_gt_128!(bid_power10_table_128!(n), c))
+}
+
+/// Returns the value multiplied by `10^k`, when the exponent can be increased without overflow.
+fn bid_scale(x: BidUint128, k: i32) -> Option<BidUint128> {
+  if (x.w[1] & MASK_SPECIAL) == MASK_SPECIAL || (x.w[1] & MASK_STEERING_BITS) == MASK_STEERING_BITS {
+    // infinity, NaN and non-canonical zero are not changed
+    return Some(x);
+  }
+  let exp = (((x.w[1] & MASK_EXP) >> 49) as i32) + k;
+  if exp > DECIMAL_MAX_EXPON_128 {
+    return None;
+  }
+  Some(BidUint128 {
+    w: [x.w[0], (x.w[1] & !MASK_EXP) | ((exp as BidUint64) << 49)],
+  })
+}
+
+/// Returns inexact and underflow exceptions signaled by the operation.
+///
+/// The result is inexact when the results rounded toward zero and away from zero differ,
+/// because these exceptions are not always reported without `bid-set-status-flags` feature.
+/// The result is tiny before rounding when the result rounded toward zero is below `10^-6143`,
+/// and tiny after rounding when the rounded result is below `10^-6143`. Only a result rounded
+/// to exactly `10^-6143` is ambiguous, then the operation is repeated with the exact result
+/// multiplied by 100, where the exponent range does not limit the rounding.
+fn bid_detect_rounding(operation: &Operation, res: BidUint128, rounding: IdecRound, tininess: IdecTininess, op: &impl Fn(&Operation, IdecRound, IdecTininess, &mut IdecFlags) -> BidUint128) -> IdecFlags {
+  let mut ignored = BID_EXACT_STATUS;
+  let res_z = op(operation, BID_ROUNDING_TO_ZERO, tininess, &mut ignored);
+  let away = if (res_z.w[1] & MASK_SIGN) == 0 { BID_ROUNDING_UP } else { BID_ROUNDING_DOWN };
+  let res_a = op(operation, away, tininess, &mut ignored);
+  if res_a == res_z {
+    return BID_EXACT_STATUS;
+  }
+  let is_tiny = if !bid_is_tiny(res_z, 0) {
+    false
+  } else if tininess == BID_TININESS_BEFORE_ROUNDING || bid_is_tiny(res, 0) {
+    true
+  } else {
+    match operation.scaled(2) {
+      Some(scaled) => bid_is_tiny(op(&scaled, rounding, tininess, &mut ignored), 2),
+      None => true,
+    }
+  };
+  if is_tiny { BID_INEXACT_EXCEPTION | BID_UNDERFLOW_EXCEPTION } else { BID_INEXACT_EXCEPTION }
+}
+
+/// Computes the operation, accumulates the raised exception flags and checks them against enabled traps.
+///
+/// Inexact result and underflow are always detected by [bid_detect_rounding],
+/// so checked operations report the same exceptions with and without `bid-set-status-flags` feature.
+pub(crate) fn bid_check(
+  operation: Operation,
+  rounding: IdecRound,
+  tininess: IdecTininess,
+  traps: IdecFlags,
+  flags: &mut IdecFlags,
+  op: impl Fn(&Operation, IdecRound, IdecTininess, &mut IdecFlags) -> BidUint128,
+) -> Result<BidUint128, DecimalError> {
+  let mut raised = BID_EXACT_STATUS;
+  let res = op(&operation, rounding, tininess, &mut raised);
+  let raised = (raised & !(BID_INEXACT_EXCEPTION | BID_UNDERFLOW_EXCEPTION)) | bid_detect_exceptions(&operation, res) | bid_detect_rounding(&operation, res, rounding, tininess, &op);
+  *flags |= raised;
+  let trapped = raised & traps;
+  if trapped == BID_NO_EXCEPTION {
+    Ok(res)
+  } else {
+    Err(DecimalError {
+      operation,
+      result: res,
+      flags: raised,
+      trapped,
+    })
+  }
+}
+
+/// Computes the operation with full precision.
+fn bid_compute(operation: &Operation, rounding: IdecRound, tininess: IdecTininess, flags: &mut IdecFlags) -> BidUint128 {
+  match *operation {
+    Operation::Add(x, y) => bid128_add(x, y, rounding, flags),
+    Operation::Sub(x, y) => bid128_sub(x, y, rounding, flags),
+    Operation::Mul(x, y) => bid128_mul_with_tininess(x, y, rounding, tininess, flags),
+    Operation::Div(x, y) => bid128_div(x, y, rounding, flags),
+    Operation::Fma(x, y, z) => bid128_fma_with_tininess(x, y, z, rounding, tininess, flags),
+  }
+}
+
+/// Adds two 128-bit decimal floating-point values,
+/// returns an error when an exception with enabled trap is raised.
+pub fn bid128_checked_add(x: BidUint128, y: BidUint128, rounding: IdecRound, tininess: IdecTininess, traps: IdecFlags, flags: &mut IdecFlags) -> Result<BidUint128, DecimalError> {
+  bid_check(Operation::Add(x, y), rounding, tininess, traps, flags, bid_compute)
+}
+
+/// Subtracts two 128-bit decimal floating-point values,
+/// returns an error when an exception with enabled trap is raised.
+pub fn bid128_checked_sub(x: BidUint128, y: BidUint128, rounding: IdecRound, tininess: IdecTininess, traps: IdecFlags, flags: &mut IdecFlags) -> Result<BidUint128, DecimalError> {
+  bid_check(Operation::Sub(x, y), rounding, tininess, traps, flags, bid_compute)
+}
+
+/// Multiplies two 128-bit decimal floating-point values,
+/// returns an error when an exception with enabled trap is raised.
+pub fn bid128_checked_mul(x: BidUint128, y: BidUint128, rounding: IdecRound, tininess: IdecTininess, traps: IdecFlags, flags: &mut IdecFlags) -> Result<BidUint128, DecimalError> {
+  bid_check(Operation::Mul(x, y), rounding, tininess, traps, flags, bid_compute)
+}
+
+/// Divides two 128-bit decimal floating-point values,
+/// returns an error when an exception with enabled trap is raised.
+pub fn bid128_checked_div(x: BidUint128, y: BidUint128, rounding: IdecRound, tininess: IdecTininess, traps: IdecFlags, flags: &mut IdecFlags) -> Result<BidUint128, DecimalError> {
+  bid_check(Operation::Div(x, y), rounding, tininess, traps, flags, bid_compute)
+}
+
+/// Computes `x * y + z` with a single rounding,
+/// returns an error when an exception with enabled trap is raised.
+#[allow(clippy::too_many_arguments)]
+pub fn bid128_checked_fma(x: BidUint128, y: BidUint128, z: BidUint128, rounding: IdecRound, tininess: IdecTininess, traps: IdecFlags, flags: &mut IdecFlags) -> Result<BidUint128, DecimalError> {
+  bid_check(Operation::Fma(x, y, z), rounding, tininess, traps, flags, bid_compute)
+}
@@ -405,76 +405,44 @@ pub fn bid128_div(x: BidUint128, y: BidUint128, rounding: IdecRound, flags: &mut
   }
 
   if diff_expon >= 0 {
-    if cfg!(feature = "ieee-round-nearest") {
-      // rounding
-      // 2*ca4 - cy
-      ca4r.w[1] = (ca4.w[1].wrapping_add(ca4.w[1])) | (ca4.w[0] >> 63);
-      ca4r.w[0] = ca4.w[0].wrapping_add(ca4.w[0]);
-      sub_borrow_out!(ca4r.w[0], carry64, ca4r.w[0], cy.w[0]);
-      ca4r.w[1] = ca4r.w[1].wrapping_sub(cy.w[1]).wrapping_sub(carry64);
-
-      d = if ca4r.w[1] | ca4r.w[0] > 0 { 1 } else { 0 };
-      carry64 = 1u64.wrapping_add(((ca4r.w[1] as i64) >> 63) as u64) & (cq.w[0] | d);
-
-      cq.w[0] += carry64;
-      if cq.w[0] < carry64 {
-        inc!(cq.w[1]);
-      }
-    } else if cfg!(feature = "ieee-round-nearest-ties-away") {
-      // rounding
-      // 2*ca4 - cy
-      ca4r.w[1] = (ca4.w[1].wrapping_add(ca4.w[1])) | (ca4.w[0] >> 63);
-      ca4r.w[0] = ca4.w[0].wrapping_add(ca4.w[0]);
-      sub_borrow_out!(ca4r.w[0], carry64, ca4r.w[0], cy.w[0]);
-      ca4r.w[1] = ca4r.w[1].wrapping_sub(cy.w[1]).wrapping_sub(carry64);
-
-      d = if ca4r.w[1] | ca4r.w[0] > 0 { 0 } else { 1 };
-      carry64 = 1u64.wrapping_add(((ca4r.w[1] as i64) >> 63) as u64) | d;
-
-      cq.w[0] += carry64;
-      if cq.w[0] < carry64 {
-        inc!(cq.w[1]);
-      }
-    } else {
-      rmode = rounding;
-      if sign_x ^ sign_y > 0 && rmode.wrapping_sub(1) < 2 {
-        rmode = 3 - rmode;
-      }
-      match rmode {
-        BID_ROUNDING_TO_NEAREST => {
-          // rounding
-          // 2*ca4 - cy
-          ca4r.w[1] = ca4.w[1].wrapping_add(ca4.w[1]) | (ca4.w[0] >> 63);
-          ca4r.w[0] = ca4.w[0].wrapping_add(ca4.w[0]);
-          sub_borrow_out!(ca4r.w[0], carry64, ca4r.w[0], cy.w[0]);
-          ca4r.w[1] = ca4r.w[1].wrapping_sub(cy.w[1]).wrapping_sub(carry64);
-          d = if ca4r.w[1] | ca4r.w[0] > 0 { 1 } else { 0 };
-          carry64 = 1_i64.wrapping_add((ca4r.w[1] as i64) >> 63) as u64 & (cq.w[0] | d);
-          inc!(cq.w[0], carry64);
-          if cq.w[0] < carry64 {
-            inc!(cq.w[1]);
-          }
+    rmode = rounding;
+    if sign_x ^ sign_y > 0 && rmode.wrapping_sub(1) < 2 {
+      rmode = 3 - rmode;
+    }
+    match rmode {
+      BID_ROUNDING_TO_NEAREST => {
+        // rounding
+        // 2*ca4 - cy
+        ca4r.w[1] = ca4.w[1].wrapping_add(ca4.w[1]) | (ca4.w[0] >> 63);
+        ca4r.w[0] = ca4.w[0].wrapping_add(ca4.w[0]);
+        sub_borrow_out!(ca4r.w[0], carry64, ca4r.w[0], cy.w[0]);
+        ca4r.w[1] = ca4r.w[1].wrapping_sub(cy.w[1]).wrapping_sub(carry64);
+        d = if ca4r.w[1] | ca4r.w[0] > 0 { 1 } else { 0 };
+        carry64 = 1_i64.wrapping_add((ca4r.w[1] as i64) >> 63) as u64 & (cq.w[0] | d);
+        inc!(cq.w[0], carry64);
+        if cq.w[0] < carry64 {
+          inc!(cq.w[1]);
         }
-        BID_ROUNDING_TIES_AWAY => {
-          // rounding
-          // 2*ca4 - cy
-          ca4r.w[1] = ca4.w[1].wrapping_add(ca4.w[1]) | (ca4.w[0] >> 63);
-          ca4r.w[0] = ca4.w[0].wrapping_add(ca4.w[0]);
-          sub_borrow_out!(ca4r.w[0], carry64, ca4r.w[0], cy.w[0]);
-          ca4r.w[1] = ca4r.w[1].wrapping_sub(cy.w[1]).wrapping_sub(carry64);
-          d = if ca4r.w[1] | ca4r.w[0] > 0 { 0 } else { 1 };
-          carry64 = 1_i64.wrapping_add((ca4r.w[1] as i64) >> 63) as u64 | d;
-          inc!(cq.w[0], carry64);
-          if cq.w[0] < carry64 {
-            inc!(cq.w[1]);
-          }
+      }
+      BID_ROUNDING_TIES_AWAY => {
+        // rounding
+        // 2*ca4 - cy
+        ca4r.w[1] = ca4.w[1].wrapping_add(ca4.w[1]) | (ca4.w[0] >> 63);
+        ca4r.w[0] = ca4.w[0].wrapping_add(ca4.w[0]);
+        sub_borrow_out!(ca4r.w[0], carry64, ca4r.w[0], cy.w[0]);
+        ca4r.w[1] = ca4r.w[1].wrapping_sub(cy.w[1]).wrapping_sub(carry64);
+        d = if ca4r.w[1] | ca4r.w[0] > 0 { 0 } else { 1 };
+        carry64 = 1_i64.wrapping_add((ca4r.w[1] as i64) >> 63) as u64 | d;
+        inc!(cq.w[0], carry64);
+        if cq.w[0] < carry64 {
+          inc!(cq.w[1]);
         }
-        BID_ROUNDING_DOWN | BID_ROUNDING_TO_ZERO => {}
-        _ => {
-          inc!(cq.w[0]);
-          if cq.w[0] == 0 {
-            inc!(cq.w[1]);
-          }
+      }
+      BID_ROUNDING_DOWN | BID_ROUNDING_TO_ZERO => {}
+      _ => {
+        inc!(cq.w[0]);
+        if cq.w[0] == 0 {
+          inc!(cq.w[1]);
         }
       }
     }
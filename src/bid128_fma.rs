@@ -3,8 +3,8 @@ use crate::bid_round::*;
 use crate::bid128::*;
 use crate::bid128_common::*;
 use crate::{
-  BID_INEXACT_EXCEPTION, BID_INVALID_EXCEPTION, BID_OVERFLOW_EXCEPTION, BID_ROUNDING_DOWN, BID_ROUNDING_TIES_AWAY, BID_ROUNDING_TO_NEAREST, BID_ROUNDING_TO_ZERO, BID_ROUNDING_UP, BID_UNDERFLOW_EXCEPTION, BidUint64, BidUint128, BidUint192,
-  BidUint256, IdecFlags, IdecRound,
+  BID_INEXACT_EXCEPTION, BID_INVALID_EXCEPTION, BID_OVERFLOW_EXCEPTION, BID_ROUNDING_DOWN, BID_ROUNDING_TIES_AWAY, BID_ROUNDING_TO_NEAREST, BID_ROUNDING_TO_ZERO, BID_ROUNDING_UP, BID_TININESS_AFTER_ROUNDING, BID_TININESS_BEFORE_ROUNDING,
+  BID_TININESS_DEFAULT, BID_UNDERFLOW_EXCEPTION, BidUint64, BidUint128, BidUint192, BidUint256, IdecFlags, IdecRound, IdecTininess,
 };
 use core::mem::swap;
 
@@ -162,11 +162,16 @@ fn bid_sub256(mut x: BidUint256, y: BidUint256, pz: &mut BidUint256) {
 
 /// FMA.
 pub fn bid128_fma(x: BidUint128, y: BidUint128, z: BidUint128, rounding: IdecRound, flags: &mut IdecFlags) -> BidUint128 {
+  bid128_fma_with_tininess(x, y, z, rounding, BID_TININESS_DEFAULT, flags)
+}
+
+/// FMA with explicitly specified tininess detection method.
+pub fn bid128_fma_with_tininess(x: BidUint128, y: BidUint128, z: BidUint128, rounding: IdecRound, tininess: IdecTininess, flags: &mut IdecFlags) -> BidUint128 {
   let mut is_midpoint_lt_even = false;
   let mut is_midpoint_gt_even = false;
   let mut is_inexact_lt_midpoint = false;
   let mut is_inexact_gt_midpoint = false;
-  bid128_ext_fma(&mut is_midpoint_lt_even, &mut is_midpoint_gt_even, &mut is_inexact_lt_midpoint, &mut is_inexact_gt_midpoint, x, y, z, rounding, flags)
+  bid128_ext_fma(&mut is_midpoint_lt_even, &mut is_midpoint_gt_even, &mut is_inexact_lt_midpoint, &mut is_inexact_gt_midpoint, x, y, z, rounding, tininess, flags)
 }
 
 fn bid_bid_nr_digits256(r256: BidUint256) -> i32 {
@@ -234,6 +239,7 @@ fn bid_add_and_round(
   c3: BidUint128,
   c4: BidUint256,
   rnd_mode: u32,
+  tininess: IdecTininess,
   ptr_is_midpoint_lt_even: &mut bool,
   ptr_is_midpoint_gt_even: &mut bool,
   ptr_is_inexact_lt_midpoint: &mut bool,
@@ -400,7 +406,7 @@ fn bid_add_and_round(
       r128.w[1] = r256.w[1];
       r128.w[0] = r256.w[0];
     }
-    if !cfg!(feature = "decimal-tiny-detection-after-rounding") {
+    if tininess == BID_TININESS_BEFORE_ROUNDING {
       if e4 + x0 < EXPMIN {
         // for all rounding modes
         is_tiny = true;
@@ -411,7 +417,7 @@ fn bid_add_and_round(
     // the rounded result has p34 = 34 digits
     e4 = e4 + x0 + incr_exp;
     if rnd_mode == BID_ROUNDING_TO_NEAREST {
-      if cfg!(feature = "decimal-tiny-detection-after-rounding") {
+      if tininess == BID_TININESS_AFTER_ROUNDING {
         if e4 < EXPMIN {
           is_tiny = true; // for other rounding modes apply correction
         }
@@ -426,7 +432,7 @@ fn bid_add_and_round(
       p128.w[0] = r128.w[0];
       bid_rounding_correction(rnd_mode, is_inexact_lt_midpoint, is_inexact_gt_midpoint, is_midpoint_lt_even, is_midpoint_gt_even, 0, &mut p128, ptrfpsf);
       // the number of digits in the significand is p34 = 34
-      if cfg!(feature = "decimal-tiny-detection-after-rounding") {
+      if tininess == BID_TININESS_AFTER_ROUNDING {
         let tmp_scale = (((p128.w[1] & MASK_EXP) >> 49) as i32).wrapping_sub(6176); // -1, 0, or +1
         if e4 + tmp_scale < EXPMIN {
           is_tiny = true;
@@ -628,6 +634,7 @@ pub fn bid128_ext_fma(
   mut y: BidUint128,
   mut z: BidUint128,
   rounding: IdecRound,
+  tininess: IdecTininess,
   flags: &mut IdecFlags,
 ) -> BidUint128 {
   let mut res = BidUint128 { w: [0xbaddbaddbaddbadd, 0xbaddbaddbaddbadd] };
@@ -1299,12 +1306,8 @@ pub fn bid128_ext_fma(
       q4 = P34;
       if incr_exp > 0 {
         inc!(e4);
-        if cfg!(feature = "decimal-tiny-detection-after-rounding") {
-          if q4 + e4 == EXPMIN + P34 {
-            set_status_flags!(flags, BID_INEXACT_EXCEPTION | BID_UNDERFLOW_EXCEPTION);
-          }
-        } else {
-          //
+        if tininess == BID_TININESS_BEFORE_ROUNDING && q4 + e4 == EXPMIN + P34 {
+          set_status_flags!(flags, BID_INEXACT_EXCEPTION | BID_UNDERFLOW_EXCEPTION);
         }
       }
       // res is now the coefficient of the result rounded to the destination
@@ -1804,7 +1807,7 @@ pub fn bid128_ext_fma(
               res.w[1] |= z_sign | (e3.wrapping_add(6176) as u64) << 49;
             }
             if e3 == EXPMIN {
-              if cfg!(feature = "decimal-tiny-detection-after-rounding") {
+              if tininess == BID_TININESS_AFTER_ROUNDING {
                 if r64 < 5 || (r64 == 5 && !is_inexact_lt_midpoint) {
                   // result not tiny (in round-to-nearest mode)
                   // rounds to 10^33 * 10^emin
@@ -1853,7 +1856,7 @@ pub fn bid128_ext_fma(
         //      endif
         //    endif
 
-        if cfg!(feature = "decimal-tiny-detection-after-rounding") {
+        if tininess == BID_TININESS_AFTER_ROUNDING {
           // determine if c4 > 5 * 10^(q4-1)
           if q4 <= 19 {
             c4gt5toq4m1 = c4.w[0] > bid_midpoint64!(q4 - 1);
@@ -2620,7 +2623,7 @@ pub fn bid128_ext_fma(
           if (res.w[1] & MASK_COEFF) < 0x0000314dc6448d93 || ((res.w[1] & MASK_COEFF) == 0x0000314dc6448d93 && res.w[0] < 0x38c15b0a00000000) {
             is_tiny = true;
           }
-          if cfg!(feature = "decimal-tiny-detection-after-rounding") {
+          if tininess == BID_TININESS_AFTER_ROUNDING {
             if ((res.w[1] & 0x7fffffffffffffff) == 0x0000314dc6448d93) && (res.w[0] == 0x38c15b0a00000000) && /* 10^33*10^-6176 */ (z_sign != p_sign) {
               is_tiny = true;
             }
@@ -2831,7 +2834,7 @@ pub fn bid128_ext_fma(
           // (16), and (17) if we just change the sign of delta
           delta = -delta;
         }
-        bid_add_and_round(q3, q4, e4, delta, P34, z_sign, p_sign, c3, c4, rounding, &mut is_midpoint_lt_even, &mut is_midpoint_gt_even, &mut is_inexact_lt_midpoint, &mut is_inexact_gt_midpoint, flags, &mut res);
+        bid_add_and_round(q3, q4, e4, delta, P34, z_sign, p_sign, c3, c4, rounding, tininess, &mut is_midpoint_lt_even, &mut is_midpoint_gt_even, &mut is_inexact_lt_midpoint, &mut is_inexact_gt_midpoint, flags, &mut res);
         *ptr_is_midpoint_lt_even = is_midpoint_lt_even;
         *ptr_is_midpoint_gt_even = is_midpoint_gt_even;
         *ptr_is_inexact_lt_midpoint = is_inexact_lt_midpoint;
@@ -3411,7 +3414,7 @@ pub fn bid128_ext_fma(
         if rounding != BID_ROUNDING_TO_NEAREST {
           bid_rounding_correction(rounding, is_inexact_lt_midpoint, is_inexact_gt_midpoint, is_midpoint_lt_even, is_midpoint_gt_even, e4, &mut res, flags);
         }
-        if cfg!(feature = "decimal-tiny-detection-after-rounding") {
+        if tininess == BID_TININESS_AFTER_ROUNDING {
           // correction needed for tininess detection before rounding
           if (((res.w[1] & 0x7fffffffffffffff) == 0x0000314dc6448d93) && // 10^33*10^-6176_high
           (res.w[0] == 0x38c15b0a00000000)) &&  // 10^33*10^-6176_low
@@ -3448,7 +3451,7 @@ pub fn bid128_ext_fma(
         // calculate first the result rounded to the destination precision, with
         // unbounded exponent
 
-        bid_add_and_round(q3, q4, e4, delta, P34, z_sign, p_sign, c3, c4, rounding, &mut is_midpoint_lt_even, &mut is_midpoint_gt_even, &mut is_inexact_lt_midpoint, &mut is_inexact_gt_midpoint, flags, &mut res);
+        bid_add_and_round(q3, q4, e4, delta, P34, z_sign, p_sign, c3, c4, rounding, tininess, &mut is_midpoint_lt_even, &mut is_midpoint_gt_even, &mut is_inexact_lt_midpoint, &mut is_inexact_gt_midpoint, flags, &mut res);
         *ptr_is_midpoint_lt_even = is_midpoint_lt_even;
         *ptr_is_midpoint_gt_even = is_midpoint_gt_even;
         *ptr_is_inexact_lt_midpoint = is_inexact_lt_midpoint;
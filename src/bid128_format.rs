@@ -0,0 +1,519 @@
+//! # Formatting in fixed-point, scientific and engineering notation
+
+use crate::bid_conf::IdecRound;
+use crate::bid_functions::*;
+use crate::bid_internal::*;
+use crate::bid128_common::*;
+use crate::bid128_locale::NumberFormat;
+use crate::bid128_noncomp::bid_nan_payload;
+use crate::bid128_string::bid_coefficient_to_chars;
+use crate::{BidUint64, BidUint128};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::fmt;
+use core::fmt::Write;
+
+/// Maximum number of digits in the coefficient of 128-bit value.
+const MAX_COEFFICIENT_DIGITS: usize = 34;
+
+/// Decimal digits of a finite value, the value is equal to `digits × 10^exponent`.
+#[derive(Copy, Clone)]
+pub(crate) struct Digits {
+  /// Digits of the coefficient (values 0 to 9), the most significant first,
+  /// without leading zeros, zero has a single digit.
+  digits: [u8; MAX_COEFFICIENT_DIGITS + 1],
+  /// Number of digits.
+  len: usize,
+  /// Exponent of the least significant digit.
+  exponent: i32,
+}
+
+impl Digits {
+  /// Creates digits from the canonical coefficient and the unbiased exponent.
+  fn new(coefficient: BidUint128, exponent: i32) -> Self {
+    let mut digits = [0; MAX_COEFFICIENT_DIGITS + 1];
+    if coefficient.w[0] == 0 && coefficient.w[1] == 0 {
+      return Self { digits, len: 1, exponent };
+    }
+    let len = bid_coefficient_to_chars(coefficient, &mut digits, 0);
+    for digit in &mut digits[..len] {
+      *digit -= b'0';
+    }
+    Self { digits, len, exponent }
+  }
+
+  /// Returns `true` when the value is zero.
+  fn is_zero(&self) -> bool {
+    self.digits[0] == 0
+  }
+
+  /// Returns the exponent of the most significant digit.
+  fn adjusted(&self) -> i32 {
+    self.exponent + self.len as i32 - 1
+  }
+
+  /// Returns the digit at the specified position (power of ten).
+  fn digit_at(&self, position: i32) -> u8 {
+    if position >= self.exponent && position <= self.adjusted() {
+      self.digits[(self.adjusted() - position) as usize]
+    } else {
+      0
+    }
+  }
+
+  /// Rounds the value, so that the least significant digit is at the specified position (power of ten).
+  fn round_at(&mut self, position: i32, negative: bool, rounding: IdecRound) {
+    if position <= self.exponent {
+      return;
+    }
+    let dropped = (position as i64 - self.exponent as i64) as usize;
+    let kept = self.len.saturating_sub(dropped);
+    let (round_digit, sticky) = if dropped > self.len {
+      (0, self.digits[..self.len].iter().any(|d| *d > 0))
+    } else {
+      (self.digits[kept], self.digits[kept + 1..self.len].iter().any(|d| *d > 0))
+    };
+    let odd = kept > 0 && self.digits[kept - 1] % 2 == 1;
+    let increment = match rounding {
+      BID_ROUNDING_TO_NEAREST => round_digit > 5 || (round_digit == 5 && (sticky || odd)),
+      BID_ROUNDING_TIES_AWAY => round_digit >= 5,
+      BID_ROUNDING_DOWN => negative && (round_digit > 0 || sticky),
+      BID_ROUNDING_UP => !negative && (round_digit > 0 || sticky),
+      _ => false,
+    };
+    self.len = kept;
+    self.exponent = position;
+    if increment {
+      let mut i = kept;
+      loop {
+        if i == 0 {
+          self.digits.copy_within(0..self.len, 1);
+          self.digits[0] = 1;
+          self.len += 1;
+          break;
+        }
+        i -= 1;
+        if self.digits[i] == 9 {
+          self.digits[i] = 0;
+        } else {
+          self.digits[i] += 1;
+          break;
+        }
+      }
+    }
+    if self.len == 0 {
+      self.digits[0] = 0;
+      self.len = 1;
+    }
+  }
+
+  /// Rounds the value to the specified number of significant digits.
+  fn round_to_significant(&mut self, significant_digits: usize, negative: bool, rounding: IdecRound) {
+    let significant_digits = significant_digits.clamp(1, MAX_COEFFICIENT_DIGITS);
+    self.round_at(self.adjusted() - significant_digits as i32 + 1, negative, rounding);
+    if self.len > significant_digits {
+      // Rounding carried into a new digit, the dropped digit is zero.
+      self.len -= 1;
+      self.exponent += 1;
+    }
+  }
+}
+
+/// Unpacked 128-bit decimal floating-point value.
+pub(crate) enum Unpacked {
+  /// Quiet NaN with payload.
+  NaN(u128),
+  /// Signaling NaN with payload.
+  SNaN(u128),
+  /// Infinity.
+  Inf,
+  /// Finite value.
+  Finite(Digits),
+}
+
+/// Unpacks the value into the sign and digits.
+pub(crate) fn bid_unpack(x: BidUint128) -> (bool, Unpacked) {
+  let negative = (x.w[1] & MASK_SIGN) != 0;
+  if (x.w[1] & MASK_NAN) == MASK_NAN {
+    let payload = bid_nan_payload(x);
+    return (negative, if (x.w[1] & MASK_SNAN) == MASK_SNAN { Unpacked::SNaN(payload) } else { Unpacked::NaN(payload) });
+  }
+  if (x.w[1] & MASK_INF) == MASK_INF {
+    return (negative, Unpacked::Inf);
+  }
+  let mut sign: BidUint64 = 0;
+  let mut exponent: i32 = 0;
+  let mut coefficient = BidUint128::default();
+  unpack_bid128_value(&mut sign, &mut exponent, &mut coefficient, x);
+  (negative, Unpacked::Finite(Digits::new(coefficient, exponent - DECIMAL_EXPONENT_BIAS_128)))
+}
+
+/// Writes special values, nothing is written for finite values.
+pub(crate) fn bid_write_special(negative: bool, unpacked: &Unpacked, writer: &mut dyn Write) -> fmt::Result {
+  match unpacked {
+    Unpacked::NaN(payload) => bid_write_nan("NaN", *payload, writer),
+    Unpacked::SNaN(payload) => bid_write_nan("sNaN", *payload, writer),
+    Unpacked::Inf => writer.write_str(if negative { "-inf" } else { "inf" }),
+    Unpacked::Finite(_) => Ok(()),
+  }
+}
+
+/// Writes NaN followed by its payload, zero payload is not written.
+fn bid_write_nan(name: &str, payload: u128, writer: &mut dyn Write) -> fmt::Result {
+  writer.write_str(name)?;
+  if payload > 0 { write!(writer, "{}", payload) } else { Ok(()) }
+}
+
+/// Writes the digit (value 0 to 9).
+fn bid_write_digit(digit: u8, writer: &mut dyn Write) -> fmt::Result {
+  writer.write_char((b'0' + digit) as char)
+}
+
+/// Writes digits in fixed-point notation, without sign, using separators from the number format.
+pub(crate) fn bid_write_fixed_digits(mut d: Digits, fraction_digits: Option<usize>, negative: bool, rounding: IdecRound, format: &NumberFormat, writer: &mut dyn Write) -> fmt::Result {
+  if let Some(fraction_digits) = fraction_digits {
+    d.round_at(-(fraction_digits.min(i32::MAX as usize) as i32), negative, rounding);
+  }
+  let group_size = format.group_size() as i32;
+  let most_significant = if d.is_zero() { 0 } else { d.adjusted().max(0) };
+  for position in (0..=most_significant).rev() {
+    bid_write_digit(d.digit_at(position), writer)?;
+    if let Some(group_separator) = format.group_separator()
+      && position > 0
+      && position % group_size == 0
+    {
+      writer.write_char(group_separator)?;
+    }
+  }
+  let fraction_digits = fraction_digits.unwrap_or(d.exponent.min(0).unsigned_abs() as usize);
+  if fraction_digits > 0 {
+    writer.write_char(format.decimal_separator())?;
+    for i in 1..=fraction_digits {
+      bid_write_digit(d.digit_at(-(i.min(i32::MAX as usize) as i32)), writer)?;
+    }
+  }
+  Ok(())
+}
+
+/// Writes the digits of the mantissa with the radix point after `integer_digits`
+/// and the exponent, padding the mantissa with zeros up to `total_digits`.
+fn bid_write_mantissa(d: &Digits, integer_digits: usize, total_digits: usize, exponent: i32, upper_case: bool, writer: &mut dyn Write) -> fmt::Result {
+  for i in 0..total_digits.max(integer_digits) {
+    if i == integer_digits {
+      writer.write_char('.')?;
+    }
+    bid_write_digit(if i < d.len { d.digits[i] } else { 0 }, writer)?;
+  }
+  write!(writer, "{}{}", if upper_case { 'E' } else { 'e' }, exponent)
+}
+
+/// Writes digits in scientific notation, without sign.
+fn bid_write_scientific_digits(mut d: Digits, significant_digits: Option<usize>, negative: bool, rounding: IdecRound, upper_case: bool, writer: &mut dyn Write) -> fmt::Result {
+  if let Some(significant_digits) = significant_digits {
+    d.round_to_significant(significant_digits, negative, rounding);
+  }
+  bid_write_mantissa(&d, 1, significant_digits.unwrap_or(d.len).max(1), d.adjusted(), upper_case, writer)
+}
+
+/// Writes digits in engineering notation, without sign.
+fn bid_write_engineering_digits(mut d: Digits, significant_digits: Option<usize>, negative: bool, rounding: IdecRound, upper_case: bool, writer: &mut dyn Write) -> fmt::Result {
+  if let Some(significant_digits) = significant_digits {
+    d.round_to_significant(significant_digits, negative, rounding);
+  }
+  let total_digits = significant_digits.unwrap_or(d.len).max(1);
+  if d.is_zero() {
+    return bid_write_mantissa(&d, 1, total_digits, d.adjusted().div_euclid(3) * 3, upper_case, writer);
+  }
+  let exponent = d.adjusted().div_euclid(3) * 3;
+  bid_write_mantissa(&d, (d.adjusted() - exponent) as usize + 1, total_digits, exponent, upper_case, writer)
+}
+
+/// Writes a 128-bit decimal floating-point value in fixed-point notation, like `-123.45`.
+///
+/// When `fraction_digits` is specified, the value is rounded to this number of digits after
+/// the radix point using the specified rounding mode, otherwise all digits of the value are written.
+/// Infinities are written as `inf` and `-inf`, NaNs as `NaN` and `sNaN`.
+///
+/// # Examples
+///
+/// ```
+/// use decimus::{BID_ROUNDING_TO_NEAREST, bid128_from_string, bid128_write_fixed};
+///
+/// let mut flags = 0;
+/// let x = bid128_from_string("12345E-2", BID_ROUNDING_TO_NEAREST, &mut flags);
+/// let mut s = String::new();
+/// bid128_write_fixed(x, Some(1), BID_ROUNDING_TO_NEAREST, &mut s).unwrap();
+/// assert_eq!("123.4", s);
+/// ```
+pub fn bid128_write_fixed<W: fmt::Write>(x: BidUint128, fraction_digits: Option<usize>, rounding: IdecRound, writer: &mut W) -> fmt::Result {
+  let (negative, unpacked) = bid_unpack(x);
+  if let Unpacked::Finite(d) = unpacked {
+    if negative {
+      writer.write_char('-')?;
+    }
+    return bid_write_fixed_digits(d, fraction_digits, negative, rounding, &NumberFormat::PLAIN, writer);
+  }
+  bid_write_special(negative, &unpacked, writer)
+}
+
+/// Writes a 128-bit decimal floating-point value in scientific notation, like `-1.2345e2`.
+///
+/// When `significant_digits` is specified, the value is rounded to this number of significant digits
+/// using the specified rounding mode, otherwise all digits of the coefficient are written.
+/// Infinities are written as `inf` and `-inf`, NaNs as `NaN` and `sNaN`.
+pub fn bid128_write_scientific<W: fmt::Write>(x: BidUint128, significant_digits: Option<usize>, rounding: IdecRound, upper_case: bool, writer: &mut W) -> fmt::Result {
+  let (negative, unpacked) = bid_unpack(x);
+  if let Unpacked::Finite(d) = unpacked {
+    if negative {
+      writer.write_char('-')?;
+    }
+    return bid_write_scientific_digits(d, significant_digits, negative, rounding, upper_case, writer);
+  }
+  bid_write_special(negative, &unpacked, writer)
+}
+
+/// Writes a 128-bit decimal floating-point value in engineering notation, like `-123.45e3`,
+/// where the exponent is a multiple of three.
+///
+/// When `significant_digits` is specified, the value is rounded to this number of significant digits
+/// using the specified rounding mode, otherwise all digits of the coefficient are written.
+/// Infinities are written as `inf` and `-inf`, NaNs as `NaN` and `sNaN`.
+pub fn bid128_write_engineering<W: fmt::Write>(x: BidUint128, significant_digits: Option<usize>, rounding: IdecRound, upper_case: bool, writer: &mut W) -> fmt::Result {
+  let (negative, unpacked) = bid_unpack(x);
+  if let Unpacked::Finite(d) = unpacked {
+    if negative {
+      writer.write_char('-')?;
+    }
+    return bid_write_engineering_digits(d, significant_digits, negative, rounding, upper_case, writer);
+  }
+  bid_write_special(negative, &unpacked, writer)
+}
+
+/// Writes the value as specified by the to-scientific-string
+/// or to-engineering-string conversion of the General Decimal Arithmetic Specification.
+fn bid_write_spec_string(x: BidUint128, engineering: bool, writer: &mut dyn Write) -> fmt::Result {
+  let (negative, unpacked) = bid_unpack(x);
+  if negative {
+    writer.write_char('-')?;
+  }
+  let d = match unpacked {
+    Unpacked::Inf => return writer.write_str("Infinity"),
+    Unpacked::NaN(_) | Unpacked::SNaN(_) => return bid_write_special(negative, &unpacked, writer),
+    Unpacked::Finite(d) => d,
+  };
+  let adjusted = d.adjusted();
+  if d.exponent <= 0 && adjusted >= -6 {
+    // Plain notation, the radix point is placed inside the coefficient or zeros are prepended.
+    let integer_digits = d.len as i32 + d.exponent;
+    if integer_digits <= 0 {
+      writer.write_str("0.")?;
+      for _ in integer_digits..0 {
+        writer.write_char('0')?;
+      }
+    }
+    for (i, digit) in d.digits[..d.len].iter().enumerate() {
+      if i as i32 == integer_digits && integer_digits > 0 {
+        writer.write_char('.')?;
+      }
+      bid_write_digit(*digit, writer)?;
+    }
+    return Ok(());
+  }
+  let (integer_digits, total_digits, exponent) = if !engineering {
+    (1, d.len, adjusted)
+  } else if d.is_zero() {
+    // Exponent of zero is rounded up to the multiple of three, zeros are written after the radix point.
+    let exponent = adjusted + (3 - adjusted.rem_euclid(3)) % 3;
+    (1, 1 + (exponent - adjusted) as usize, exponent)
+  } else {
+    let exponent = adjusted - adjusted.rem_euclid(3);
+    ((adjusted - exponent) as usize + 1, d.len, exponent)
+  };
+  for i in 0..total_digits.max(integer_digits) {
+    if i == integer_digits {
+      writer.write_char('.')?;
+    }
+    bid_write_digit(if i < d.len { d.digits[i] } else { 0 }, writer)?;
+  }
+  if exponent != 0 {
+    write!(writer, "E{:+}", exponent)?;
+  }
+  Ok(())
+}
+
+/// Writes a 128-bit decimal floating-point value as specified by the to-scientific-string conversion
+/// of the General Decimal Arithmetic Specification, like `1.23E+3`, `0.00123` or `1.2300`.
+///
+/// The exponent of the value (its cohort) is preserved, so the result is compatible
+/// with `BigDecimal.toString()` in Java and `str()` of `Decimal` in Python.
+pub fn bid128_write_sci_string<W: fmt::Write>(x: BidUint128, writer: &mut W) -> fmt::Result {
+  bid_write_spec_string(x, false, writer)
+}
+
+/// Writes a 128-bit decimal floating-point value as specified by the to-engineering-string conversion
+/// of the General Decimal Arithmetic Specification, like `123E+3` or `12.34E+3`,
+/// where the exponent is a multiple of three.
+///
+/// The result is compatible with `BigDecimal.toEngineeringString()` in Java
+/// and `Decimal.to_eng_string()` in Python.
+pub fn bid128_write_eng_string<W: fmt::Write>(x: BidUint128, writer: &mut W) -> fmt::Result {
+  bid_write_spec_string(x, true, writer)
+}
+
+/// Converts a 128-bit decimal floating-point value to string
+/// as specified by the to-scientific-string conversion of the General Decimal Arithmetic Specification.
+///
+/// # Examples
+///
+/// ```
+/// use decimus::{BID_ROUNDING_TO_NEAREST, bid128_from_string, bid128_to_sci_string};
+///
+/// let mut flags = 0;
+/// assert_eq!("1.23E+3", bid128_to_sci_string(bid128_from_string("123E+1", BID_ROUNDING_TO_NEAREST, &mut flags)));
+/// assert_eq!("0.00123", bid128_to_sci_string(bid128_from_string("123E-5", BID_ROUNDING_TO_NEAREST, &mut flags)));
+/// assert_eq!("1.2300", bid128_to_sci_string(bid128_from_string("12300E-4", BID_ROUNDING_TO_NEAREST, &mut flags)));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn bid128_to_sci_string(x: BidUint128) -> String {
+  let mut s = String::new();
+  let _ = bid128_write_sci_string(x, &mut s);
+  s
+}
+
+/// Converts a 128-bit decimal floating-point value to string
+/// as specified by the to-engineering-string conversion of the General Decimal Arithmetic Specification.
+///
+/// # Examples
+///
+/// ```
+/// use decimus::{BID_ROUNDING_TO_NEAREST, bid128_from_string, bid128_to_eng_string};
+///
+/// let mut flags = 0;
+/// assert_eq!("123E+3", bid128_to_eng_string(bid128_from_string("123E+3", BID_ROUNDING_TO_NEAREST, &mut flags)));
+/// assert_eq!("12.34E+3", bid128_to_eng_string(bid128_from_string("1234E+1", BID_ROUNDING_TO_NEAREST, &mut flags)));
+/// assert_eq!("0.00E+3", bid128_to_eng_string(bid128_from_string("0E+1", BID_ROUNDING_TO_NEAREST, &mut flags)));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn bid128_to_eng_string(x: BidUint128) -> String {
+  let mut s = String::new();
+  let _ = bid128_write_eng_string(x, &mut s);
+  s
+}
+
+/// Writer that only counts the written characters.
+struct CharCounter(usize);
+
+impl Write for CharCounter {
+  fn write_str(&mut self, s: &str) -> fmt::Result {
+    self.0 += s.chars().count();
+    Ok(())
+  }
+}
+
+/// Writes the sign and the body produced by the function, honouring the width, fill,
+/// alignment, sign and zero-padding flags of the formatter. NaNs pass `None` as the sign, they are never signed.
+fn bid_pad(f: &mut fmt::Formatter<'_>, negative: Option<bool>, zero_pad: bool, body: impl Fn(&mut dyn Write) -> fmt::Result) -> fmt::Result {
+  let sign = match negative {
+    Some(true) => "-",
+    Some(false) if f.sign_plus() => "+",
+    _ => "",
+  };
+  let mut counter = CharCounter(sign.len());
+  body(&mut counter)?;
+  let padding = f.width().unwrap_or(0).saturating_sub(counter.0);
+  if padding == 0 {
+    f.write_str(sign)?;
+    return body(f);
+  }
+  if zero_pad && f.sign_aware_zero_pad() {
+    f.write_str(sign)?;
+    for _ in 0..padding {
+      f.write_char('0')?;
+    }
+    return body(f);
+  }
+  let (before, after) = match f.align() {
+    Some(fmt::Alignment::Left) => (0, padding),
+    Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+    _ => (padding, 0),
+  };
+  let fill = f.fill();
+  for _ in 0..before {
+    f.write_char(fill)?;
+  }
+  f.write_str(sign)?;
+  body(f)?;
+  for _ in 0..after {
+    f.write_char(fill)?;
+  }
+  Ok(())
+}
+
+/// Formats the value using the function writing finite digits, special values are never zero-padded.
+fn bid_fmt(x: BidUint128, f: &mut fmt::Formatter<'_>, write_digits: impl Fn(Digits, bool, &mut dyn Write) -> fmt::Result) -> fmt::Result {
+  match bid_unpack(x) {
+    (negative, Unpacked::Finite(d)) => bid_pad(f, Some(negative), true, |writer| write_digits(d, negative, writer)),
+    (negative, Unpacked::Inf) => bid_pad(f, Some(negative), false, |writer| writer.write_str("inf")),
+    (_, unpacked) => bid_pad(f, None, false, |writer| bid_write_special(false, &unpacked, writer)),
+  }
+}
+
+/// Formats the value in fixed-point notation, the precision is the number of digits after the radix point.
+///
+/// # Examples
+///
+/// ```
+/// use decimus::{BID_ROUNDING_TO_NEAREST, bid128_from_string};
+///
+/// let mut flags = 0;
+/// let x = bid128_from_string("-12345E-2", BID_ROUNDING_TO_NEAREST, &mut flags);
+/// assert_eq!("-123.45", format!("{}", x));
+/// assert_eq!("-123.4", format!("{:.1}", x));
+/// assert_eq!("-000123.450", format!("{:011.3}", x));
+/// ```
+impl fmt::Display for BidUint128 {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let precision = f.precision();
+    bid_fmt(*self, f, |d, negative, writer| bid_write_fixed_digits(d, precision, negative, BID_ROUNDING_TO_NEAREST, &NumberFormat::PLAIN, writer))
+  }
+}
+
+/// Formats the value in scientific notation with lower-case exponent mark,
+/// the precision is the number of digits after the radix point.
+///
+/// # Examples
+///
+/// ```
+/// use decimus::{BID_ROUNDING_TO_NEAREST, bid128_from_string};
+///
+/// let mut flags = 0;
+/// let x = bid128_from_string("12345E-2", BID_ROUNDING_TO_NEAREST, &mut flags);
+/// assert_eq!("1.2345e2", format!("{:e}", x));
+/// assert_eq!("+1.23e2", format!("{:+.2e}", x));
+/// ```
+impl fmt::LowerExp for BidUint128 {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let significant_digits = f.precision().map(|precision| precision.saturating_add(1));
+    bid_fmt(*self, f, |d, negative, writer| bid_write_scientific_digits(d, significant_digits, negative, BID_ROUNDING_TO_NEAREST, false, writer))
+  }
+}
+
+/// Formats the value in scientific notation with upper-case exponent mark,
+/// the precision is the number of digits after the radix point.
+///
+/// # Examples
+///
+/// ```
+/// use decimus::{BID_ROUNDING_TO_NEAREST, bid128_from_string};
+///
+/// let mut flags = 0;
+/// let x = bid128_from_string("12345E-2", BID_ROUNDING_TO_NEAREST, &mut flags);
+/// assert_eq!("1.2345E2", format!("{:E}", x));
+/// assert_eq!("1.23E2", format!("{:.2E}", x));
+/// ```
+impl fmt::UpperExp for BidUint128 {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let significant_digits = f.precision().map(|precision| precision.saturating_add(1));
+    bid_fmt(*self, f, |d, negative, writer| bid_write_scientific_digits(d, significant_digits, negative, BID_ROUNDING_TO_NEAREST, true, writer))
+  }
+}
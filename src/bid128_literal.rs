@@ -0,0 +1,75 @@
+//! # Decimal literals validated at compile time
+
+use crate::BidUint128;
+use crate::bid128_common::*;
+use crate::bid128_noncomp::bid128_is_inf;
+use crate::bid128_string::bid_from_string_const;
+
+/// Creates a 128-bit decimal floating-point value (binary encoding) from a literal at compile time.
+///
+/// The literal may be a numeric literal like `dec!(123.45)` or `dec!(-1e-300)`,
+/// or a string literal accepted by [bid128_from_string_const](crate::bid128_from_string_const),
+/// like `dec!("1e-300")` or `dec!("NaN")`. Digit separators and type suffixes are not allowed.
+///
+/// Literals that can not be represented exactly fail to compile,
+/// unless marked with `inexact`, then the value is rounded to nearest with ties to even.
+/// The value is always bit-for-bit equal to the value returned by [bid128_from_string](crate::bid128_from_string)
+/// for the same literal with rounding to nearest.
+///
+/// # Examples
+///
+/// ```
+/// use decimus::{BidUint128, dec};
+///
+/// const PRICE: BidUint128 = dec!(123.45);
+/// assert_eq!(BidUint128 { w: [12345, 0x303c000000000000] }, PRICE);
+/// assert_eq!(BidUint128 { w: [1, 0xade8000000000000] }, dec!(-1e-300));
+/// assert_eq!(BidUint128 { w: [1, 0x2de8000000000000] }, dec!("1e-300"));
+/// assert_eq!(dec!(0.1234567890123456789012345678901234), dec!(0.12345678901234567890123456789012345, inexact));
+/// ```
+///
+/// Malformed literals fail to compile:
+///
+/// ```compile_fail
+/// let x = decimus::dec!("1,5");
+/// ```
+///
+/// Inexact literals fail to compile without the `inexact` marker:
+///
+/// ```compile_fail
+/// let x = decimus::dec!(0.12345678901234567890123456789012345);
+/// ```
+#[macro_export]
+macro_rules! dec {
+  (- $x:literal) => {
+    const { $crate::bid128_from_literal(stringify!($x), true, false) }
+  };
+  (- $x:literal, inexact) => {
+    const { $crate::bid128_from_literal(stringify!($x), true, true) }
+  };
+  ($x:literal) => {
+    const { $crate::bid128_from_literal(stringify!($x), false, false) }
+  };
+  ($x:literal, inexact) => {
+    const { $crate::bid128_from_literal(stringify!($x), false, true) }
+  };
+}
+
+/// Converts the stringified literal passed to [dec] macro.
+///
+/// String literals are stringified with the surrounding quotes, these are removed before parsing.
+#[doc(hidden)]
+pub const fn bid128_from_literal(literal: &str, negative: bool, allow_inexact: bool) -> BidUint128 {
+  let mut s = literal.as_bytes();
+  if let [b'"', inner @ .., b'"'] = s {
+    s = inner;
+  }
+  assert!(!negative || s.is_empty() || (s[0] != b'-' && s[0] != b'+'), "invalid decimal literal");
+  let (mut value, inexact) = bid_from_string_const(s);
+  assert!(!inexact || !bid128_is_inf(value), "decimal literal out of range");
+  assert!(allow_inexact || !inexact, "inexact decimal literal, mark it with `inexact` to allow rounding");
+  if negative {
+    value.w[1] |= MASK_SIGN;
+  }
+  value
+}
@@ -0,0 +1,272 @@
+//! # Locale-aware formatting and parsing
+
+use crate::BidUint128;
+use crate::bid_conf::{IdecFlags, IdecRound};
+use crate::bid128_format::*;
+use crate::bid128_string::{CharSequence, ParseDecimalError, ParseDecimalErrorKind, bid_special_length, bid_validate_payload, bid128_from_ascii};
+use core::fmt;
+
+/// Maximum number of significant digits passed to the parser, all coefficient digits plus the rounding digit.
+const MAX_SIGNIFICANT_DIGITS: usize = 35;
+
+/// Size of the buffer for the number in ASCII format
+/// (sign, significant digits, sticky digit, exponent mark, exponent sign and 6 exponent digits).
+const ASCII_NUMBER_SIZE: usize = MAX_SIGNIFICANT_DIGITS + 10;
+
+/// Maximum absolute value of the exponent passed to the parser,
+/// larger exponents always overflow or underflow.
+const MAX_ASCII_EXPONENT: i64 = 999_999;
+
+/// Separators used in locale-aware formatting and parsing of decimal numbers,
+/// like `1.234.567,89` or `1'234'567.89`.
+///
+/// # Examples
+///
+/// ```
+/// use decimus::{BID_ROUNDING_TO_NEAREST, NumberFormat, bid128_from_localized_string, bid128_write_localized};
+///
+/// let german = NumberFormat::new(',', Some('.'));
+/// let swiss = NumberFormat::new('.', Some('\''));
+///
+/// let mut flags = 0;
+/// let x = bid128_from_localized_string("1.234.567,89", &german, BID_ROUNDING_TO_NEAREST, &mut flags).unwrap();
+/// let mut s = String::new();
+/// bid128_write_localized(x, &swiss, None, BID_ROUNDING_TO_NEAREST, &mut s).unwrap();
+/// assert_eq!("1'234'567.89", s);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct NumberFormat {
+  /// Character separating the integer part from the fraction part.
+  decimal_separator: char,
+  /// Character separating groups of digits in the integer part.
+  group_separator: Option<char>,
+  /// Number of digits in a group.
+  group_size: usize,
+}
+
+impl NumberFormat {
+  /// Number format with radix point and without grouping, like `1234567.89`.
+  pub const PLAIN: NumberFormat = NumberFormat::new('.', None);
+
+  /// Creates a number format with specified separators, digits are grouped by three.
+  ///
+  /// # Panics
+  ///
+  /// Panics when the separators are equal, when any of them is a digit or a sign,
+  /// or when any of them is a white space other than the space character used for grouping.
+  pub const fn new(decimal_separator: char, group_separator: Option<char>) -> Self {
+    assert!(is_valid_separator(decimal_separator), "invalid decimal separator");
+    if let Some(group_separator) = group_separator {
+      assert!(is_valid_separator(group_separator) || group_separator == ' ', "invalid group separator");
+      assert!(group_separator != decimal_separator, "decimal and group separators must differ");
+    }
+    Self {
+      decimal_separator,
+      group_separator,
+      group_size: 3,
+    }
+  }
+
+  /// Returns the number format with the specified number of digits in a group,
+  /// zero disables grouping.
+  pub const fn with_group_size(mut self, group_size: usize) -> Self {
+    self.group_size = group_size;
+    self
+  }
+
+  /// Returns the character separating the integer part from the fraction part.
+  pub const fn decimal_separator(&self) -> char {
+    self.decimal_separator
+  }
+
+  /// Returns the character separating groups of digits in the integer part,
+  /// `None` when digits are not grouped.
+  pub const fn group_separator(&self) -> Option<char> {
+    if self.group_size > 0 { self.group_separator } else { None }
+  }
+
+  /// Returns the number of digits in a group.
+  pub const fn group_size(&self) -> usize {
+    self.group_size
+  }
+}
+
+impl Default for NumberFormat {
+  fn default() -> Self {
+    Self::PLAIN
+  }
+}
+
+/// Returns `true` when the character may be used as a separator.
+const fn is_valid_separator(c: char) -> bool {
+  !c.is_ascii_digit() && !c.is_ascii_whitespace() && c != '+' && c != '-'
+}
+
+/// Writes a 128-bit decimal floating-point value in fixed-point notation
+/// using separators from the number format, like `-1.234.567,89`.
+///
+/// When `fraction_digits` is specified, the value is rounded to this number of digits after
+/// the decimal separator using the specified rounding mode, otherwise all digits of the value are written.
+/// Infinities are written as `inf` and `-inf`, NaNs as `NaN` and `sNaN`.
+pub fn bid128_write_localized<W: fmt::Write>(x: BidUint128, format: &NumberFormat, fraction_digits: Option<usize>, rounding: IdecRound, writer: &mut W) -> fmt::Result {
+  let (negative, unpacked) = bid_unpack(x);
+  if let Unpacked::Finite(d) = unpacked {
+    if negative {
+      writer.write_char('-')?;
+    }
+    return bid_write_fixed_digits(d, fraction_digits, negative, rounding, format, writer);
+  }
+  bid_write_special(negative, &unpacked, writer)
+}
+
+/// Number rewritten to ASCII format accepted by the parser.
+struct AsciiNumber {
+  /// Characters of the number.
+  chars: [u8; ASCII_NUMBER_SIZE],
+  /// Number of characters.
+  len: usize,
+  /// Number of significant digits.
+  significant_digits: usize,
+  /// Number of fraction digits stored.
+  fraction_digits: i64,
+  /// Number of integer digits not stored.
+  dropped_digits: i64,
+  /// Set when any digit that was not stored is not zero.
+  sticky: bool,
+}
+
+impl AsciiNumber {
+  /// Creates an empty number.
+  fn new() -> Self {
+    Self {
+      chars: [0; ASCII_NUMBER_SIZE],
+      len: 0,
+      significant_digits: 0,
+      fraction_digits: 0,
+      dropped_digits: 0,
+      sticky: false,
+    }
+  }
+
+  /// Appends the character.
+  fn push(&mut self, c: u8) {
+    self.chars[self.len] = c;
+    self.len += 1;
+  }
+
+  /// Appends the digit of the integer part or the fraction part.
+  fn push_digit(&mut self, c: u8, fraction: bool) {
+    if self.significant_digits == 0 && c == b'0' {
+      // Leading zeros are skipped, but still count as fraction digits.
+      self.fraction_digits += fraction as i64;
+    } else if self.significant_digits < MAX_SIGNIFICANT_DIGITS {
+      self.push(c);
+      self.significant_digits += 1;
+      self.fraction_digits += fraction as i64;
+    } else {
+      self.sticky |= c != b'0';
+      self.dropped_digits += !fraction as i64;
+    }
+  }
+
+  /// Appends the sticky digit and the exponent, returns the characters of the number.
+  fn finish(&mut self) -> &[u8] {
+    if self.significant_digits == 0 {
+      self.push(b'0');
+    }
+    let mut exponent = self.dropped_digits - self.fraction_digits;
+    if self.sticky {
+      // Any non-zero digit after the rounding digit is equivalent to a single one.
+      self.push(b'1');
+      exponent -= 1;
+    }
+    let exponent = exponent.clamp(-MAX_ASCII_EXPONENT, MAX_ASCII_EXPONENT);
+    self.push(b'E');
+    self.push(if exponent < 0 { b'-' } else { b'+' });
+    let mut divisor = 100_000;
+    while divisor > 0 {
+      self.push(b'0' + (exponent.unsigned_abs() / divisor % 10) as u8);
+      divisor /= 10;
+    }
+    &self.chars[..self.len]
+  }
+}
+
+/// Converts a value represented in fixed-point notation using separators from the number format
+/// to 128-bit decimal floating-point format (binary encoding), rejecting malformed input.
+///
+/// Accepted are numbers with optional sign, group separators and decimal separator,
+/// and case-insensitive `Inf`, `Infinity`, `NaN` and `sNaN` with optional sign and NaN payload.
+/// Group separators are optional, but when present, all groups except the first one
+/// must have exactly the number of digits specified in the number format.
+/// Input of any length is accepted, digits that do not fit into the coefficient are rounded.
+pub fn bid128_from_localized_string(input: &str, format: &NumberFormat, rounding: IdecRound, flags: &mut IdecFlags) -> Result<BidUint128, ParseDecimalError> {
+  if input.is_empty() {
+    return Err(ParseDecimalError::new(ParseDecimalErrorKind::Empty, 0));
+  }
+  let bytes = input.as_bytes();
+  let mut number = AsciiNumber::new();
+  let mut p: usize = 0;
+
+  if bytes[p] == b'-' || bytes[p] == b'+' {
+    number.push(bytes[p]);
+    p += 1;
+  }
+
+  // Check for case-insensitive infinity, inf, snan or nan with optional payload.
+  let ps = CharSequence::new(bytes);
+  if let Some((keyword, payload)) = bid_special_length(&ps, p) {
+    bid_validate_payload(&ps, p + keyword, payload)?;
+    let end = p + keyword + payload;
+    return if end < bytes.len() {
+      Err(ParseDecimalError::new(ParseDecimalErrorKind::TrailingCharacters, end))
+    } else {
+      Ok(bid128_from_ascii(bytes, rounding, flags))
+    };
+  }
+
+  // Integer part with optional group separators.
+  let mut ndigits = 0;
+  let mut group_digits = 0;
+  let mut grouped = false;
+  let group_size = format.group_size();
+  let mut chars = input[p..].chars().peekable();
+  while let Some(&c) = chars.peek() {
+    if c.is_ascii_digit() {
+      number.push_digit(c as u8, false);
+      group_digits += 1;
+      ndigits += 1;
+    } else if Some(c) == format.group_separator() {
+      if group_digits == 0 || group_digits > group_size || (grouped && group_digits != group_size) {
+        return Err(ParseDecimalError::new(ParseDecimalErrorKind::InvalidGrouping, p));
+      }
+      grouped = true;
+      group_digits = 0;
+    } else {
+      break;
+    }
+    p += c.len_utf8();
+    chars.next();
+  }
+  if grouped && group_digits != group_size {
+    return Err(ParseDecimalError::new(ParseDecimalErrorKind::InvalidGrouping, p));
+  }
+
+  // Fraction part.
+  if chars.peek() == Some(&format.decimal_separator()) {
+    p += format.decimal_separator().len_utf8();
+    chars.next();
+    while let Some(c) = chars.next_if(char::is_ascii_digit) {
+      number.push_digit(c as u8, true);
+      ndigits += 1;
+      p += 1;
+    }
+  }
+  if ndigits == 0 {
+    return Err(ParseDecimalError::new(ParseDecimalErrorKind::InvalidDigit, p));
+  }
+  if p < bytes.len() {
+    return Err(ParseDecimalError::new(ParseDecimalErrorKind::TrailingCharacters, p));
+  }
+  Ok(bid128_from_ascii(number.finish(), rounding, flags))
+}
@@ -2,11 +2,17 @@
 
 use crate::bid_conf::*;
 use crate::bid128_common::*;
-use crate::bid128_fma::bid128_fma;
-use crate::{BidUint64, BidUint128};
+use crate::bid128_fma::bid128_fma_with_tininess;
+use crate::{BID_TININESS_DEFAULT, BidUint64, BidUint128};
 
 /// Multiplies two 128-bit decimal floating-point values.
 pub fn bid128_mul(x: BidUint128, y: BidUint128, rounding: IdecRound, flags: &mut IdecFlags) -> BidUint128 {
+  bid128_mul_with_tininess(x, y, rounding, BID_TININESS_DEFAULT, flags)
+}
+
+/// Multiplies two 128-bit decimal floating-point values
+/// with explicitly specified tininess detection method.
+pub fn bid128_mul_with_tininess(x: BidUint128, y: BidUint128, rounding: IdecRound, tininess: IdecTininess, flags: &mut IdecFlags) -> BidUint128 {
   let z = BidUint128 { w: [0x0000000000000000, 0x5ffe000000000000] };
   let mut res = BidUint128 { w: [0xbaddbaddbaddbadd, 0xbaddbaddbaddbadd] };
   let x_sign: BidUint64;
@@ -81,5 +87,5 @@ pub fn bid128_mul(x: BidUint128, y: BidUint128, rounding: IdecRound, flags: &mut
     }
   }
 
-  bid128_fma(y, x, z, rounding, flags)
+  bid128_fma_with_tininess(y, x, z, rounding, tininess, flags)
 }
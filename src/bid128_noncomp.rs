@@ -1,8 +1,9 @@
-use crate::BidUint128;
+use crate::bid_internal::DECIMAL_EXPONENT_BIAS_128;
 use crate::bid128_common::*;
+use crate::{BidUint64, BidUint128};
 
 /// Returns `true` if value is `zero` (+0 or -0).
-pub fn bid128_is_zero(x: BidUint128) -> bool {
+pub const fn bid128_is_zero(x: BidUint128) -> bool {
   if (x.w[1] & MASK_INF) == MASK_INF {
     return false;
   }
@@ -16,3 +17,165 @@ pub fn bid128_is_zero(x: BidUint128) -> bool {
   }
   false
 }
+
+/// Returns `true` if value is `NaN` (quiet or signaling).
+pub const fn bid128_is_nan(x: BidUint128) -> bool {
+  (x.w[1] & MASK_NAN) == MASK_NAN
+}
+
+/// Returns `true` if value is signaling `NaN`.
+pub const fn bid128_is_signaling(x: BidUint128) -> bool {
+  (x.w[1] & MASK_SNAN) == MASK_SNAN
+}
+
+/// Returns `true` if value is `infinity` (+Inf or -Inf).
+pub const fn bid128_is_inf(x: BidUint128) -> bool {
+  (x.w[1] & MASK_ANY_INF) == MASK_INF
+}
+
+/// Returns `true` if value is `finite` (zero, subnormal or normal, not infinite or NaN).
+pub const fn bid128_is_finite(x: BidUint128) -> bool {
+  (x.w[1] & MASK_INF) != MASK_INF
+}
+
+/// Classes of decimal floating-point values.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ClassTypes {
+  /// Signaling `NaN`.
+  SignalingNaN,
+  /// Quiet `NaN`.
+  QuietNaN,
+  /// Negative infinity.
+  NegativeInfinity,
+  /// Negative normal value.
+  NegativeNormal,
+  /// Negative subnormal value.
+  NegativeSubnormal,
+  /// Negative zero.
+  NegativeZero,
+  /// Positive zero.
+  PositiveZero,
+  /// Positive subnormal value.
+  PositiveSubnormal,
+  /// Positive normal value.
+  PositiveNormal,
+  /// Positive infinity.
+  PositiveInfinity,
+}
+
+/// Returns the class of the value.
+pub const fn bid128_class(x: BidUint128) -> ClassTypes {
+  let negative = (x.w[1] & MASK_SIGN) == MASK_SIGN;
+  if (x.w[1] & MASK_NAN) == MASK_NAN {
+    return if (x.w[1] & MASK_SNAN) == MASK_SNAN { ClassTypes::SignalingNaN } else { ClassTypes::QuietNaN };
+  }
+  if (x.w[1] & MASK_INF) == MASK_INF {
+    return if negative { ClassTypes::NegativeInfinity } else { ClassTypes::PositiveInfinity };
+  }
+  if bid128_is_zero(x) {
+    return if negative { ClassTypes::NegativeZero } else { ClassTypes::PositiveZero };
+  }
+  // The smallest normal value is 1 x 10^-6143 = 10^33 x 10^-6176,
+  // so the value is subnormal when sig_x * 10^exp_x < 10^33.
+  let exp_x = ((x.w[1] >> 49) & 0x0000000000003fff) as u32;
+  let sig_x = ((x.w[1] as u128 & MASK_COEFF as u128) << 64) | x.w[0] as u128;
+  if exp_x < 33 && sig_x < 10_u128.pow(33 - exp_x) {
+    return if negative { ClassTypes::NegativeSubnormal } else { ClassTypes::PositiveSubnormal };
+  }
+  if negative { ClassTypes::NegativeNormal } else { ClassTypes::PositiveNormal }
+}
+
+/// Largest payload of 128-bit NaN, payloads have at most 33 digits.
+pub(crate) const MAX_NAN_PAYLOAD_128: u128 = 999_999_999_999_999_999_999_999_999_999_999;
+
+/// Returns the payload (diagnostic information) of NaN, zero when the payload is not canonical.
+pub(crate) const fn bid_nan_payload(x: BidUint128) -> u128 {
+  let payload = ((x.w[1] as u128 & 0x00003fffffffffff) << 64) | x.w[0] as u128;
+  if payload <= MAX_NAN_PAYLOAD_128 { payload } else { 0 }
+}
+
+/// Returns NaN with specified high word bits (sign and signaling bit) and payload.
+pub(crate) const fn bid_nan_with_payload(high: BidUint64, payload: u128) -> BidUint128 {
+  BidUint128 {
+    w: [payload as BidUint64, high | (payload >> 64) as BidUint64],
+  }
+}
+
+/// Returns quiet `NaN` with specified payload (diagnostic information),
+/// payloads larger than 33 digits are replaced with zero.
+///
+/// # Examples
+///
+/// ```
+/// use decimus::{BidUint128, bid128_get_payload, bid128_nan, bid128_write_string};
+///
+/// let x = bid128_nan(404);
+/// let mut s = String::new();
+/// bid128_write_string(x, &mut s).unwrap();
+/// assert_eq!("+NaN404", s);
+/// assert_eq!(BidUint128 { w: [404, 0x3040000000000000] }, bid128_get_payload(x));
+/// ```
+pub const fn bid128_nan(payload: u128) -> BidUint128 {
+  bid_nan_with_payload(MASK_NAN, if payload <= MAX_NAN_PAYLOAD_128 { payload } else { 0 })
+}
+
+/// Returns the payload of `NaN` as a non-negative integer with zero exponent,
+/// returns -1 when the value is not `NaN` (getPayload operation of IEEE 754-2019).
+pub const fn bid128_get_payload(x: BidUint128) -> BidUint128 {
+  if (x.w[1] & MASK_NAN) != MASK_NAN {
+    return BidUint128 { w: [1, 0xb040000000000000] };
+  }
+  let payload = bid_nan_payload(x);
+  BidUint128 {
+    w: [payload as BidUint64, 0x3040000000000000 | (payload >> 64) as BidUint64],
+  }
+}
+
+/// Returns quiet `NaN` with payload equal to the value,
+/// returns +0 when the value is not a valid payload (setPayload operation of IEEE 754-2019).
+///
+/// Valid payloads are integers from 0 to 10^33 - 1 in any representation, like `12E+1` or `120E-1`.
+pub const fn bid128_set_payload(x: BidUint128) -> BidUint128 {
+  bid_set_payload(x, MASK_NAN)
+}
+
+/// Returns signaling `NaN` with payload equal to the value,
+/// returns +0 when the value is not a valid payload (setPayloadSignaling operation of IEEE 754-2019).
+///
+/// Valid payloads are integers from 0 to 10^33 - 1 in any representation, like `12E+1` or `120E-1`.
+pub const fn bid128_set_payload_signaling(x: BidUint128) -> BidUint128 {
+  bid_set_payload(x, MASK_SNAN)
+}
+
+/// Returns `NaN` with specified high word bits and payload equal to the value, or +0 when the payload is not valid.
+const fn bid_set_payload(x: BidUint128, high: BidUint64) -> BidUint128 {
+  let zero = BidUint128 { w: [0, 0x3040000000000000] };
+  if (x.w[1] & MASK_SPECIAL) == MASK_SPECIAL {
+    return zero;
+  }
+  if bid128_is_zero(x) {
+    return bid_nan_with_payload(high, 0);
+  }
+  if (x.w[1] & MASK_SIGN) == MASK_SIGN {
+    return zero;
+  }
+  let exponent = ((x.w[1] & MASK_EXP) >> 49) as i32 - DECIMAL_EXPONENT_BIAS_128;
+  let mut payload = ((x.w[1] as u128 & MASK_COEFF as u128) << 64) | x.w[0] as u128;
+  if exponent < 0 {
+    // The payload must be an integer, so all removed digits must be zeros.
+    let divisor = match 10_u128.checked_pow(exponent.unsigned_abs()) {
+      Some(divisor) if payload.is_multiple_of(divisor) => divisor,
+      _ => return zero,
+    };
+    payload /= divisor;
+  } else {
+    payload = match 10_u128.checked_pow(exponent as u32) {
+      Some(multiplier) if payload <= MAX_NAN_PAYLOAD_128 / multiplier => payload * multiplier,
+      _ => return zero,
+    };
+  }
+  if payload > MAX_NAN_PAYLOAD_128 {
+    return zero;
+  }
+  bid_nan_with_payload(high, payload)
+}
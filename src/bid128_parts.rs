@@ -0,0 +1,162 @@
+//! # Conversions to and from coefficient and exponent pairs and 128-bit integers
+
+use crate::bid_conf::{IdecFlags, IdecRound};
+use crate::bid_functions::*;
+use crate::bid_internal::*;
+use crate::bid128_common::*;
+use crate::bid128_noncomp::bid_nan_payload;
+use crate::{BidUint64, BidUint128};
+use core::cmp::Ordering;
+
+/// Maximum number of digits of 128-bit unsigned integer.
+pub(crate) const MAX_DIGITS_U128: u32 = 39;
+
+/// Returns the number of decimal digits of the value, zero has no digits.
+fn bid_digits_u128(x: u128) -> u32 {
+  if x == 0 { 0 } else { x.ilog10() + 1 }
+}
+
+/// Removes `n` least significant digits of the coefficient, rounding the result according to the rounding mode,
+/// returns the rounded coefficient and `true` when any of removed digits was not zero.
+pub(crate) fn bid_round_u128(coefficient: u128, n: u32, negative: bool, rounding: IdecRound) -> (u128, bool) {
+  if n == 0 {
+    return (coefficient, false);
+  }
+  // Coefficients have at most 39 digits, so removing more digits leaves a remainder below half.
+  let (quotient, remainder, half) = if n < MAX_DIGITS_U128 {
+    let divisor = 10_u128.pow(n);
+    (coefficient / divisor, coefficient % divisor, Some(divisor / 2))
+  } else {
+    (0, coefficient, None)
+  };
+  let to_half = half.map_or(Ordering::Less, |half| remainder.cmp(&half));
+  let increment = match rounding {
+    BID_ROUNDING_TO_NEAREST => to_half == Ordering::Greater || (to_half == Ordering::Equal && quotient % 2 == 1),
+    BID_ROUNDING_TIES_AWAY => to_half != Ordering::Less,
+    BID_ROUNDING_UP => remainder > 0 && !negative,
+    BID_ROUNDING_DOWN => remainder > 0 && negative,
+    _ => false,
+  };
+  (quotient + increment as u128, remainder > 0)
+}
+
+/// Converts the sign, coefficient and exponent to 128-bit decimal floating-point value (binary encoding),
+/// the value is equal to `coefficient * 10^exponent`, negative when `sign` is `true`.
+///
+/// Coefficients with more than 34 digits and values below the smallest subnormal exponent
+/// are rounded according to the rounding mode, values too large to be represented overflow.
+///
+/// # Examples
+///
+/// ```
+/// use decimus::{BID_ROUNDING_TO_NEAREST, BidUint128, bid128_from_parts};
+///
+/// let mut flags = 0;
+/// // NUMERIC(38,10) value 12.3456789012 stored as unscaled 123456789012 with scale 10.
+/// let x = bid128_from_parts(false, 123456789012, -10, BID_ROUNDING_TO_NEAREST, &mut flags);
+/// assert_eq!(BidUint128 { w: [123456789012, 0x302c000000000000] }, x);
+/// ```
+pub fn bid128_from_parts(sign: bool, coefficient: u128, exponent: i32, rounding: IdecRound, flags: &mut IdecFlags) -> BidUint128 {
+  let sgn: BidUint64 = if sign { MASK_SIGN } else { 0 };
+  let digits = bid_digits_u128(coefficient);
+  let mut expon = exponent as i64 + DECIMAL_EXPONENT_BIAS_128 as i64;
+  // Digits above the precision and below the minimum exponent are removed with single rounding.
+  let n = (digits as i64 - MAX_FORMAT_DIGITS_128 as i64).max(-expon).max(0);
+  let (coeff, inexact) = bid_round_u128(coefficient, n.min(MAX_DIGITS_U128 as i64) as u32, sign, rounding);
+  expon += n;
+  if cfg!(feature = "bid-set-status-flags") && inexact {
+    set_status_flags!(flags, BID_INEXACT_EXCEPTION);
+    if digits as i64 - 1 + (exponent as i64) < -(DECIMAL_EXPONENT_BIAS_128 as i64) + MAX_FORMAT_DIGITS_128 as i64 - 1 {
+      set_status_flags!(flags, BID_UNDERFLOW_EXCEPTION);
+    }
+  }
+  // Exponents far above the maximum overflow anyway, limit them to fit the exponent type.
+  let expon = expon.min(2 * DECIMAL_MAX_EXPON_128 as i64) as i32;
+  let mut res = BidUint128::default();
+  bid_get_bid128(&mut res, sgn, expon, coeff.into(), rounding, flags)
+}
+
+/// Converts a 128-bit decimal floating-point value (binary encoding) to the sign, coefficient and exponent,
+/// the value is equal to `coefficient * 10^exponent`, negative when the sign is `true`.
+///
+/// Non-canonical values are converted to zero coefficient. Infinities are converted to zero coefficient
+/// and exponent, and NaNs to their payload and zero exponent, use [bid128_is_finite](crate::bid128_is_finite)
+/// to distinguish them from finite values.
+///
+/// # Examples
+///
+/// ```
+/// use decimus::{BidUint128, bid128_to_parts};
+///
+/// let x = BidUint128 { w: [123456789012, 0xb02c000000000000] };
+/// assert_eq!((true, 123456789012, -10), bid128_to_parts(x));
+/// ```
+pub fn bid128_to_parts(x: BidUint128) -> (bool, u128, i32) {
+  let sign = (x.w[1] & MASK_SIGN) != 0;
+  if (x.w[1] & MASK_NAN) == MASK_NAN {
+    return (sign, bid_nan_payload(x), 0);
+  }
+  if (x.w[1] & MASK_INF) == MASK_INF {
+    return (sign, 0, 0);
+  }
+  let mut sgn: BidUint64 = 0;
+  let mut exponent: i32 = 0;
+  let mut coefficient = BidUint128::default();
+  unpack_bid128_value(&mut sgn, &mut exponent, &mut coefficient, x);
+  (sign, coefficient.into(), exponent - DECIMAL_EXPONENT_BIAS_128)
+}
+
+/// Converts signed 128-bit integer into 128-bit decimal floating-point value,
+/// integers with more than 34 digits are rounded according to the rounding mode.
+///
+/// Integers scaled by a power of ten, like SQL `NUMERIC` values, are converted with [bid128_from_parts].
+pub fn bid128_from_i128(x: i128, rounding: IdecRound, flags: &mut IdecFlags) -> BidUint128 {
+  bid128_from_parts(x < 0, x.unsigned_abs(), 0, rounding, flags)
+}
+
+/// Converts a 128-bit decimal floating-point value to signed 128-bit integer scaled by `10^scale`,
+/// like unscaled value of SQL `NUMERIC(p, scale)` or Arrow `Decimal128(p, scale)`,
+/// rounding the result according to the rounding mode.
+///
+/// Returns `None` for infinities, NaNs and values out of range of `i128`.
+///
+/// # Examples
+///
+/// ```
+/// use decimus::{BID_ROUNDING_TO_NEAREST, BidUint128, bid128_to_i128_scaled};
+///
+/// let mut flags = 0;
+/// let x = BidUint128 { w: [12345, 0x303c000000000000] }; // 123.45
+/// assert_eq!(Some(123450), bid128_to_i128_scaled(x, 3, BID_ROUNDING_TO_NEAREST, &mut flags));
+/// assert_eq!(Some(12), bid128_to_i128_scaled(x, -1, BID_ROUNDING_TO_NEAREST, &mut flags));
+/// ```
+pub fn bid128_to_i128_scaled(x: BidUint128, scale: i32, rounding: IdecRound, flags: &mut IdecFlags) -> Option<i128> {
+  let (sign, coefficient, exponent) = bid128_to_parts(x);
+  if (x.w[1] & MASK_INF) == MASK_INF {
+    if cfg!(feature = "bid-set-status-flags") {
+      set_status_flags!(flags, BID_INVALID_EXCEPTION);
+    }
+    return None;
+  }
+  let k = exponent as i64 + scale as i64;
+  let magnitude = if coefficient == 0 {
+    Some(0)
+  } else if k >= 0 {
+    10_u128.checked_pow(k.min(MAX_DIGITS_U128 as i64) as u32).and_then(|multiplier| coefficient.checked_mul(multiplier))
+  } else {
+    let (quotient, inexact) = bid_round_u128(coefficient, (-k).min(MAX_DIGITS_U128 as i64) as u32, sign, rounding);
+    if cfg!(feature = "bid-set-status-flags") && inexact {
+      set_status_flags!(flags, BID_INEXACT_EXCEPTION);
+    }
+    Some(quotient)
+  };
+  let value = match magnitude {
+    Some(m) if sign && m <= i128::MIN.unsigned_abs() => Some((m as i128).wrapping_neg()),
+    Some(m) if !sign && m <= i128::MAX as u128 => Some(m as i128),
+    _ => None,
+  };
+  if cfg!(feature = "bid-set-status-flags") && value.is_none() {
+    set_status_flags!(flags, BID_INVALID_EXCEPTION);
+  }
+  value
+}
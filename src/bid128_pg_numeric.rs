@@ -0,0 +1,215 @@
+//! # Conversions to and from PostgreSQL NUMERIC binary format
+//!
+//! PostgreSQL sends `numeric` values in binary format (`numeric_send`, binary `COPY`) as a header
+//! of four big-endian 16-bit fields: the number of digits, the weight of the first digit, the sign
+//! and the display scale, followed by the digits in base 10000, most significant first.
+//! The value is equal to `digit[i] * 10000^(weight - i)` summed over all digits,
+//! and the display scale is the number of decimal digits after the decimal point.
+
+use crate::BidUint128;
+use crate::bid_conf::{IdecFlags, IdecRound};
+use crate::bid_internal::MAX_FORMAT_DIGITS_128;
+use crate::bid128_common::*;
+use crate::bid128_parts::{bid128_from_parts, bid128_to_parts};
+use core::fmt;
+
+/// Maximum length in bytes of 128-bit decimal floating-point value in PostgreSQL NUMERIC binary format,
+/// 34 digits of the coefficient aligned to base 10000 take at most 10 digits.
+pub const BID128_MAX_PG_NUMERIC_LENGTH: usize = 28;
+
+/// Sign of positive values.
+const NUMERIC_POS: u16 = 0x0000;
+
+/// Sign of negative values.
+const NUMERIC_NEG: u16 = 0x4000;
+
+/// Sign of NaN.
+const NUMERIC_NAN: u16 = 0xC000;
+
+/// Sign of positive infinity.
+const NUMERIC_PINF: u16 = 0xD000;
+
+/// Sign of negative infinity.
+const NUMERIC_NINF: u16 = 0xF000;
+
+/// Mask of valid display scale bits.
+const NUMERIC_DSCALE_MASK: u16 = 0x3FFF;
+
+/// Display scale written by PostgreSQL for infinities.
+const NUMERIC_INF_DSCALE: u16 = 0x0020;
+
+/// Base of NUMERIC digits.
+const NBASE: u16 = 10000;
+
+/// Errors reported when decoding PostgreSQL NUMERIC binary format,
+/// the same as rejected by PostgreSQL itself.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PgNumericError {
+  /// Input is shorter than the header or its length does not match the number of digits.
+  InvalidLength,
+  /// Sign is not one of the values defined by PostgreSQL.
+  InvalidSign,
+  /// Display scale is out of range.
+  InvalidScale,
+  /// Digit is not in range 0 to 9999.
+  InvalidDigit,
+}
+
+impl fmt::Display for PgNumericError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      PgNumericError::InvalidLength => write!(f, "invalid length in external numeric value"),
+      PgNumericError::InvalidSign => write!(f, "invalid sign in external numeric value"),
+      PgNumericError::InvalidScale => write!(f, "invalid scale in external numeric value"),
+      PgNumericError::InvalidDigit => write!(f, "invalid digit in external numeric value"),
+    }
+  }
+}
+
+impl core::error::Error for PgNumericError {}
+
+/// Writes the header and digits of NUMERIC value into the buffer, returns the number of written bytes.
+fn bid_write_pg_numeric(buf: &mut [u8], digits: &[u16], weight: i16, sign: u16, dscale: u16) -> usize {
+  buf[0..2].copy_from_slice(&(digits.len() as u16).to_be_bytes());
+  buf[2..4].copy_from_slice(&weight.to_be_bytes());
+  buf[4..6].copy_from_slice(&sign.to_be_bytes());
+  buf[6..8].copy_from_slice(&dscale.to_be_bytes());
+  for (chunk, digit) in buf[8..].chunks_exact_mut(2).zip(digits) {
+    chunk.copy_from_slice(&digit.to_be_bytes());
+  }
+  8 + 2 * digits.len()
+}
+
+/// Converts a 128-bit decimal floating-point value (binary encoding) to PostgreSQL NUMERIC binary format,
+/// returns the written part of the buffer. The conversion of finite values is exact,
+/// the display scale is the number of digits after the decimal point.
+///
+/// NaNs are converted to NaN regardless of the sign, payload and signaling bit,
+/// negative zeros are converted to zero, because PostgreSQL has only one NaN and no negative zero.
+/// Non-canonical values are converted to zero.
+///
+/// # Examples
+///
+/// ```
+/// use decimus::{BID128_MAX_PG_NUMERIC_LENGTH, BidUint128, bid128_to_pg_numeric};
+///
+/// let mut buf = [0; BID128_MAX_PG_NUMERIC_LENGTH];
+/// let x = BidUint128 { w: [150, 0x303c000000000000] }; // 1.50
+/// assert_eq!([0, 2, 0, 0, 0, 0, 0, 2, 0, 1, 0x13, 0x88], bid128_to_pg_numeric(x, &mut buf));
+/// ```
+pub fn bid128_to_pg_numeric<const N: usize>(x: BidUint128, buf: &mut [u8; N]) -> &[u8] {
+  const { assert!(N >= BID128_MAX_PG_NUMERIC_LENGTH, "buffer is too small to hold any 128-bit decimal value") };
+  if (x.w[1] & MASK_NAN) == MASK_NAN {
+    let k = bid_write_pg_numeric(buf, &[], 0, NUMERIC_NAN, 0);
+    return &buf[..k];
+  }
+  if (x.w[1] & MASK_INF) == MASK_INF {
+    let sign = if (x.w[1] & MASK_SIGN) != 0 { NUMERIC_NINF } else { NUMERIC_PINF };
+    let k = bid_write_pg_numeric(buf, &[], 0, sign, NUMERIC_INF_DSCALE);
+    return &buf[..k];
+  }
+  let (sign, coefficient, exponent) = bid128_to_parts(x);
+  let dscale = (-exponent).max(0) as u16;
+  if coefficient == 0 {
+    let k = bid_write_pg_numeric(buf, &[], 0, NUMERIC_POS, dscale);
+    return &buf[..k];
+  }
+  // Coefficient is aligned to the exponent of the least significant digit in base 10000,
+  // shifted coefficient has at most 37 decimal digits.
+  let mut weight = exponent.div_euclid(4);
+  let mut value = coefficient * 10_u128.pow(exponent.rem_euclid(4) as u32);
+  while value.is_multiple_of(NBASE as u128) {
+    value /= NBASE as u128;
+    weight += 1;
+  }
+  let mut digits = [0; 10];
+  let mut n = 0;
+  while value > 0 {
+    digits[n] = (value % NBASE as u128) as u16;
+    value /= NBASE as u128;
+    n += 1;
+  }
+  digits[..n].reverse();
+  weight += n as i32 - 1;
+  let k = bid_write_pg_numeric(buf, &digits[..n], weight as i16, if sign { NUMERIC_NEG } else { NUMERIC_POS }, dscale);
+  &buf[..k]
+}
+
+/// Converts a value in PostgreSQL NUMERIC binary format to 128-bit decimal floating-point value (binary encoding),
+/// values with more than 34 significant digits are rounded according to the rounding mode.
+///
+/// The exponent of the result is equal to the negated display scale, when the value fits in 34 digits,
+/// so trailing zeros are preserved like in `1.50`. NaN is converted to quiet NaN.
+///
+/// # Examples
+///
+/// ```
+/// use decimus::{BID_ROUNDING_TO_NEAREST, BidUint128, bid128_from_pg_numeric};
+///
+/// let mut flags = 0;
+/// let bytes = [0, 2, 0, 0, 0, 0, 0, 2, 0, 1, 0x13, 0x88];
+/// let x = bid128_from_pg_numeric(&bytes, BID_ROUNDING_TO_NEAREST, &mut flags).unwrap();
+/// assert_eq!(BidUint128 { w: [150, 0x303c000000000000] }, x); // 1.50
+/// ```
+pub fn bid128_from_pg_numeric(bytes: &[u8], rounding: IdecRound, flags: &mut IdecFlags) -> Result<BidUint128, PgNumericError> {
+  let field = |i: usize| u16::from_be_bytes([bytes[2 * i], bytes[2 * i + 1]]);
+  if bytes.len() < 8 {
+    return Err(PgNumericError::InvalidLength);
+  }
+  let ndigits = field(0) as i16;
+  if ndigits < 0 || bytes.len() != 8 + 2 * ndigits as usize {
+    return Err(PgNumericError::InvalidLength);
+  }
+  let weight = field(1) as i16;
+  let sign = field(2);
+  let dscale = field(3);
+  match sign {
+    NUMERIC_POS | NUMERIC_NEG => {}
+    NUMERIC_NAN => return Ok(BidUint128 { w: [0, MASK_NAN] }),
+    NUMERIC_PINF => return Ok(BidUint128 { w: [0, MASK_INF] }),
+    NUMERIC_NINF => return Ok(BidUint128 { w: [0, MASK_SIGN | MASK_INF] }),
+    _ => return Err(PgNumericError::InvalidSign),
+  }
+  if (dscale & NUMERIC_DSCALE_MASK) != dscale {
+    return Err(PgNumericError::InvalidScale);
+  }
+  // Decimal digits are accumulated up to 38 digits, the remaining digits are removed,
+  // non-zero removed digits make the last digit non-zero (sticky digit), so the final rounding is not affected.
+  let mut coefficient: u128 = 0;
+  let mut consumed: i32 = 0;
+  let mut sticky = false;
+  for i in 0..ndigits as usize {
+    let digit = field(4 + i);
+    if digit >= NBASE {
+      return Err(PgNumericError::InvalidDigit);
+    }
+    for divisor in [1000, 100, 10, 1] {
+      let d = (digit / divisor % 10) as u128;
+      if coefficient < 10_u128.pow(37) {
+        coefficient = coefficient * 10 + d;
+        consumed += 1;
+      } else {
+        sticky |= d != 0;
+      }
+    }
+  }
+  let mut exponent = 4 * (weight as i32 + 1) - consumed;
+  let preferred = -(dscale as i32);
+  if sticky {
+    if coefficient.is_multiple_of(10) {
+      coefficient += 1;
+    }
+  } else if coefficient == 0 {
+    exponent = preferred;
+  } else {
+    while exponent < preferred && coefficient.is_multiple_of(10) {
+      coefficient /= 10;
+      exponent += 1;
+    }
+    while exponent > preferred && coefficient < 10_u128.pow(MAX_FORMAT_DIGITS_128 as u32 - 1) {
+      coefficient *= 10;
+      exponent -= 1;
+    }
+  }
+  Ok(bid128_from_parts(sign == NUMERIC_NEG, coefficient, exponent, rounding, flags))
+}
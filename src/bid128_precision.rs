@@ -0,0 +1,164 @@
+//! # Reduced-precision arithmetic
+
+use crate::bid_conf::*;
+use crate::bid_decimal_data::*;
+use crate::bid_functions::*;
+use crate::bid_internal::*;
+use crate::bid_round::*;
+use crate::bid128_add::{bid128_add, bid128_sub};
+use crate::bid128_common::*;
+use crate::bid128_div::bid128_div;
+use crate::bid128_fma::bid128_fma_with_tininess;
+use crate::bid128_mul::bid128_mul_with_tininess;
+use crate::bid128_noncomp::bid128_is_zero;
+use crate::{BidUint64, BidUint128};
+
+/// Returns the number of decimal digits in the coefficient.
+fn bid_coefficient_digits(c: BidUint128) -> i32 {
+  let mut q = 1;
+  while q < P34 && !unsigned_compare_gt_128!(bid_power10_table_128!(q), c) {
+    inc!(q);
+  }
+  q
+}
+
+/// Computes the operation rounded to `precision` significant digits.
+///
+/// The operation is first computed with rounding toward zero. When the truncated result
+/// is inexact and ends with digit 0 or 5, the last digit is incremented, so that
+/// the final rounding to `precision` digits is not affected by double rounding.
+fn bid_round_to_precision(precision: u32, rounding: IdecRound, tininess: IdecTininess, flags: &mut IdecFlags, op: impl Fn(IdecRound, &mut IdecFlags) -> BidUint128) -> BidUint128 {
+  let p = precision.clamp(1, P34 as u32) as i32;
+  if p == P34 {
+    return op(rounding, flags);
+  }
+  let mut z_flags = BID_EXACT_STATUS;
+  let res_z = op(BID_ROUNDING_TO_ZERO, &mut z_flags);
+  if (res_z.w[1] & MASK_SPECIAL) == MASK_SPECIAL || bid128_is_zero(res_z) {
+    // NaN, infinity and zero are not affected by the precision, but the sign of zero is
+    return op(rounding, flags);
+  }
+  let sign: BidUint64 = res_z.w[1] & MASK_SIGN;
+  let mut exp = (((res_z.w[1] & MASK_EXP) >> 49) as i32) - DECIMAL_EXPONENT_BIAS_128;
+  let mut c = BidUint128 { w: [res_z.w[0], res_z.w[1] & MASK_COEFF] };
+  let q = bid_coefficient_digits(c);
+  if q <= p {
+    // the result already fits in the requested precision
+    return op(rounding, flags);
+  }
+  // the last digit of the truncated coefficient, 2^64 mod 10 = 6
+  let last_digit = ((c.w[1] % 10) * 6 + c.w[0] % 10) % 10;
+  let mut is_inexact = (z_flags & BID_INEXACT_EXCEPTION) == BID_INEXACT_EXCEPTION;
+  if !is_inexact && (last_digit == 0 || last_digit == 5) {
+    // the inexact flag is not always reported, compare with the result rounded away from zero
+    let away = if sign == 0 { BID_ROUNDING_UP } else { BID_ROUNDING_DOWN };
+    let mut a_flags = BID_EXACT_STATUS;
+    let res_a = op(away, &mut a_flags);
+    is_inexact = res_a.w[0] != res_z.w[0] || res_a.w[1] != res_z.w[1];
+  }
+  if is_inexact && (last_digit == 0 || last_digit == 5) {
+    // sticky digit, the last digit is always below 9
+    inc!(c.w[0]);
+    if c.w[0] == 0 {
+      inc!(c.w[1]);
+    }
+  }
+
+  // round the coefficient to nearest, with p digits
+  let x = q - p;
+  let mut c_star: BidUint128 = Default::default();
+  let mut incr_exp = 0;
+  let mut is_midpoint_lt_even = false;
+  let mut is_midpoint_gt_even = false;
+  let mut is_inexact_lt_midpoint = false;
+  let mut is_inexact_gt_midpoint = false;
+  if q <= 18 {
+    bid_round64_2_18(q, x, c.w[0], &mut c_star.w[0], &mut incr_exp, &mut is_midpoint_lt_even, &mut is_midpoint_gt_even, &mut is_inexact_lt_midpoint, &mut is_inexact_gt_midpoint);
+  } else {
+    bid_round128_19_38(q, x, c, &mut c_star, &mut incr_exp, &mut is_midpoint_lt_even, &mut is_midpoint_gt_even, &mut is_inexact_lt_midpoint, &mut is_inexact_gt_midpoint);
+  }
+  let e = exp;
+  inc!(exp, x + incr_exp);
+
+  // correction from rounding to nearest to other rounding modes,
+  // the indicators are determined for the absolute value of the result
+  let away_from_zero = (sign == 0 && rounding == BID_ROUNDING_UP) || (sign != 0 && rounding == BID_ROUNDING_DOWN);
+  let toward_zero = rounding == BID_ROUNDING_TO_ZERO || (sign == 0 && rounding == BID_ROUNDING_DOWN) || (sign != 0 && rounding == BID_ROUNDING_UP);
+  if (away_from_zero && (is_inexact_lt_midpoint || is_midpoint_gt_even)) || (rounding == BID_ROUNDING_TIES_AWAY && is_midpoint_gt_even) {
+    // C* = C* + 1
+    inc!(c_star.w[0]);
+    if c_star.w[0] == 0 {
+      inc!(c_star.w[1]);
+    }
+    if c_star == bid_power10_table_128!(p) {
+      // C* = 10^p => rounding overflow
+      c_star = bid_power10_table_128!(p - 1);
+      inc!(exp);
+    }
+  } else if toward_zero && (is_midpoint_lt_even || is_inexact_gt_midpoint) {
+    // C* = C* - 1
+    dec!(c_star.w[0]);
+    if c_star.w[0] == 0xffffffffffffffff {
+      dec!(c_star.w[1]);
+    }
+    let mut upper = bid_power10_table_128!(p - 1);
+    dec!(upper.w[0]);
+    if c_star == upper {
+      // C* = 10^(p-1) - 1, the rounding crossed into the lower decade
+      c_star = bid_power10_table_128!(p);
+      dec!(c_star.w[0]);
+      dec!(exp);
+    }
+  }
+
+  *flags |= z_flags;
+  if is_inexact || is_midpoint_lt_even || is_midpoint_gt_even || is_inexact_lt_midpoint || is_inexact_gt_midpoint {
+    set_status_flags!(flags, BID_INEXACT_EXCEPTION);
+    let is_tiny = if tininess == BID_TININESS_BEFORE_ROUNDING {
+      q + e < EXPMIN + P34
+    } else {
+      bid_coefficient_digits(c_star) + exp < EXPMIN + P34
+    };
+    if is_tiny {
+      set_status_flags!(flags, BID_UNDERFLOW_EXCEPTION);
+    }
+  }
+  let mut res: BidUint128 = Default::default();
+  bid_get_bid128(&mut res, sign, exp + DECIMAL_EXPONENT_BIAS_128, c_star, rounding, flags)
+}
+
+/// Adds two 128-bit decimal floating-point values, rounding the result to `precision` significant digits.
+///
+/// The precision is clamped to range `1..=34`.
+pub fn bid128_add_with_precision(x: BidUint128, y: BidUint128, precision: u32, rounding: IdecRound, tininess: IdecTininess, flags: &mut IdecFlags) -> BidUint128 {
+  bid_round_to_precision(precision, rounding, tininess, flags, |rnd_mode, pfpsf| bid128_add(x, y, rnd_mode, pfpsf))
+}
+
+/// Subtracts two 128-bit decimal floating-point values, rounding the result to `precision` significant digits.
+///
+/// The precision is clamped to range `1..=34`.
+pub fn bid128_sub_with_precision(x: BidUint128, y: BidUint128, precision: u32, rounding: IdecRound, tininess: IdecTininess, flags: &mut IdecFlags) -> BidUint128 {
+  bid_round_to_precision(precision, rounding, tininess, flags, |rnd_mode, pfpsf| bid128_sub(x, y, rnd_mode, pfpsf))
+}
+
+/// Multiplies two 128-bit decimal floating-point values, rounding the result to `precision` significant digits.
+///
+/// The precision is clamped to range `1..=34`.
+pub fn bid128_mul_with_precision(x: BidUint128, y: BidUint128, precision: u32, rounding: IdecRound, tininess: IdecTininess, flags: &mut IdecFlags) -> BidUint128 {
+  bid_round_to_precision(precision, rounding, tininess, flags, |rnd_mode, pfpsf| bid128_mul_with_tininess(x, y, rnd_mode, tininess, pfpsf))
+}
+
+/// Divides two 128-bit decimal floating-point values, rounding the result to `precision` significant digits.
+///
+/// The precision is clamped to range `1..=34`.
+pub fn bid128_div_with_precision(x: BidUint128, y: BidUint128, precision: u32, rounding: IdecRound, tininess: IdecTininess, flags: &mut IdecFlags) -> BidUint128 {
+  bid_round_to_precision(precision, rounding, tininess, flags, |rnd_mode, pfpsf| bid128_div(x, y, rnd_mode, pfpsf))
+}
+
+/// Computes `x * y + z`, rounding the result to `precision` significant digits.
+///
+/// The precision is clamped to range `1..=34`.
+#[allow(clippy::too_many_arguments)]
+pub fn bid128_fma_with_precision(x: BidUint128, y: BidUint128, z: BidUint128, precision: u32, rounding: IdecRound, tininess: IdecTininess, flags: &mut IdecFlags) -> BidUint128 {
+  bid_round_to_precision(precision, rounding, tininess, flags, |rnd_mode, pfpsf| bid128_fma_with_tininess(x, y, z, rnd_mode, tininess, pfpsf))
+}
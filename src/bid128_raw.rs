@@ -0,0 +1,154 @@
+//! # Raw encoding format for debugging and round-trip
+
+use crate::bid_internal::*;
+use crate::bid128_common::*;
+use crate::bid128_noncomp::{ClassTypes, bid128_class};
+use crate::bid128_string::{ParseDecimalError, ParseDecimalErrorKind};
+use crate::{BidUint64, BidUint128};
+use core::fmt;
+
+/// Writes the raw encoding of a 128-bit decimal floating-point value as hexadecimal words,
+/// the most significant word first, like `0x3040000000000000_0000000000000001`.
+///
+/// # Examples
+///
+/// ```
+/// use decimus::{BidUint128, bid128_from_raw_string, bid128_write_raw};
+///
+/// let x = BidUint128 { w: [0x0000000000000001, 0x3040000000000000] };
+/// let mut s = String::new();
+/// bid128_write_raw(x, &mut s).unwrap();
+/// assert_eq!("0x3040000000000000_0000000000000001", s);
+/// assert_eq!(Ok(x), bid128_from_raw_string(&s));
+/// ```
+pub fn bid128_write_raw<W: fmt::Write>(x: BidUint128, writer: &mut W) -> fmt::Result {
+  write!(writer, "0x{:016x}_{:016x}", x.w[1], x.w[0])
+}
+
+/// Converts the raw encoding of a 128-bit decimal floating-point value to binary encoding.
+///
+/// Accepted are two hexadecimal words of 1 to 16 digits each, the most significant word first,
+/// in the format written by [bid128_write_raw] (`0x3040000000000000_0000000000000001`,
+/// the `0x` prefix is optional) or by the `Debug` implementation (`[3040000000000000 0000000000000001]`).
+pub fn bid128_from_raw_string(input: &str) -> Result<BidUint128, ParseDecimalError> {
+  let bytes = input.as_bytes();
+  if bytes.is_empty() {
+    return Err(ParseDecimalError::new(ParseDecimalErrorKind::Empty, 0));
+  }
+  let (mut p, separator, end) = if bytes[0] == b'[' {
+    (1, b' ', Some(b']'))
+  } else if bytes.len() > 1 && bytes[0] == b'0' && (bytes[1] == b'x' || bytes[1] == b'X') {
+    (2, b'_', None)
+  } else {
+    (0, b'_', None)
+  };
+  let high = bid_parse_hex_word(bytes, &mut p)?;
+  if bytes.get(p) != Some(&separator) {
+    return Err(ParseDecimalError::new(ParseDecimalErrorKind::InvalidDigit, p));
+  }
+  p += 1;
+  let low = bid_parse_hex_word(bytes, &mut p)?;
+  if let Some(end) = end {
+    if bytes.get(p) != Some(&end) {
+      return Err(ParseDecimalError::new(ParseDecimalErrorKind::InvalidDigit, p));
+    }
+    p += 1;
+  }
+  if p < bytes.len() {
+    return Err(ParseDecimalError::new(ParseDecimalErrorKind::TrailingCharacters, p));
+  }
+  Ok(BidUint128 { w: [low, high] })
+}
+
+/// Parses a hexadecimal word of 1 to 16 digits starting at position `p`, advances the position after the word.
+fn bid_parse_hex_word(bytes: &[u8], p: &mut usize) -> Result<BidUint64, ParseDecimalError> {
+  let start = *p;
+  let mut word: BidUint64 = 0;
+  while let Some(digit) = bytes.get(*p).and_then(|c| (*c as char).to_digit(16)) {
+    if *p - start == 16 {
+      return Err(ParseDecimalError::new(ParseDecimalErrorKind::InvalidDigit, *p));
+    }
+    word = (word << 4) | digit as BidUint64;
+    *p += 1;
+  }
+  if *p == start {
+    return Err(ParseDecimalError::new(ParseDecimalErrorKind::InvalidDigit, *p));
+  }
+  Ok(word)
+}
+
+/// Decoded fields of a 128-bit decimal floating-point value.
+///
+/// The `Display` implementation shows all fields in a single line, like
+/// `0x3040000000000000_0000000000000001 (sign: +, exponent: 0, biased exponent: 6176, coefficient: 1, class: PositiveNormal)`.
+///
+/// # Examples
+///
+/// ```
+/// use decimus::{BidUint128, ClassTypes, bid128_decode};
+///
+/// let decoded = bid128_decode(BidUint128 { w: [0x000000000000007b, 0xb03e000000000000] });
+/// assert!(decoded.negative);
+/// assert_eq!(-1, decoded.exponent);
+/// assert_eq!(123, decoded.coefficient);
+/// assert_eq!(ClassTypes::NegativeNormal, decoded.class);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DecodedBid128 {
+  /// Decoded value.
+  pub value: BidUint128,
+  /// `true` when the sign bit is set.
+  pub negative: bool,
+  /// Biased exponent, zero for infinities and NaNs.
+  pub biased_exponent: u32,
+  /// Unbiased exponent, zero for infinities and NaNs.
+  pub exponent: i32,
+  /// Coefficient of finite values or payload of NaNs, zero for infinities and non-canonical values.
+  pub coefficient: u128,
+  /// `true` when the coefficient (or payload) is canonical.
+  pub canonical: bool,
+  /// Class of the value.
+  pub class: ClassTypes,
+}
+
+impl fmt::Display for DecodedBid128 {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    bid128_write_raw(self.value, f)?;
+    write!(
+      f,
+      " (sign: {}, exponent: {}, biased exponent: {}, coefficient: {}{}, class: {:?})",
+      if self.negative { '-' } else { '+' },
+      self.exponent,
+      self.biased_exponent,
+      self.coefficient,
+      if self.canonical { "" } else { " (non-canonical)" },
+      self.class
+    )
+  }
+}
+
+/// Decodes the sign, exponent, coefficient and class of a 128-bit decimal floating-point value.
+pub fn bid128_decode(x: BidUint128) -> DecodedBid128 {
+  let class = bid128_class(x);
+  let negative = (x.w[1] & MASK_SIGN) == MASK_SIGN;
+  let (biased_exponent, coefficient, limit) = if (x.w[1] & MASK_NAN) == MASK_NAN {
+    (0, ((x.w[1] as u128 & 0x00003fffffffffff) << 64) | x.w[0] as u128, 10_u128.pow(33))
+  } else if (x.w[1] & MASK_INF) == MASK_INF {
+    (0, 0, 1)
+  } else if (x.w[1] & MASK_STEERING_BITS) == MASK_STEERING_BITS {
+    // The coefficient in this encoding is always greater than the largest canonical coefficient.
+    (((x.w[1] >> 47) & 0x3fff) as u32, u128::MAX, 10_u128.pow(34))
+  } else {
+    (((x.w[1] >> 49) & 0x3fff) as u32, ((x.w[1] as u128 & MASK_COEFF as u128) << 64) | x.w[0] as u128, 10_u128.pow(34))
+  };
+  let canonical = coefficient < limit;
+  DecodedBid128 {
+    value: x,
+    negative,
+    biased_exponent,
+    exponent: if (x.w[1] & MASK_INF) == MASK_INF { 0 } else { biased_exponent as i32 - DECIMAL_EXPONENT_BIAS_128 },
+    coefficient: if canonical { coefficient } else { 0 },
+    canonical,
+    class,
+  }
+}
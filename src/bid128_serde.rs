@@ -0,0 +1,205 @@
+//! # Serialization and deserialization with serde
+//!
+//! By default, [BidUint128] is serialized as a string in the same format as produced
+//! by [bid128_to_string](crate::bid128_to_string), like `"+12345E-2"`, so no precision is lost
+//! in any data format. Other representations are selected with the `#[serde(with = "...")]` attribute
+//! using one of the modules below:
+//!
+//! - [string] - string, the default representation,
+//! - [number] - JSON number without precision loss, like `123.45` (requires `serde-json-number` feature),
+//! - [bytes] - raw 16 bytes of the binary encoding in little-endian order, for compact binary formats.
+//!
+//! Deserialized strings are parsed with [bid128_from_string_strict](crate::bid128_from_string_strict),
+//! values with more than 34 significant digits are rounded to nearest with ties to even.
+//!
+//! # Examples
+//!
+//! ```
+//! use decimus::BidUint128;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Payment {
+//!   amount: BidUint128,
+//!   #[serde(with = "decimus::bid128_serde::bytes")]
+//!   fee: BidUint128,
+//! }
+//! ```
+
+use crate::bid_functions::BID_ROUNDING_TO_NEAREST;
+use crate::bid128_string::{BID128_MAX_STRING_LENGTH, bid128_from_string_strict, bid128_to_str_buf};
+use crate::{BidUint128, IdecFlags};
+use core::fmt;
+use serde::de::{self, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+impl Serialize for BidUint128 {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    string::serialize(self, serializer)
+  }
+}
+
+impl<'de> Deserialize<'de> for BidUint128 {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    string::deserialize(deserializer)
+  }
+}
+
+/// Converts the deserialized string, malformed input is reported as an error.
+fn bid_parse<E: de::Error>(value: &str) -> Result<BidUint128, E> {
+  let mut flags: IdecFlags = 0;
+  bid128_from_string_strict(value, BID_ROUNDING_TO_NEAREST, &mut flags).map_err(E::custom)
+}
+
+/// Serialization of [BidUint128] as a string, like `"+12345E-2"`.
+///
+/// # Examples
+///
+/// ```
+/// use decimus::BidUint128;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Price {
+///   #[serde(with = "decimus::bid128_serde::string")]
+///   value: BidUint128,
+/// }
+/// ```
+pub mod string {
+  use super::*;
+
+  /// Serializes the value as a string.
+  pub fn serialize<S: Serializer>(x: &BidUint128, serializer: S) -> Result<S::Ok, S::Error> {
+    let mut buf = [0; BID128_MAX_STRING_LENGTH];
+    serializer.serialize_str(bid128_to_str_buf(*x, &mut buf))
+  }
+
+  /// Deserializes the value from a string.
+  pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BidUint128, D::Error> {
+    deserializer.deserialize_str(StringVisitor)
+  }
+
+  struct StringVisitor;
+
+  impl Visitor<'_> for StringVisitor {
+    type Value = BidUint128;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+      write!(f, "a string containing a decimal number")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+      bid_parse(value)
+    }
+  }
+}
+
+/// Serialization of [BidUint128] as a JSON number without precision loss, like `123.45`.
+///
+/// The number is written in scientific string format, see [bid128_to_sci_string](crate::bid128_to_sci_string),
+/// so the exponent of the value is preserved. Infinities and NaNs can not be serialized.
+///
+/// Works only with `serde_json`, the number is passed unchanged through its `RawValue`, so the `arbitrary_precision`
+/// feature of `serde_json` is not needed and numbers elsewhere in the application are not affected.
+///
+/// # Examples
+///
+/// ```
+/// use decimus::{BidUint128, dec};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Price {
+///   #[serde(with = "decimus::bid128_serde::number")]
+///   value: BidUint128,
+/// }
+///
+/// let json = serde_json::to_string(&Price { value: dec!(123.450) }).unwrap();
+/// assert_eq!(r#"{"value":123.450}"#, json);
+/// ```
+#[cfg(feature = "serde-json-number")]
+pub mod number {
+  use super::*;
+  use crate::bid128_format::bid128_to_sci_string;
+  use crate::bid128_noncomp::bid128_is_finite;
+  use alloc::boxed::Box;
+  use serde::de::{Error as _, Unexpected};
+  use serde::ser::Error as _;
+  use serde_json::value::RawValue;
+
+  /// Serializes the value as a number.
+  pub fn serialize<S: Serializer>(x: &BidUint128, serializer: S) -> Result<S::Ok, S::Error> {
+    if !bid128_is_finite(*x) {
+      return Err(S::Error::custom("infinity and NaN can not be serialized as a number"));
+    }
+    let number = RawValue::from_string(bid128_to_sci_string(*x)).map_err(S::Error::custom)?;
+    number.serialize(serializer)
+  }
+
+  /// Deserializes the value from a number.
+  pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BidUint128, D::Error> {
+    let number = Box::<RawValue>::deserialize(deserializer)?;
+    let text = number.get();
+    if !text.starts_with(|c: char| c == '-' || c.is_ascii_digit()) {
+      return Err(D::Error::invalid_type(Unexpected::Other(text), &"a JSON number"));
+    }
+    bid_parse(text)
+  }
+}
+
+/// Serialization of [BidUint128] as 16 bytes of the binary encoding in little-endian order,
+/// see [BidUint128::to_le_bytes].
+///
+/// Data formats without native support for bytes, like JSON, represent them as a sequence of 16 numbers.
+///
+/// # Examples
+///
+/// ```
+/// use decimus::BidUint128;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Price {
+///   #[serde(with = "decimus::bid128_serde::bytes")]
+///   value: BidUint128,
+/// }
+/// ```
+pub mod bytes {
+  use super::*;
+
+  /// Serializes the value as bytes.
+  pub fn serialize<S: Serializer>(x: &BidUint128, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_bytes(&x.to_le_bytes())
+  }
+
+  /// Deserializes the value from bytes or a sequence of bytes.
+  pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BidUint128, D::Error> {
+    deserializer.deserialize_bytes(BytesVisitor)
+  }
+
+  struct BytesVisitor;
+
+  impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = BidUint128;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+      write!(f, "16 bytes of a decimal number")
+    }
+
+    fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Self::Value, E> {
+      let bytes: [u8; 16] = value.try_into().map_err(|_| E::invalid_length(value.len(), &self))?;
+      Ok(BidUint128::from_le_bytes(bytes))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+      let mut bytes = [0; 16];
+      for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(i, &self))?;
+      }
+      if seq.next_element::<u8>()?.is_some() {
+        return Err(de::Error::invalid_length(17, &self));
+      }
+      Ok(BidUint128::from_le_bytes(bytes))
+    }
+  }
+}
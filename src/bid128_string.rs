@@ -7,18 +7,111 @@ use crate::bid128::*;
 use crate::bid128_2_str_macros::*;
 use crate::bid128_2_str_tables::*;
 use crate::bid128_common::*;
+use crate::bid128_noncomp::{MAX_NAN_PAYLOAD_128, bid_nan_payload, bid_nan_with_payload, bid128_is_inf};
 use crate::{BidUint32, BidUint64, BidUint128};
+#[cfg(feature = "alloc")]
 use alloc::string::String;
-use alloc::vec;
+use core::cell::Cell;
+use core::fmt;
+use core::ops::Index;
 
 /// Maximum number of digits in string format for 128-bit value.
 const MAX_FORMAT_DIGITS_128: i32 = 34;
 
-/// Maximum number of characters in the input string.
-const MAX_STRING_DIGITS_128: i32 = 100;
+/// Number of significant digits kept while parsing, all coefficient digits plus the rounding digit.
+const MAX_PARSED_DIGITS_128: usize = MAX_FORMAT_DIGITS_128 as usize + 1;
 
-/// Maximum size of the input buffer for characters.
-const MAX_BUFFER_SIZE: usize = MAX_STRING_DIGITS_128 as usize;
+/// Maximum number of characters in string format of 128-bit value
+/// (sign, 34 digits, exponent mark, exponent sign and 4 exponent digits).
+pub const BID128_MAX_STRING_LENGTH: usize = 41;
+
+/// Input characters, reading past the end returns the null character, like in C string.
+///
+/// Unicode minus signs and full-width digits can be read as their ASCII equivalents without copying the input,
+/// then the index of a character differs from its byte position in the input.
+pub(crate) struct CharSequence<'a> {
+  /// Input bytes.
+  input: &'a [u8],
+  /// Set when Unicode minus signs and full-width digits are read as ASCII characters.
+  unicode: bool,
+  /// Number of characters.
+  len: usize,
+  /// Index of the last read character and its byte position, characters are read mostly forward.
+  cursor: Cell<(usize, usize)>,
+}
+
+impl<'a> CharSequence<'a> {
+  /// Creates a sequence where each byte of the input is a character.
+  pub(crate) fn new(input: &'a [u8]) -> Self {
+    Self {
+      input,
+      unicode: false,
+      len: input.len(),
+      cursor: Cell::new((0, 0)),
+    }
+  }
+
+  /// Creates a sequence where Unicode minus signs and full-width digits are read as ASCII characters.
+  fn new_unicode(input: &'a [u8]) -> Self {
+    let mut len = 0;
+    let mut p = 0;
+    while p < input.len() {
+      p += bid_unicode_width(&input[p..]);
+      len += 1;
+    }
+    Self {
+      input,
+      unicode: true,
+      len,
+      cursor: Cell::new((0, 0)),
+    }
+  }
+
+  /// Returns the number of characters.
+  fn len(&self) -> usize {
+    self.len
+  }
+
+  /// Returns `true` when there are no characters.
+  fn is_empty(&self) -> bool {
+    self.len == 0
+  }
+
+  /// Returns the byte position in the input of the character with specified index.
+  fn position(&self, index: usize) -> usize {
+    if !self.unicode {
+      return index;
+    }
+    let (mut i, mut p) = self.cursor.get();
+    while i < index && p < self.input.len() {
+      p += bid_unicode_width(&self.input[p..]);
+      i += 1;
+    }
+    while i > index {
+      p -= if p >= 3 && bid_unicode_replacement(&self.input[p - 3..]).is_some() { 3 } else { 1 };
+      i -= 1;
+    }
+    self.cursor.set((i, p));
+    p
+  }
+}
+
+impl Index<usize> for CharSequence<'_> {
+  type Output = u8;
+
+  fn index(&self, index: usize) -> &Self::Output {
+    if index >= self.len {
+      return &0;
+    }
+    let p = self.position(index);
+    if self.unicode
+      && let Some(c) = bid_unicode_replacement(&self.input[p..])
+    {
+      return c;
+    }
+    &self.input[p]
+  }
+}
 
 /// Utility macro that assigns a value to table element.
 macro_rules! set {
@@ -34,16 +127,55 @@ macro_rules! set {
 
 /// Converts a 128-bit decimal floating-point value (binary encoding)
 /// to string format (decimal character sequence).
+#[cfg(feature = "alloc")]
 pub fn bid128_to_string(x: BidUint128) -> String {
+  let mut buf = [0; BID128_MAX_STRING_LENGTH];
+  String::from(bid128_to_str_buf(x, &mut buf))
+}
+
+/// Converts a 128-bit decimal floating-point value (binary encoding)
+/// to string format (decimal character sequence) stored in the provided buffer.
+///
+/// The buffer must be at least [BID128_MAX_STRING_LENGTH] bytes long,
+/// this is checked at compile time.
+///
+/// # Examples
+///
+/// ```
+/// use decimus::{BID128_MAX_STRING_LENGTH, BidUint128, bid128_to_str_buf};
+///
+/// let mut buf = [0; BID128_MAX_STRING_LENGTH];
+/// assert_eq!("+123E-2", bid128_to_str_buf(BidUint128 { w: [123, 0x303c000000000000] }, &mut buf));
+/// ```
+///
+/// ```compile_fail
+/// use decimus::{BidUint128, bid128_to_str_buf};
+///
+/// let mut buf = [0; 16];
+/// bid128_to_str_buf(BidUint128 { w: [123, 0x303c000000000000] }, &mut buf);
+/// ```
+pub fn bid128_to_str_buf<const N: usize>(x: BidUint128, buf: &mut [u8; N]) -> &str {
+  const { assert!(N >= BID128_MAX_STRING_LENGTH, "buffer is too small to hold any 128-bit decimal value") };
+  let k = bid128_to_chars(x, buf);
+  core::str::from_utf8(&buf[..k]).expect("formatted decimal value contains only ASCII characters")
+}
+
+/// Writes a 128-bit decimal floating-point value (binary encoding)
+/// in string format (decimal character sequence) to the provided writer.
+pub fn bid128_write_string<W: fmt::Write>(x: BidUint128, writer: &mut W) -> fmt::Result {
+  let mut buf = [0; BID128_MAX_STRING_LENGTH];
+  writer.write_str(bid128_to_str_buf(x, &mut buf))
+}
+
+/// Writes the characters of a 128-bit decimal floating-point value to the buffer,
+/// returns the number of written characters.
+fn bid128_to_chars(x: BidUint128, str: &mut [u8]) -> usize {
   let x_sign: BidUint64;
   let mut x_exp: BidUint64;
   let mut exp: i32; // Unbiased exponent.
-  let mut str: [u8; MAX_BUFFER_SIZE + 1] = [0; MAX_BUFFER_SIZE + 1]; // Output characters.
   let mut k: usize = 0; // Number of characters in the string (index of the next free position in string).
   let mut c1: BidUint128 = BidUint128::default(); // Note: c1.w[1], c1.w[0] represent x_signif_hi, x_signif_lo (all are BID_UINT64)
   let ind: i32;
-  let mut midi: [BidUint32; 12] = [0; 12];
-  let mut ptr: usize;
 
   // Check for NaN or Infinity.
   if (x.w[1] & MASK_SPECIAL) == MASK_SPECIAL {
@@ -65,6 +197,17 @@ pub fn bid128_to_string(x: BidUint128) -> String {
         set!(b'a', str, k);
         set!(b'N', str, k);
       }
+      // Print the payload, if any.
+      let payload = bid_nan_payload(x);
+      if payload > 0 {
+        k = bid_coefficient_to_chars(
+          BidUint128 {
+            w: [payload as BidUint64, (payload >> 64) as BidUint64],
+          },
+          str,
+          k,
+        );
+      }
     } else {
       // 'x' is not a NaN, so it must be infinity
       if (x.w[1] & MASK_SIGN) == 0x0 {
@@ -81,7 +224,7 @@ pub fn bid128_to_string(x: BidUint128) -> String {
         set!(b'f', str, k);
       }
     }
-    str[..k].iter().map(|c| *c as char).collect()
+    k
   } else if (x.w[1] & MASK_COEFF) == 0 && x.w[0] == 0 {
     // 'x' is 0
     if x.w[1] & MASK_SIGN > 0 {
@@ -117,7 +260,7 @@ pub fn bid128_to_string(x: BidUint128) -> String {
       exp /= 10;
     }
     set!((exp % 10) as u8 + b'0', str, k, pos);
-    str[..k].iter().map(|c| *c as char).collect()
+    k
   } else {
     // 'x' is not special and is not zero.
     // Unpack 'x'.
@@ -143,57 +286,7 @@ pub fn bid128_to_string(x: BidUint128) -> String {
     if c1.w[1] > 0x0001ed09bead87c0 || (c1.w[1] == 0x0001ed09bead87c0 && c1.w[0] > 0x378d8e63ffffffff) || (x.w[1] & 0x6000000000000000) == 0x6000000000000000 || (c1.w[1] == 0 && c1.w[0] == 0) {
       set!(b'0', str, k);
     } else {
-      /* ****************************************************
-      This takes a bid coefficient in c1.w[1],c1.w[0]
-      and put the converted character sequence at location
-      starting at &(str[k]). The function returns the number
-      of MiDi returned. Note that the character sequence
-      does not have leading zeros EXCEPT when the input is of
-      zero value. It will then output 1 character '0'
-      The algorithm essentailly tries first to get a sequence of
-      Millenial Digits "MiDi" and then uses table lookup to get the
-      character strings of these MiDis.
-      **************************************************** */
-      /* Algorithm first decompose possibly 34 digits in hi and lo
-      18 digits. (The high can have at most 16 digits). It then
-      uses macro that handle 18 digit portions.
-      The first step is to get hi and lo such that
-      2^(64) c1.w[1] + c1.w[0] = hi * 10^18  + lo,   0 <= lo < 10^18.
-      We use a table lookup method to obtain the hi and lo 18 digits.
-      [c1.w[1],c1.w[0]] = c_8 2^(107) + c_7 2^(101) + ... + c_0 2^(59) + d
-      where 0 <= d < 2^59 and each c_j has 6 bits. Because d fits in
-      18 digits,  we set hi = 0, and lo = d to begin with.
-      We then retrieve from a table, for j = 0, 1, ..., 8
-      that gives us A and B where c_j 2^(59+6j) = A * 10^18 + B.
-      hi += A ; lo += B; After each accumulation into lo, we normalize
-      immediately. So at the end, we have the decomposition as we need. */
-
-      let mut lo_18dig: BidUint64 = (c1.w[0] << 5) >> 5;
-      let mut hi_18dig: BidUint64 = 0;
-      let mut tmp = (c1.w[0] >> 59) + (c1.w[1] << 5);
-      let mut i = 0;
-      let mut a;
-      while tmp > 0 {
-        a = ((tmp & 0x000000000000003F) as i32) << 1;
-        tmp >>= 6;
-        hi_18dig += MOD10_18_TBL[i][a as usize];
-        a += 1;
-        lo_18dig += MOD10_18_TBL[i][a as usize];
-        i += 1;
-        __l0_normalize_10to18!(hi_18dig, lo_18dig);
-      }
-      ptr = 0;
-      if hi_18dig == 0 {
-        __l0_split_midi_6_lead!(lo_18dig, midi, ptr);
-      } else {
-        __l0_split_midi_6_lead!(hi_18dig, midi, ptr);
-        __l0_split_midi_6!(lo_18dig, midi, ptr);
-      }
-
-      __l0_midi2str_lead!(midi[0], str, k);
-      for i in 1..ptr {
-        __l0_midi2str!(midi[i], str, k);
-      }
+      k = bid_coefficient_to_chars(c1, str, k);
     }
 
     // Print E and sign of the exponent.
@@ -236,13 +329,623 @@ pub fn bid128_to_string(x: BidUint128) -> String {
         set!(BID_CHAR_TABLE3[(ind + 2) as usize], str, k);
       }
     }
-    str[..k].iter().map(|c| *c as char).collect()
+    k
   }
 }
 
+/// Writes the decimal digits of the non-zero canonical coefficient to the string starting at position `k`,
+/// returns the position after the last written character.
+pub(crate) fn bid_coefficient_to_chars(c1: BidUint128, str: &mut [u8], mut k: usize) -> usize {
+  let mut midi: [BidUint32; 12] = [0; 12];
+  let mut ptr: usize;
+
+  /* ****************************************************
+  This takes a bid coefficient in c1.w[1],c1.w[0]
+  and put the converted character sequence at location
+  starting at &(str[k]). The function returns the number
+  of MiDi returned. Note that the character sequence
+  does not have leading zeros EXCEPT when the input is of
+  zero value. It will then output 1 character '0'
+  The algorithm essentailly tries first to get a sequence of
+  Millenial Digits "MiDi" and then uses table lookup to get the
+  character strings of these MiDis.
+  **************************************************** */
+  /* Algorithm first decompose possibly 34 digits in hi and lo
+  18 digits. (The high can have at most 16 digits). It then
+  uses macro that handle 18 digit portions.
+  The first step is to get hi and lo such that
+  2^(64) c1.w[1] + c1.w[0] = hi * 10^18  + lo,   0 <= lo < 10^18.
+  We use a table lookup method to obtain the hi and lo 18 digits.
+  [c1.w[1],c1.w[0]] = c_8 2^(107) + c_7 2^(101) + ... + c_0 2^(59) + d
+  where 0 <= d < 2^59 and each c_j has 6 bits. Because d fits in
+  18 digits,  we set hi = 0, and lo = d to begin with.
+  We then retrieve from a table, for j = 0, 1, ..., 8
+  that gives us A and B where c_j 2^(59+6j) = A * 10^18 + B.
+  hi += A ; lo += B; After each accumulation into lo, we normalize
+  immediately. So at the end, we have the decomposition as we need. */
+
+  let mut lo_18dig: BidUint64 = (c1.w[0] << 5) >> 5;
+  let mut hi_18dig: BidUint64 = 0;
+  let mut tmp = (c1.w[0] >> 59) + (c1.w[1] << 5);
+  let mut i = 0;
+  let mut a;
+  while tmp > 0 {
+    a = ((tmp & 0x000000000000003F) as i32) << 1;
+    tmp >>= 6;
+    hi_18dig += MOD10_18_TBL[i][a as usize];
+    a += 1;
+    lo_18dig += MOD10_18_TBL[i][a as usize];
+    i += 1;
+    __l0_normalize_10to18!(hi_18dig, lo_18dig);
+  }
+  ptr = 0;
+  if hi_18dig == 0 {
+    __l0_split_midi_6_lead!(lo_18dig, midi, ptr);
+  } else {
+    __l0_split_midi_6_lead!(hi_18dig, midi, ptr);
+    __l0_split_midi_6!(lo_18dig, midi, ptr);
+  }
+
+  __l0_midi2str_lead!(midi[0], str, k);
+  for i in 1..ptr {
+    __l0_midi2str!(midi[i], str, k);
+  }
+  k
+}
+
 /// Converts a value represented in string format (decimal character sequence)
 /// to 128-bit decimal floating-point format (binary encoding).
+///
+/// Input containing non-ASCII characters is converted to quiet NaN.
 pub fn bid128_from_string(input: &str, rounding: IdecRound, flags: &mut IdecFlags) -> BidUint128 {
+  if !input.is_ascii() {
+    return BidUint128 { w: [0, 0x7c00000000000000] };
+  }
+  bid128_from_chars(CharSequence::new(input.as_bytes()), rounding, flags)
+}
+
+/// Converts a value represented in string format (decimal character sequence)
+/// at the beginning of the byte slice to 128-bit decimal floating-point format (binary encoding).
+///
+/// Returns the converted value and the number of consumed bytes, like `strtod` in C.
+/// When the input does not start with a number, quiet NaN is returned and no bytes are consumed.
+///
+/// # Examples
+///
+/// ```
+/// use decimus::{BID_ROUNDING_TO_NEAREST, bid128_from_bytes, bid128_from_string};
+///
+/// let mut flags = 0;
+/// let (x, consumed) = bid128_from_bytes(b"12.50;EUR", BID_ROUNDING_TO_NEAREST, &mut flags);
+/// assert_eq!(bid128_from_string("12.50", BID_ROUNDING_TO_NEAREST, &mut flags), x);
+/// assert_eq!(5, consumed);
+/// ```
+pub fn bid128_from_bytes(input: &[u8], rounding: IdecRound, flags: &mut IdecFlags) -> (BidUint128, usize) {
+  let length = bid_number_length(input);
+  if length == 0 {
+    return (BidUint128 { w: [0, 0x7c00000000000000] }, 0);
+  }
+  (bid128_from_chars(CharSequence::new(&input[..length]), rounding, flags), length)
+}
+
+/// Kinds of errors reported by the strict parser.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ParseDecimalErrorKind {
+  /// Input string is empty.
+  Empty,
+  /// Invalid character found, or the input ended unexpectedly.
+  InvalidDigit,
+  /// Exponent has more than 6 significant digits.
+  ExponentOverflow,
+  /// NaN payload has more than 33 significant digits.
+  PayloadOverflow,
+  /// Characters found after a valid number.
+  TrailingCharacters,
+  /// Non-ASCII character found.
+  NonAsciiCharacter,
+  /// Group separator found at invalid position.
+  InvalidGrouping,
+  /// Value can not be represented exactly, it would be rounded or overflow.
+  Inexact,
+}
+
+/// Error returned by the strict parser.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ParseDecimalError {
+  /// Kind of the error.
+  kind: ParseDecimalErrorKind,
+  /// Byte position in the input where the error was detected.
+  position: usize,
+}
+
+impl ParseDecimalError {
+  pub(crate) fn new(kind: ParseDecimalErrorKind, position: usize) -> Self {
+    Self { kind, position }
+  }
+
+  /// Returns the kind of the error.
+  pub fn kind(&self) -> ParseDecimalErrorKind {
+    self.kind
+  }
+
+  /// Returns the byte position in the input where the error was detected,
+  /// equal to the input length when the input ended unexpectedly.
+  pub fn position(&self) -> usize {
+    self.position
+  }
+}
+
+impl fmt::Display for ParseDecimalError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self.kind {
+      ParseDecimalErrorKind::Empty => write!(f, "cannot parse decimal from empty string"),
+      ParseDecimalErrorKind::InvalidDigit => write!(f, "invalid digit found at position {}", self.position),
+      ParseDecimalErrorKind::ExponentOverflow => write!(f, "exponent too large at position {}", self.position),
+      ParseDecimalErrorKind::PayloadOverflow => write!(f, "NaN payload too large at position {}", self.position),
+      ParseDecimalErrorKind::TrailingCharacters => write!(f, "unexpected characters at position {}", self.position),
+      ParseDecimalErrorKind::NonAsciiCharacter => write!(f, "non-ASCII character found at position {}", self.position),
+      ParseDecimalErrorKind::InvalidGrouping => write!(f, "invalid digit grouping at position {}", self.position),
+      ParseDecimalErrorKind::Inexact => write!(f, "value can not be represented exactly"),
+    }
+  }
+}
+
+impl core::error::Error for ParseDecimalError {}
+
+/// Converts a value represented in string format (decimal character sequence)
+/// to 128-bit decimal floating-point format (binary encoding), rejecting malformed input.
+///
+/// Accepted are numbers with optional sign, radix point and exponent,
+/// and case-insensitive `Inf`, `Infinity`, `NaN` and `sNaN` with optional sign,
+/// NaNs may be followed by a payload of at most 33 digits, like `NaN123`.
+/// White spaces and non-ASCII characters are not allowed.
+///
+/// # Examples
+///
+/// ```
+/// use decimus::{BID_ROUNDING_TO_NEAREST, ParseDecimalErrorKind, bid128_from_string_strict};
+///
+/// let mut flags = 0;
+/// assert!(bid128_from_string_strict("-12.5E3", BID_ROUNDING_TO_NEAREST, &mut flags).is_ok());
+/// let err = bid128_from_string_strict("12,5", BID_ROUNDING_TO_NEAREST, &mut flags).unwrap_err();
+/// assert_eq!(ParseDecimalErrorKind::TrailingCharacters, err.kind());
+/// assert_eq!(2, err.position());
+/// ```
+pub fn bid128_from_string_strict(input: &str, rounding: IdecRound, flags: &mut IdecFlags) -> Result<BidUint128, ParseDecimalError> {
+  bid128_from_chars_strict(CharSequence::new(input.as_bytes()), rounding, flags)
+}
+
+/// Converts a value represented in string format (decimal character sequence)
+/// to 128-bit decimal floating-point format (binary encoding), rejecting malformed input.
+///
+/// Accepted is the same input as in [bid128_from_string_strict], but Unicode minus sign (U+2212)
+/// and full-width digits (U+FF10 to U+FF19) are accepted as their ASCII equivalents.
+/// Error positions are byte positions in the input.
+///
+/// # Examples
+///
+/// ```
+/// use decimus::{BID_ROUNDING_TO_NEAREST, bid128_from_string, bid128_from_string_unicode};
+///
+/// let mut flags = 0;
+/// let x = bid128_from_string_unicode("−１２.５", BID_ROUNDING_TO_NEAREST, &mut flags);
+/// assert_eq!(Ok(bid128_from_string("-12.5", BID_ROUNDING_TO_NEAREST, &mut flags)), x);
+/// ```
+pub fn bid128_from_string_unicode(input: &str, rounding: IdecRound, flags: &mut IdecFlags) -> Result<BidUint128, ParseDecimalError> {
+  bid128_from_chars_strict(CharSequence::new_unicode(input.as_bytes()), rounding, flags)
+}
+
+/// Converts a character sequence to 128-bit decimal floating-point format (binary encoding), rejecting malformed input.
+fn bid128_from_chars_strict(ps: CharSequence, rounding: IdecRound, flags: &mut IdecFlags) -> Result<BidUint128, ParseDecimalError> {
+  if let Err(err) = bid_validate_number(&ps) {
+    return Err(ParseDecimalError::new(err.kind, ps.position(err.position)));
+  }
+  Ok(bid128_from_chars(ps, rounding, flags))
+}
+
+/// Converts a value represented in string format (decimal character sequence)
+/// to 128-bit decimal floating-point format (binary encoding) in const context.
+///
+/// Accepted is the same input as in [bid128_from_string_strict], except non-ASCII characters,
+/// so exponents with more than 6 significant digits are rejected.
+/// Values with more than 34 significant digits or with exponent below the minimum
+/// are rounded to nearest with ties to even.
+///
+/// # Panics
+///
+/// Panics when the input is rejected by [bid128_from_string_strict] or when the value is too large
+/// to be represented, in const context this is a compile-time error.
+///
+/// # Examples
+///
+/// ```
+/// use decimus::{BidUint128, bid128_from_string_const};
+///
+/// const FEE_RATE: BidUint128 = bid128_from_string_const("0.0025");
+/// assert_eq!(BidUint128 { w: [25, 0x3038000000000000] }, FEE_RATE);
+/// ```
+///
+/// ```compile_fail
+/// use decimus::{BidUint128, bid128_from_string_const};
+///
+/// const INVALID: BidUint128 = bid128_from_string_const("0,0025");
+/// ```
+pub const fn bid128_from_string_const(input: &str) -> BidUint128 {
+  let (value, inexact) = bid_from_string_const(input.as_bytes());
+  assert!(!inexact || !bid128_is_inf(value), "decimal literal out of range");
+  value
+}
+
+/// Converts a value represented in string format (decimal character sequence)
+/// to 128-bit decimal floating-point format (binary encoding) in const context,
+/// returns the converted value and `true` when the value was rounded.
+/// Values too large to be represented are rounded to infinity.
+pub(crate) const fn bid_from_string_const(s: &[u8]) -> (BidUint128, bool) {
+  assert!(!s.is_empty(), "empty decimal literal");
+  let mut p: usize = 0;
+  let sign: BidUint64 = if s[0] == b'-' { MASK_SIGN } else { 0 };
+  if s[0] == b'-' || s[0] == b'+' {
+    p += 1;
+  }
+
+  // Check for case-insensitive infinity, inf, snan or nan with optional payload.
+  if bid_const_is_keyword(s, p, b"infinity") || bid_const_is_keyword(s, p, b"inf") {
+    return (BidUint128 { w: [0, sign | MASK_INF] }, false);
+  }
+  let (nan, payload_start) = if bid_const_starts_with_keyword(s, p, b"snan") {
+    (MASK_SNAN, p + 4)
+  } else if bid_const_starts_with_keyword(s, p, b"nan") {
+    (MASK_NAN, p + 3)
+  } else {
+    (0, 0)
+  };
+  if nan != 0 {
+    let mut payload: u128 = 0;
+    p = payload_start;
+    while p < s.len() {
+      assert!(s[p].is_ascii_digit(), "invalid character in decimal literal");
+      payload = payload * 10 + (s[p] - b'0') as u128;
+      assert!(payload <= MAX_NAN_PAYLOAD_128, "NaN payload too large in decimal literal");
+      p += 1;
+    }
+    return (bid_nan_with_payload(sign | nan, payload), false);
+  }
+
+  // Digits with optional radix point, digits that do not fit into the coefficient are rounded.
+  let mut coefficient: u128 = 0;
+  let mut ndigits = 0;
+  let mut exponent: i64 = 0;
+  let mut radix_point = false;
+  let mut any_digit = false;
+  let mut round_digit: u128 = 0;
+  let mut sticky = false;
+  let mut dropped = false;
+  while p < s.len() && (s[p].is_ascii_digit() || (s[p] == b'.' && !radix_point)) {
+    if s[p] == b'.' {
+      radix_point = true;
+    } else {
+      let digit = (s[p] - b'0') as u128;
+      any_digit = true;
+      if ndigits < MAX_FORMAT_DIGITS_128 {
+        coefficient = coefficient * 10 + digit;
+        if coefficient > 0 {
+          ndigits += 1;
+        }
+        exponent -= radix_point as i64;
+      } else {
+        if dropped {
+          sticky |= digit > 0;
+        } else {
+          round_digit = digit;
+          dropped = true;
+        }
+        exponent += !radix_point as i64;
+      }
+    }
+    p += 1;
+  }
+  assert!(any_digit, "invalid decimal literal");
+
+  // Exponent requires at least one digit and at most 6 significant digits, like in the strict parser.
+  if p < s.len() && (s[p] == b'e' || s[p] == b'E') {
+    p += 1;
+    let negative_exponent = p < s.len() && s[p] == b'-';
+    if p < s.len() && (s[p] == b'-' || s[p] == b'+') {
+      p += 1;
+    }
+    assert!(p < s.len() && s[p].is_ascii_digit(), "invalid exponent in decimal literal");
+    let mut value: i64 = 0;
+    while p < s.len() && s[p].is_ascii_digit() {
+      value = value * 10 + (s[p] - b'0') as i64;
+      assert!(value < 1_000_000, "exponent too large in decimal literal");
+      p += 1;
+    }
+    exponent += if negative_exponent { -value } else { value };
+  }
+  assert!(p == s.len(), "invalid character in decimal literal");
+
+  // Digits below the minimum exponent are rounded off.
+  let min_exponent = -(DECIMAL_EXPONENT_BIAS_128 as i64);
+  if exponent < min_exponent - MAX_FORMAT_DIGITS_128 as i64 {
+    sticky |= round_digit > 0 || coefficient > 0;
+    round_digit = 0;
+    coefficient = 0;
+    exponent = min_exponent;
+  }
+  while exponent < min_exponent {
+    sticky |= round_digit > 0;
+    round_digit = coefficient % 10;
+    coefficient /= 10;
+    exponent += 1;
+  }
+  let inexact = round_digit > 0 || sticky;
+  if round_digit > 5 || (round_digit == 5 && (sticky || coefficient % 2 == 1)) {
+    coefficient += 1;
+    if coefficient == 10_u128.pow(MAX_FORMAT_DIGITS_128 as u32) {
+      coefficient /= 10;
+      exponent += 1;
+    }
+  }
+
+  // Exponent above the maximum is reduced by adding trailing zeros to the coefficient.
+  let max_exponent = (DECIMAL_MAX_EXPON_128 - DECIMAL_EXPONENT_BIAS_128) as i64;
+  if coefficient == 0 && exponent > max_exponent {
+    exponent = max_exponent;
+  }
+  while exponent > max_exponent && coefficient < 10_u128.pow(MAX_FORMAT_DIGITS_128 as u32 - 1) {
+    coefficient *= 10;
+    exponent -= 1;
+  }
+  if exponent > max_exponent {
+    return (BidUint128 { w: [0, sign | MASK_INF] }, true);
+  }
+  let value = BidUint128 {
+    w: [coefficient as BidUint64, sign | ((exponent + DECIMAL_EXPONENT_BIAS_128 as i64) as BidUint64) << 49 | (coefficient >> 64) as BidUint64],
+  };
+  (value, inexact)
+}
+
+/// Returns `true` when the input starts at position `p` with the specified lower-case keyword, ignoring case.
+const fn bid_const_starts_with_keyword(input: &[u8], p: usize, keyword: &[u8]) -> bool {
+  if input.len() - p < keyword.len() {
+    return false;
+  }
+  let mut i: usize = 0;
+  while i < keyword.len() {
+    if input[p + i].to_ascii_lowercase() != keyword[i] {
+      return false;
+    }
+    i += 1;
+  }
+  true
+}
+
+/// Returns `true` when the input from position `p` to the end is the specified lower-case keyword, ignoring case.
+const fn bid_const_is_keyword(input: &[u8], p: usize, keyword: &[u8]) -> bool {
+  input.len() - p == keyword.len() && bid_const_starts_with_keyword(input, p, keyword)
+}
+
+/// Checks whether the whole character sequence is a number in string format,
+/// error positions are character indexes.
+pub(crate) fn bid_validate_number(ps: &CharSequence) -> Result<(), ParseDecimalError> {
+  if ps.is_empty() {
+    return Err(ParseDecimalError::new(ParseDecimalErrorKind::Empty, 0));
+  }
+  if let Some(position) = (0..ps.len()).find(|p| !ps[*p].is_ascii()) {
+    return Err(ParseDecimalError::new(ParseDecimalErrorKind::NonAsciiCharacter, position));
+  }
+  let mut p: usize = 0;
+
+  if ps[p] == b'-' || ps[p] == b'+' {
+    p += 1;
+  }
+
+  // Check for case-insensitive infinity, inf, snan or nan with optional payload.
+  if let Some((keyword, payload)) = bid_special_length(ps, p) {
+    bid_validate_payload(ps, p + keyword, payload)?;
+    return bid_validate_end(ps, p + keyword + payload);
+  }
+
+  // Digits with optional radix point, at least one digit is required.
+  let mut ndigits = 0;
+  while ps[p].is_ascii_digit() {
+    p += 1;
+    ndigits += 1;
+  }
+  if ps[p] == b'.' {
+    p += 1;
+    while ps[p].is_ascii_digit() {
+      p += 1;
+      ndigits += 1;
+    }
+  }
+  if ndigits == 0 {
+    return Err(ParseDecimalError::new(ParseDecimalErrorKind::InvalidDigit, p));
+  }
+
+  // Exponent requires at least one digit.
+  if ps[p] == b'e' || ps[p] == b'E' {
+    p += 1;
+    if ps[p] == b'-' || ps[p] == b'+' {
+      p += 1;
+    }
+    let start = p;
+    while ps[p].is_ascii_digit() {
+      p += 1;
+    }
+    if p == start {
+      return Err(ParseDecimalError::new(ParseDecimalErrorKind::InvalidDigit, p));
+    }
+    if (start..p).skip_while(|q| ps[*q] == b'0').count() > 6 {
+      return Err(ParseDecimalError::new(ParseDecimalErrorKind::ExponentOverflow, start));
+    }
+  }
+  bid_validate_end(ps, p)
+}
+
+/// Checks whether the number ends at the end of the character sequence.
+fn bid_validate_end(ps: &CharSequence, p: usize) -> Result<(), ParseDecimalError> {
+  if p < ps.len() { Err(ParseDecimalError::new(ParseDecimalErrorKind::TrailingCharacters, p)) } else { Ok(()) }
+}
+
+/// Returns the ASCII equivalent of the Unicode minus sign or full-width digit
+/// encoded in UTF-8 at the beginning of the input.
+fn bid_unicode_replacement(input: &[u8]) -> Option<&'static u8> {
+  match input {
+    [0xe2, 0x88, 0x92, ..] => Some(&b'-'),
+    [0xef, 0xbc, c @ 0x90..=0x99, ..] => Some(&b"0123456789"[(c - 0x90) as usize]),
+    _ => None,
+  }
+}
+
+/// Returns the number of bytes of the character at the beginning of the input,
+/// Unicode minus sign and full-width digits are single characters, all other bytes are separate characters.
+fn bid_unicode_width(input: &[u8]) -> usize {
+  if bid_unicode_replacement(input).is_some() { 3 } else { 1 }
+}
+
+/// Returns the length of the case-insensitive `infinity`, `inf`, `snan` or `nan` keyword at position `p`
+/// and the number of digits of the NaN payload following it, `None` when there is no keyword at position `p`.
+pub(crate) fn bid_special_length(ps: &CharSequence, p: usize) -> Option<(usize, usize)> {
+  let keyword = [b"infinity".as_slice(), b"inf", b"snan", b"nan"].into_iter().find(|keyword| bid_starts_with_keyword(ps, p, keyword))?;
+  let start = p + keyword.len();
+  let payload = if keyword.ends_with(b"nan") { (start..).take_while(|q| ps[*q].is_ascii_digit()).count() } else { 0 };
+  Some((keyword.len(), payload))
+}
+
+/// Checks whether the NaN payload of specified length starting at position `p` has at most 33 significant digits.
+pub(crate) fn bid_validate_payload(ps: &CharSequence, p: usize, length: usize) -> Result<(), ParseDecimalError> {
+  if (p..p + length).skip_while(|q| ps[*q] == b'0').count() > MAX_FORMAT_DIGITS_128 as usize - 1 {
+    Err(ParseDecimalError::new(ParseDecimalErrorKind::PayloadOverflow, p))
+  } else {
+    Ok(())
+  }
+}
+
+/// Returns `true` when the specified lower-case keyword starts at position `p`, ignoring case.
+fn bid_starts_with_keyword(ps: &CharSequence, p: usize, keyword: &[u8]) -> bool {
+  keyword.iter().enumerate().all(|(i, c)| ps[p + i].to_ascii_lowercase() == *c)
+}
+
+/// Returns the length of the longest prefix of the input that is a number in string format,
+/// or zero when the input does not start with a number.
+fn bid_number_length(input: &[u8]) -> usize {
+  let ps = CharSequence::new(input);
+  let mut p: usize = 0;
+
+  // Skip the leading white spaces and the sign.
+  while ps[p] == b' ' || ps[p] == b'\t' {
+    p += 1;
+  }
+  if ps[p] == b'-' || ps[p] == b'+' {
+    p += 1;
+  }
+
+  // Check for case-insensitive infinity, inf, snan or nan with optional payload.
+  if let Some((keyword, payload)) = bid_special_length(&ps, p) {
+    return p + keyword + payload;
+  }
+
+  // Digits with optional radix point, at least one digit is required.
+  let mut ndigits = 0;
+  while ps[p].is_ascii_digit() {
+    p += 1;
+    ndigits += 1;
+  }
+  if ps[p] == b'.' {
+    p += 1;
+    while ps[p].is_ascii_digit() {
+      p += 1;
+      ndigits += 1;
+    }
+  }
+  if ndigits == 0 {
+    return 0;
+  }
+
+  // Exponent is consumed only when followed by at least one digit.
+  if ps[p] == b'e' || ps[p] == b'E' {
+    let mut q = p + 1;
+    if ps[q] == b'-' || ps[q] == b'+' {
+      q += 1;
+    }
+    if ps[q].is_ascii_digit() {
+      while ps[q].is_ascii_digit() {
+        q += 1;
+      }
+      p = q;
+    }
+  }
+  p
+}
+
+/// Converts ASCII characters to 128-bit decimal floating-point format (binary encoding).
+pub(crate) fn bid128_from_ascii(input: &[u8], rounding: IdecRound, flags: &mut IdecFlags) -> BidUint128 {
+  bid128_from_chars(CharSequence::new(input), rounding, flags)
+}
+
+/// Significant digits of the parsed coefficient.
+///
+/// Only the digits needed to build and round the coefficient are stored,
+/// all remaining digits are folded into the sticky bit, so the input may have any length.
+struct ParsedDigits {
+  /// Leading significant digits (ASCII characters).
+  digits: [u8; MAX_PARSED_DIGITS_128],
+  /// Set when any digit that did not fit into `digits` is not zero.
+  sticky: bool,
+  /// Set when any digit beyond the coefficient precision is not zero.
+  inexact: bool,
+}
+
+impl ParsedDigits {
+  /// Creates an empty sequence of digits.
+  fn new() -> Self {
+    Self {
+      digits: [0; MAX_PARSED_DIGITS_128],
+      sticky: false,
+      inexact: false,
+    }
+  }
+
+  /// Stores the digit `c` at position `n`.
+  fn push(&mut self, n: usize, c: u8) {
+    if n < MAX_PARSED_DIGITS_128 {
+      self.digits[n] = c;
+    } else if c > b'0' {
+      self.sticky = true;
+    }
+    if n >= MAX_FORMAT_DIGITS_128 as usize && c > b'0' {
+      self.inexact = true;
+    }
+  }
+
+  /// Returns `true` when any digit at position `n` or further is not zero.
+  fn has_nonzero_from(&self, n: usize) -> bool {
+    self.sticky || self.digits.get(n..).is_some_and(|digits| digits.iter().any(|d| *d > b'0'))
+  }
+}
+
+impl Index<usize> for ParsedDigits {
+  type Output = u8;
+
+  fn index(&self, index: usize) -> &Self::Output {
+    &self.digits[index]
+  }
+}
+
+/// Returns NaN with specified high word bits and the payload parsed from the digits starting at position `p`,
+/// payloads larger than 33 digits are replaced with zero.
+fn bid_parse_nan_payload(ps: &CharSequence, mut p: usize, high: BidUint64) -> BidUint128 {
+  let mut payload: u128 = 0;
+  while ps[p].is_ascii_digit() {
+    payload = payload.saturating_mul(10).saturating_add((ps[p] - b'0') as u128);
+    p += 1;
+  }
+  bid_nan_with_payload(high, if payload <= MAX_NAN_PAYLOAD_128 { payload } else { 0 })
+}
+
+/// Converts a character sequence to 128-bit decimal floating-point format (binary encoding).
+fn bid128_from_chars(ps: CharSequence, rounding: IdecRound, flags: &mut IdecFlags) -> BidUint128 {
   let mut res: BidUint128 = Default::default();
   let mut cx: BidUint128 = Default::default();
   let mut coeff_high: BidUint64;
@@ -259,22 +962,17 @@ pub fn bid128_from_string(input: &str, rounding: IdecRound, flags: &mut IdecFlag
   let mut dec_expon: i32;
   let mut sgn_exp: i32;
   let mut i: i32;
-  let mut set_inexact = false;
-  let mut buffer: [u8; MAX_BUFFER_SIZE] = [0_u8; MAX_BUFFER_SIZE];
+  let mut digits = ParsedDigits::new();
 
   // If null string, then return NaN.
-  if input.is_empty() {
+  if ps.is_empty() {
     res.w[1] = 0x7c00000000000000;
     res.w[0] = 0;
     return res;
   }
 
-  // Prepare a vector of input bytes, and prepare an index that simulates C pointer on char.
+  // Prepare an index that simulates C pointer on char.
   let mut p: usize = 0;
-  let mut ps = vec![0u8; input.len() + 1];
-  for (index, ch) in input.chars().enumerate() {
-    ps[index] = ch as u8;
-  }
 
   // Eliminate the leading white spaces.
   while ps[p] == b' ' || ps[p] == b'\t' {
@@ -296,10 +994,12 @@ pub fn bid128_from_string(input: &str, rounding: IdecRound, flags: &mut IdecFlag
       return res;
     }
     // Return sNaN.
-    return if tolower_macro!(ps[0]) == b's' && tolower_macro!(ps[1]) == b'n' && tolower_macro!(ps[2]) == b'a' && tolower_macro!(ps[3]) == b'n' {
-      // Return SNaN.
-      res.w[1] = 0x7e00000000000000;
-      res
+    return if tolower_macro!(ps[p]) == b's' && tolower_macro!(ps[p + 1]) == b'n' && tolower_macro!(ps[p + 2]) == b'a' && tolower_macro!(ps[p + 3]) == b'n' {
+      // Return SNaN with optional payload.
+      bid_parse_nan_payload(&ps, p + 4, 0x7e00000000000000)
+    } else if tolower_macro!(ps[p]) == b'n' && tolower_macro!(ps[p + 1]) == b'a' && tolower_macro!(ps[p + 2]) == b'n' {
+      // Return qNaN with optional payload.
+      bid_parse_nan_payload(&ps, p + 3, 0x7c00000000000000)
     } else {
       // Return qNaN.
       res.w[1] = 0x7c00000000000000;
@@ -324,24 +1024,12 @@ pub fn bid128_from_string(input: &str, rounding: IdecRound, flags: &mut IdecFlag
 
   // Check for case-insensitive +snan or -snan.
   if tolower_macro!(ps[p + 1]) == b's' && tolower_macro!(ps[p + 2]) == b'n' && tolower_macro!(ps[p + 3]) == b'a' && tolower_macro!(ps[p + 4]) == b'n' {
-    res.w[0] = 0;
-    if c == b'-' {
-      res.w[1] = 0xfe00000000000000;
-    } else {
-      res.w[1] = 0x7e00000000000000;
-    }
-    return res;
+    return bid_parse_nan_payload(&ps, p + 5, if c == b'-' { 0xfe00000000000000 } else { 0x7e00000000000000 });
   }
 
   // Check for case-insensitive +nan or -nan.
   if tolower_macro!(ps[p + 1]) == b'n' && tolower_macro!(ps[p + 2]) == b'a' && tolower_macro!(ps[p + 3]) == b'n' {
-    res.w[0] = 0;
-    if c == b'-' {
-      res.w[1] = 0xfc00000000000000;
-    } else {
-      res.w[1] = 0x7c00000000000000;
-    }
-    return res;
+    return bid_parse_nan_payload(&ps, p + 4, if c == b'-' { 0xfc00000000000000 } else { 0x7c00000000000000 });
   }
 
   // Set up sign_x to be OR'ed with the upper word later.
@@ -415,16 +1103,7 @@ pub fn bid128_from_string(input: &str, rounding: IdecRound, flags: &mut IdecFlag
   if rdx_pt_enc == 0 {
     // Investigate string (before radix point).
     while c.is_ascii_digit() {
-      if ndigits_before < MAX_FORMAT_DIGITS_128 {
-        buffer[ndigits_before as usize] = c;
-      } else if ndigits_before < MAX_STRING_DIGITS_128 {
-        buffer[ndigits_before as usize] = c;
-        if c > b'0' {
-          set_inexact = true;
-        }
-      } else if c > b'0' {
-        set_inexact = true
-      };
+      digits.push(ndigits_before as usize, c);
       p += 1;
       c = ps[p];
       ndigits_before += 1;
@@ -437,16 +1116,7 @@ pub fn bid128_from_string(input: &str, rounding: IdecRound, flags: &mut IdecFlag
       if c > 0 {
         // Investigate string (after radix point).
         while c.is_ascii_digit() {
-          if ndigits_total < MAX_FORMAT_DIGITS_128 {
-            buffer[ndigits_total as usize] = c;
-          } else if ndigits_total < MAX_STRING_DIGITS_128 {
-            buffer[ndigits_total as usize] = c;
-            if c > b'0' {
-              set_inexact = true;
-            }
-          } else if c > b'0' {
-            set_inexact = true;
-          }
+          digits.push(ndigits_total as usize, c);
           p += 1;
           c = ps[p];
           ndigits_total += 1;
@@ -460,16 +1130,7 @@ pub fn bid128_from_string(input: &str, rounding: IdecRound, flags: &mut IdecFlag
     ndigits_total = 0;
     // Investigate string (after radix point).
     while c.is_ascii_digit() {
-      if ndigits_total < MAX_FORMAT_DIGITS_128 {
-        buffer[ndigits_total as usize] = c;
-      } else if ndigits_total < MAX_STRING_DIGITS_128 {
-        buffer[ndigits_total as usize] = c;
-        if c > b'0' {
-          set_inexact = true;
-        }
-      } else if c > b'0' {
-        set_inexact = true;
-      }
+      digits.push(ndigits_total as usize, c);
       p += 1;
       c = ps[p];
       ndigits_total += 1;
@@ -538,28 +1199,28 @@ pub fn bid128_from_string(input: &str, rounding: IdecRound, flags: &mut IdecFlag
       cx.w[0] = 0;
       cx.w[1] = 0;
     } else if ndigits_total <= 19 {
-      coeff_high = (buffer[0] - b'0') as u64;
+      coeff_high = (digits[0] - b'0') as u64;
       i = 1;
       while i < ndigits_total {
         coeff2 = coeff_high.wrapping_add(coeff_high);
-        coeff_high = (coeff2 << 2).wrapping_add(coeff2).wrapping_add((buffer[i as usize] - b'0') as u64);
+        coeff_high = (coeff2 << 2).wrapping_add(coeff2).wrapping_add((digits[i as usize] - b'0') as u64);
         i += 1;
       }
       cx.w[0] = coeff_high;
       cx.w[1] = 0;
     } else {
-      coeff_high = (buffer[0] - b'0') as u64;
+      coeff_high = (digits[0] - b'0') as u64;
       i = 1;
       while i < ndigits_total - 17 {
         coeff2 = coeff_high.wrapping_add(coeff_high);
-        coeff_high = (coeff2 << 2).wrapping_add(coeff2).wrapping_add((buffer[i as usize] - b'0') as u64);
+        coeff_high = (coeff2 << 2).wrapping_add(coeff2).wrapping_add((digits[i as usize] - b'0') as u64);
         i += 1;
       }
-      coeff_low = (buffer[i as usize] - b'0') as u64;
+      coeff_low = (digits[i as usize] - b'0') as u64;
       i += 1;
       while i < ndigits_total {
         coeff_l2 = coeff_low.wrapping_add(coeff_low);
-        coeff_low = (coeff_l2 << 2).wrapping_add(coeff_l2).wrapping_add((buffer[i as usize] - b'0') as u64);
+        coeff_low = (coeff_l2 << 2).wrapping_add(coeff_l2).wrapping_add((digits[i as usize] - b'0') as u64);
         i += 1;
       }
       // Now form the coefficient as coeff_high*10^19+coeff_low+carry
@@ -583,72 +1244,46 @@ pub fn bid128_from_string(input: &str, rounding: IdecRound, flags: &mut IdecFlag
       res.w[0] = 0;
     }
 
-    coeff_high = (buffer[0] - b'0') as u64;
+    coeff_high = (digits[0] - b'0') as u64;
     i = 1;
     while i < MAX_FORMAT_DIGITS_128 - 17 {
       coeff2 = coeff_high.wrapping_add(coeff_high);
-      coeff_high = (coeff2 << 2).wrapping_add(coeff2).wrapping_add((buffer[i as usize] - b'0') as u64);
+      coeff_high = (coeff2 << 2).wrapping_add(coeff2).wrapping_add((digits[i as usize] - b'0') as u64);
       i += 1;
     }
-    coeff_low = (buffer[i as usize] - b'0') as u64;
+    coeff_low = (digits[i as usize] - b'0') as u64;
     i += 1;
     while i < MAX_FORMAT_DIGITS_128 {
       coeff_l2 = coeff_low.wrapping_add(coeff_low);
-      coeff_low = (coeff_l2 << 2).wrapping_add(coeff_l2).wrapping_add((buffer[i as usize] - b'0') as u64);
+      coeff_low = (coeff_l2 << 2).wrapping_add(coeff_l2).wrapping_add((digits[i as usize] - b'0') as u64);
       i += 1;
     }
     match rounding {
       BID_ROUNDING_TO_NEAREST => {
-        carry = ((b'4' as i8).wrapping_sub(buffer[i as usize] as i8) as u32 >> 31) as u64;
-        if (buffer[i as usize] == b'5' && (coeff_low & 1) == 0) || dec_expon < 0 {
+        carry = ((b'4' as i8).wrapping_sub(digits[i as usize] as i8) as u32 >> 31) as u64;
+        if (digits[i as usize] == b'5' && (coeff_low & 1) == 0) || dec_expon < 0 {
           if dec_expon >= 0 {
             carry = 0;
             i += 1;
           }
-          while i < ndigits_total {
-            if buffer[i as usize] > b'0' {
-              carry = 1;
-              break;
-            }
-            i += 1;
+          if digits.has_nonzero_from(i as usize) {
+            carry = 1;
           }
         }
       }
-      BID_ROUNDING_DOWN => {
-        if sign_x > 0 {
-          while i < ndigits_total {
-            if buffer[i as usize] > b'0' {
-              carry = 1;
-              break;
-            }
-            i += 1;
-          }
-        }
+      BID_ROUNDING_DOWN if sign_x > 0 => {
+        carry = digits.has_nonzero_from(i as usize) as u64;
       }
-      BID_ROUNDING_UP => {
-        if sign_x == 0 {
-          while i < ndigits_total {
-            if buffer[i as usize] > b'0' {
-              carry = 1;
-              break;
-            }
-            i += 1;
-          }
-        }
+      BID_ROUNDING_UP if sign_x == 0 => {
+        carry = digits.has_nonzero_from(i as usize) as u64;
       }
       BID_ROUNDING_TO_ZERO => {
         carry = 0;
       }
       BID_ROUNDING_TIES_AWAY => {
-        carry = ((b'4' as i8).wrapping_sub(buffer[i as usize] as i8) as u32 >> 31) as u64;
-        if dec_expon < 0 {
-          while i < ndigits_total {
-            if buffer[i as usize] > b'0' {
-              carry = 1;
-              break;
-            }
-            i += 1;
-          }
+        carry = ((b'4' as i8).wrapping_sub(digits[i as usize] as i8) as u32 >> 31) as u64;
+        if dec_expon < 0 && digits.has_nonzero_from(i as usize) {
+          carry = 1;
         }
       }
 
@@ -676,7 +1311,7 @@ pub fn bid128_from_string(input: &str, rounding: IdecRound, flags: &mut IdecFlag
       cx.w[1] = cx.w[1].wrapping_add(1);
     }
 
-    if cfg!(feature = "bid-set-status-flags") && set_inexact {
+    if cfg!(feature = "bid-set-status-flags") && digits.inexact {
       set_status_flags!(flags, BID_INEXACT_EXCEPTION);
     }
 
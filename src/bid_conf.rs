@@ -3,3 +3,6 @@ pub type IdecFlags = u32;
 
 /// Rounding modes.
 pub type IdecRound = u32;
+
+/// Tininess detection methods.
+pub type IdecTininess = u32;
@@ -0,0 +1,194 @@
+//! # Decimal context
+
+use crate::BidUint128;
+use crate::bid_conf::*;
+use crate::bid_functions::*;
+use crate::bid_internal::P34;
+use crate::bid128_checked::*;
+use crate::bid128_precision::*;
+use crate::bid128_string::bid128_from_string;
+
+/// Decimal context.
+///
+/// Bundles the rounding mode, tininess detection method, precision, accumulated exception flags
+/// and enabled traps, so they do not have to be passed to every operation.
+/// Each operation performed using the context accumulates the raised exception flags.
+///
+/// # Examples
+///
+/// ```
+/// use decimus::{BID_INEXACT_EXCEPTION, BID_ROUNDING_DOWN, Context};
+///
+/// let mut ctx = Context::new(BID_ROUNDING_DOWN);
+/// let x = ctx.from_string("2");
+/// let y = ctx.from_string("3");
+/// assert_eq!(ctx.from_string("0.6666666666666666666666666666666666"), ctx.div(x, y));
+///
+/// let z = ctx.from_string("1.000000000000000000000000000000001");
+/// assert_eq!(ctx.from_string("1.000000000000000000000000000000002"), ctx.mul(z, z));
+/// assert_eq!(BID_INEXACT_EXCEPTION, ctx.flags() & BID_INEXACT_EXCEPTION);
+///
+/// ctx.set_precision(16);
+/// assert_eq!(ctx.from_string("0.6666666666666666"), ctx.div(x, y));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Context {
+  /// Rounding mode.
+  rounding: IdecRound,
+  /// Tininess detection method.
+  tininess: IdecTininess,
+  /// Number of significant digits of the results.
+  precision: u32,
+  /// Accumulated exception flags.
+  flags: IdecFlags,
+  /// Enabled traps.
+  traps: IdecFlags,
+}
+
+impl Default for Context {
+  /// Creates a context rounding to nearest, with default tininess detection, full precision, no flags and no traps.
+  fn default() -> Self {
+    Self::new(BID_ROUNDING_TO_NEAREST)
+  }
+}
+
+impl Context {
+  /// Creates a context with specified rounding mode, default tininess detection, full precision, no flags and no traps.
+  pub fn new(rounding: IdecRound) -> Self {
+    Self {
+      rounding,
+      tininess: BID_TININESS_DEFAULT,
+      precision: P34 as u32,
+      flags: BID_EXACT_STATUS,
+      traps: BID_NO_EXCEPTION,
+    }
+  }
+
+  /// Returns the rounding mode.
+  pub fn rounding(&self) -> IdecRound {
+    self.rounding
+  }
+
+  /// Sets the rounding mode.
+  pub fn set_rounding(&mut self, rounding: IdecRound) {
+    self.rounding = rounding;
+  }
+
+  /// Returns the tininess detection method.
+  pub fn tininess(&self) -> IdecTininess {
+    self.tininess
+  }
+
+  /// Sets the tininess detection method.
+  pub fn set_tininess(&mut self, tininess: IdecTininess) {
+    self.tininess = tininess;
+  }
+
+  /// Returns the number of significant digits the results of arithmetic operations are rounded to.
+  pub fn precision(&self) -> u32 {
+    self.precision
+  }
+
+  /// Sets the number of significant digits the results of arithmetic operations are rounded to,
+  /// the precision is clamped to range `1..=34`.
+  pub fn set_precision(&mut self, precision: u32) {
+    self.precision = precision.clamp(1, P34 as u32);
+  }
+
+  /// Returns the accumulated exception flags.
+  pub fn flags(&self) -> IdecFlags {
+    self.flags
+  }
+
+  /// Sets the accumulated exception flags.
+  pub fn set_flags(&mut self, flags: IdecFlags) {
+    self.flags = flags;
+  }
+
+  /// Clears all accumulated exception flags.
+  pub fn clear_flags(&mut self) {
+    self.flags = BID_EXACT_STATUS;
+  }
+
+  /// Returns the enabled traps, checked by `checked_*` operations.
+  pub fn traps(&self) -> IdecFlags {
+    self.traps
+  }
+
+  /// Sets the enabled traps.
+  pub fn set_traps(&mut self, traps: IdecFlags) {
+    self.traps = traps;
+  }
+
+  /// Adds two 128-bit decimal floating-point values.
+  pub fn add(&mut self, x: BidUint128, y: BidUint128) -> BidUint128 {
+    bid128_add_with_precision(x, y, self.precision, self.rounding, self.tininess, &mut self.flags)
+  }
+
+  /// Subtracts two 128-bit decimal floating-point values.
+  pub fn sub(&mut self, x: BidUint128, y: BidUint128) -> BidUint128 {
+    bid128_sub_with_precision(x, y, self.precision, self.rounding, self.tininess, &mut self.flags)
+  }
+
+  /// Multiplies two 128-bit decimal floating-point values.
+  pub fn mul(&mut self, x: BidUint128, y: BidUint128) -> BidUint128 {
+    bid128_mul_with_precision(x, y, self.precision, self.rounding, self.tininess, &mut self.flags)
+  }
+
+  /// Divides two 128-bit decimal floating-point values.
+  pub fn div(&mut self, x: BidUint128, y: BidUint128) -> BidUint128 {
+    bid128_div_with_precision(x, y, self.precision, self.rounding, self.tininess, &mut self.flags)
+  }
+
+  /// Computes `x * y + z` with a single rounding.
+  pub fn fma(&mut self, x: BidUint128, y: BidUint128, z: BidUint128) -> BidUint128 {
+    bid128_fma_with_precision(x, y, z, self.precision, self.rounding, self.tininess, &mut self.flags)
+  }
+
+  /// Converts a value represented in string format to 128-bit decimal floating-point format.
+  pub fn from_string(&mut self, input: &str) -> BidUint128 {
+    bid128_from_string(input, self.rounding, &mut self.flags)
+  }
+
+  /// Computes the operation with the precision of the context and checks the raised exceptions against enabled traps.
+  fn checked(&mut self, operation: Operation) -> Result<BidUint128, DecimalError> {
+    let precision = self.precision;
+    bid_check(operation, self.rounding, self.tininess, self.traps, &mut self.flags, |operation, rounding, tininess, flags| match *operation {
+      Operation::Add(x, y) => bid128_add_with_precision(x, y, precision, rounding, tininess, flags),
+      Operation::Sub(x, y) => bid128_sub_with_precision(x, y, precision, rounding, tininess, flags),
+      Operation::Mul(x, y) => bid128_mul_with_precision(x, y, precision, rounding, tininess, flags),
+      Operation::Div(x, y) => bid128_div_with_precision(x, y, precision, rounding, tininess, flags),
+      Operation::Fma(x, y, z) => bid128_fma_with_precision(x, y, z, precision, rounding, tininess, flags),
+    })
+  }
+
+  /// Adds two 128-bit decimal floating-point values,
+  /// returns an error when an exception with enabled trap is raised.
+  pub fn checked_add(&mut self, x: BidUint128, y: BidUint128) -> Result<BidUint128, DecimalError> {
+    self.checked(Operation::Add(x, y))
+  }
+
+  /// Subtracts two 128-bit decimal floating-point values,
+  /// returns an error when an exception with enabled trap is raised.
+  pub fn checked_sub(&mut self, x: BidUint128, y: BidUint128) -> Result<BidUint128, DecimalError> {
+    self.checked(Operation::Sub(x, y))
+  }
+
+  /// Multiplies two 128-bit decimal floating-point values,
+  /// returns an error when an exception with enabled trap is raised.
+  pub fn checked_mul(&mut self, x: BidUint128, y: BidUint128) -> Result<BidUint128, DecimalError> {
+    self.checked(Operation::Mul(x, y))
+  }
+
+  /// Divides two 128-bit decimal floating-point values,
+  /// returns an error when an exception with enabled trap is raised.
+  pub fn checked_div(&mut self, x: BidUint128, y: BidUint128) -> Result<BidUint128, DecimalError> {
+    self.checked(Operation::Div(x, y))
+  }
+
+  /// Computes `x * y + z` with a single rounding,
+  /// returns an error when an exception with enabled trap is raised.
+  pub fn checked_fma(&mut self, x: BidUint128, y: BidUint128, z: BidUint128) -> Result<BidUint128, DecimalError> {
+    self.checked(Operation::Fma(x, y, z))
+  }
+}
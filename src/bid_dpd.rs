@@ -0,0 +1,218 @@
+//! # Conversions between binary (BID) and densely packed decimal (DPD) encodings
+//!
+//! Both encodings share the sign bit and the layout of special values,
+//! they differ in the representation of the exponent and the coefficient.
+//! DPD stores the leading digit and two exponent bits in the combination field
+//! and the remaining digits in declets (10 bits for 3 digits) of the trailing field.
+
+use crate::{BidUint32, BidUint64, BidUint128};
+
+/// Parameters of the decimal interchange format.
+struct Format {
+  /// Number of bits.
+  width: u32,
+  /// Number of coefficient digits.
+  precision: u32,
+  /// Number of exponent continuation bits in DPD encoding.
+  continuation: u32,
+}
+
+impl Format {
+  /// Number of bits in the trailing coefficient field (declets) in DPD encoding.
+  const fn trailing(&self) -> u32 {
+    self.width - 6 - self.continuation
+  }
+
+  /// Number of exponent bits.
+  const fn exponent_bits(&self) -> u32 {
+    self.continuation + 2
+  }
+}
+
+/// Parameters of decimal32 format.
+const DECIMAL32: Format = Format { width: 32, precision: 7, continuation: 6 };
+
+/// Parameters of decimal64 format.
+const DECIMAL64: Format = Format { width: 64, precision: 16, continuation: 8 };
+
+/// Parameters of decimal128 format.
+const DECIMAL128: Format = Format { width: 128, precision: 34, continuation: 12 };
+
+/// Returns the mask of `n` least significant bits.
+const fn mask(n: u32) -> u128 {
+  (1 << n) - 1
+}
+
+/// Encodes a number from 0 to 999 as a declet.
+fn bid_encode_declet(value: u128) -> u128 {
+  let (d2, d1, d0) = (value / 100, value / 10 % 10, value % 10);
+  // Two middle bits of digits, used when the bits of large digits (8 and 9) are packed.
+  let (d1_mid, d0_mid) = (d1 >> 1 & 3, d0 >> 1 & 3);
+  let (d2_low, d1_low, d0_low) = (d2 & 1, d1 & 1, d0 & 1);
+  match (d2 > 7, d1 > 7, d0 > 7) {
+    (false, false, false) => d2 << 7 | d1 << 4 | d0,
+    (false, false, true) => d2 << 7 | d1 << 4 | 0b1000 | d0_low,
+    (false, true, false) => d2 << 7 | d0_mid << 5 | d1_low << 4 | 0b1010 | d0_low,
+    (true, false, false) => d0_mid << 8 | d2_low << 7 | d1 << 4 | 0b1100 | d0_low,
+    (true, true, false) => d0_mid << 8 | d2_low << 7 | d1_low << 4 | 0b1110 | d0_low,
+    (true, false, true) => d1_mid << 8 | d2_low << 7 | 0b01 << 5 | d1_low << 4 | 0b1110 | d0_low,
+    (false, true, true) => d2 << 7 | 0b10 << 5 | d1_low << 4 | 0b1110 | d0_low,
+    (true, true, true) => d2_low << 7 | 0b11 << 5 | d1_low << 4 | 0b1110 | d0_low,
+  }
+}
+
+/// Decodes a declet to a number from 0 to 999.
+///
+/// Non-canonical declets (with pattern `xx1111x111`, where `xx` is not `00`)
+/// are decoded as their canonical counterparts, as required by IEEE 754.
+fn bid_decode_declet(declet: u128) -> u128 {
+  let bit = |n: u32| declet >> n & 1;
+  let (high, middle, low) = (declet >> 7 & 7, declet >> 4 & 7, declet & 7);
+  let (hi2, mid2) = (declet >> 8 & 3, declet >> 5 & 3);
+  let (d2, d1, d0) = if bit(3) == 0 {
+    (high, middle, low)
+  } else {
+    match (declet >> 1 & 3, mid2) {
+      (0b00, _) => (high, middle, 8 + bit(0)),
+      (0b01, _) => (high, 8 + bit(4), mid2 << 1 | bit(0)),
+      (0b10, _) => (8 + bit(7), middle, hi2 << 1 | bit(0)),
+      (_, 0b00) => (8 + bit(7), 8 + bit(4), hi2 << 1 | bit(0)),
+      (_, 0b01) => (8 + bit(7), hi2 << 1 | bit(4), 8 + bit(0)),
+      (_, 0b10) => (high, 8 + bit(4), 8 + bit(0)),
+      _ => (8 + bit(7), 8 + bit(4), 8 + bit(0)),
+    }
+  };
+  d2 * 100 + d1 * 10 + d0
+}
+
+/// Encodes the number as a sequence of declets filling `bits` bits.
+fn bid_encode_declets(mut value: u128, bits: u32) -> u128 {
+  let mut declets = 0;
+  for shift in (0..bits).step_by(10) {
+    declets |= bid_encode_declet(value % 1000) << shift;
+    value /= 1000;
+  }
+  declets
+}
+
+/// Decodes the sequence of declets filling `bits` bits.
+fn bid_decode_declets(declets: u128, bits: u32) -> u128 {
+  let mut value = 0;
+  for shift in (0..bits).step_by(10).rev() {
+    value = value * 1000 + bid_decode_declet(declets >> shift & 0x3ff);
+  }
+  value
+}
+
+/// Converts the value in BID encoding to DPD encoding.
+fn bid_to_dpd(x: u128, f: &Format) -> u128 {
+  let w = f.width;
+  let t = f.trailing();
+  let e = f.exponent_bits();
+  let sign = x & 1 << (w - 1);
+  let max_payload = 10_u128.pow(f.precision - 1);
+  if x >> (w - 6) & 0x1f == 0x1f {
+    // NaN keeps the signaling bit, non-canonical payload is replaced with zero.
+    let payload = x & mask(t);
+    return x & 0x7f << (w - 7) | bid_encode_declets(if payload < max_payload { payload } else { 0 }, t);
+  }
+  if x >> (w - 6) & 0x1e == 0x1e {
+    // Infinity.
+    return sign | 0x1e << (w - 6);
+  }
+  let (exponent, coefficient) = if x >> (w - 3) & 3 == 3 {
+    (x >> (w - 3 - e) & mask(e), 4 << (w - 3 - e) | x & mask(w - 3 - e))
+  } else {
+    (x >> (w - 1 - e) & mask(e), x & mask(w - 1 - e))
+  };
+  // Non-canonical coefficient is replaced with zero.
+  let coefficient = if coefficient < 10 * max_payload { coefficient } else { 0 };
+  let (leading_digit, trailing_digits) = (coefficient / max_payload, coefficient % max_payload);
+  let exponent_msbs = exponent >> f.continuation;
+  let combination = if leading_digit < 8 { exponent_msbs << 3 | leading_digit } else { 0x18 | exponent_msbs << 1 | leading_digit & 1 };
+  sign | combination << (w - 6) | (exponent & mask(f.continuation)) << t | bid_encode_declets(trailing_digits, t)
+}
+
+/// Converts the value in DPD encoding to BID encoding.
+fn bid_dpd_to_bid(x: u128, f: &Format) -> u128 {
+  let w = f.width;
+  let t = f.trailing();
+  let e = f.exponent_bits();
+  let sign = x & 1 << (w - 1);
+  let combination = x >> (w - 6) & 0x1f;
+  if combination == 0x1f {
+    // NaN keeps the signaling bit, payload is always canonical.
+    return x & 0x7f << (w - 7) | bid_decode_declets(x & mask(t), t);
+  }
+  if combination == 0x1e {
+    // Infinity.
+    return sign | 0x1e << (w - 6);
+  }
+  let (exponent_msbs, leading_digit) = if combination >> 3 == 3 { (combination >> 1 & 3, 8 | combination & 1) } else { (combination >> 3, combination & 7) };
+  let exponent = exponent_msbs << f.continuation | x >> t & mask(f.continuation);
+  let coefficient = leading_digit * 10_u128.pow(f.precision - 1) + bid_decode_declets(x & mask(t), t);
+  if coefficient < 1 << (w - 1 - e) {
+    sign | exponent << (w - 1 - e) | coefficient
+  } else {
+    sign | 3 << (w - 3) | exponent << (w - 3 - e) | coefficient & mask(w - 3 - e)
+  }
+}
+
+/// Converts a 32-bit decimal floating-point value from binary encoding (BID)
+/// to densely packed decimal encoding (DPD).
+///
+/// Non-canonical coefficients and NaN payloads are converted to zero.
+pub fn bid32_to_dpd32(x: BidUint32) -> BidUint32 {
+  bid_to_dpd(x as u128, &DECIMAL32) as BidUint32
+}
+
+/// Converts a 32-bit decimal floating-point value from densely packed decimal encoding (DPD)
+/// to binary encoding (BID).
+///
+/// Non-canonical declets are accepted and decoded as their canonical counterparts.
+pub fn bid_dpd_to_bid32(x: BidUint32) -> BidUint32 {
+  bid_dpd_to_bid(x as u128, &DECIMAL32) as BidUint32
+}
+
+/// Converts a 64-bit decimal floating-point value from binary encoding (BID)
+/// to densely packed decimal encoding (DPD).
+///
+/// Non-canonical coefficients and NaN payloads are converted to zero.
+pub fn bid64_to_dpd64(x: BidUint64) -> BidUint64 {
+  bid_to_dpd(x as u128, &DECIMAL64) as BidUint64
+}
+
+/// Converts a 64-bit decimal floating-point value from densely packed decimal encoding (DPD)
+/// to binary encoding (BID).
+///
+/// Non-canonical declets are accepted and decoded as their canonical counterparts.
+pub fn bid_dpd_to_bid64(x: BidUint64) -> BidUint64 {
+  bid_dpd_to_bid(x as u128, &DECIMAL64) as BidUint64
+}
+
+/// Converts a 128-bit decimal floating-point value from binary encoding (BID)
+/// to densely packed decimal encoding (DPD).
+///
+/// Non-canonical coefficients and NaN payloads are converted to zero.
+///
+/// # Examples
+///
+/// ```
+/// use decimus::{BidUint128, bid_dpd_to_bid128, bid128_to_dpd128};
+///
+/// let one = BidUint128 { w: [0x0000000000000001, 0x3040000000000000] };
+/// let dpd = bid128_to_dpd128(one);
+/// assert_eq!(BidUint128 { w: [0x0000000000000001, 0x2208000000000000] }, dpd);
+/// assert_eq!(one, bid_dpd_to_bid128(dpd));
+/// ```
+pub fn bid128_to_dpd128(x: BidUint128) -> BidUint128 {
+  bid_to_dpd(x.into(), &DECIMAL128).into()
+}
+
+/// Converts a 128-bit decimal floating-point value from densely packed decimal encoding (DPD)
+/// to binary encoding (BID).
+///
+/// Non-canonical declets are accepted and decoded as their canonical counterparts.
+pub fn bid_dpd_to_bid128(x: BidUint128) -> BidUint128 {
+  bid_dpd_to_bid(x.into(), &DECIMAL128).into()
+}
@@ -2,8 +2,8 @@ use crate::BidUint128;
 use crate::bid_internal::*;
 
 /// Converts signed 32-bit integer into 128-bit decimal floting-point value.
-pub fn bid128_from_int32(x: i32) -> BidUint128 {
-  let mut res = BidUint128::default();
+pub const fn bid128_from_int32(x: i32) -> BidUint128 {
+  let mut res = BidUint128 { w: [0, 0] };
   // If integer is negative, use the absolute value.
   let u = x as u32;
   if (u & SIGNMASK32) == SIGNMASK32 {
@@ -50,3 +50,16 @@ pub const BID_UNDERFLOW_INEXACT_EXCEPTION: u32 = DEC_FE_UNDERFLOW | DEC_FE_INEXA
 
 /// Overflow inexact exception.
 pub const BID_OVERFLOW_INEXACT_EXCEPTION: u32 = DEC_FE_OVERFLOW | DEC_FE_INEXACT;
+
+/// Tininess detected before rounding.
+pub const BID_TININESS_BEFORE_ROUNDING: u32 = 0x0;
+
+/// Tininess detected after rounding.
+pub const BID_TININESS_AFTER_ROUNDING: u32 = 0x1;
+
+/// Default tininess detection method, selected with `decimal-tiny-detection-after-rounding` feature.
+pub const BID_TININESS_DEFAULT: u32 = if cfg!(feature = "decimal-tiny-detection-after-rounding") {
+  BID_TININESS_AFTER_ROUNDING
+} else {
+  BID_TININESS_BEFORE_ROUNDING
+};
@@ -1103,14 +1103,9 @@ pub fn bid_get_bid128(pres: &mut BidUint128, sgn: BidUint64, mut expon: i32, mut
       if cfg!(feature = "bid-set-status-flags") {
         set_status_flags!(pfpsf, BID_OVERFLOW_EXCEPTION | BID_INEXACT_EXCEPTION);
       }
-      if cfg!(not(feature = "ieee-round-nearest-ties-away")) && cfg!(not(feature = "ieee-round-nearest")) {
-        if rnd_mode == BID_ROUNDING_TO_ZERO || (sgn > 0 && rnd_mode == BID_ROUNDING_UP) || (sgn == 0 && rnd_mode == BID_ROUNDING_DOWN) {
-          pres.w[1] = sgn | _LARGEST_BID128_HIGH;
-          pres.w[0] = _LARGEST_BID128_LOW;
-        } else {
-          pres.w[1] = sgn | INFINITY_MASK64;
-          pres.w[0] = 0;
-        }
+      if rnd_mode == BID_ROUNDING_TO_ZERO || (sgn > 0 && rnd_mode == BID_ROUNDING_UP) || (sgn == 0 && rnd_mode == BID_ROUNDING_DOWN) {
+        pres.w[1] = sgn | _LARGEST_BID128_HIGH;
+        pres.w[0] = _LARGEST_BID128_LOW;
       } else {
         pres.w[1] = sgn | INFINITY_MASK64;
         pres.w[0] = 0;
@@ -1148,11 +1143,9 @@ pub fn bid_handle_uf_128_rem(pres: &mut BidUint128, sgn: BidUint64, expon: i32,
     }
     pres.w[1] = sgn;
     pres.w[0] = 0;
-    if cfg!(not(feature = "ieee-round-nearest-ties-away")) && cfg!(not(feature = "ieee-round-nearest")) {
-      // Round to 1 at least significant position
-      if (sgn > 0 && rounding == BID_ROUNDING_DOWN) || (sgn == 0 && rounding == BID_ROUNDING_UP) {
-        pres.w[0] = 1;
-      }
+    // Round to 1 at least significant position
+    if (sgn > 0 && rounding == BID_ROUNDING_DOWN) || (sgn == 0 && rounding == BID_ROUNDING_UP) {
+      pres.w[0] = 1;
     }
     return *pres;
   }
@@ -1171,17 +1164,9 @@ pub fn bid_handle_uf_128_rem(pres: &mut BidUint128, sgn: BidUint64, expon: i32,
 
   let ed2: i32 = 1 - expon;
   // add rounding constant to cq
-  if cfg!(not(feature = "ieee-round-nearest-ties-away")) {
-    if cfg!(not(feature = "ieee-round-nearest")) {
-      rmode = rounding;
-      if sgn > 0 && rmode.wrapping_sub(1) < 2 {
-        rmode = 3 - rmode;
-      }
-    } else {
-      rmode = 0;
-    }
-  } else {
-    rmode = 0;
+  rmode = rounding;
+  if sgn > 0 && rmode.wrapping_sub(1) < 2 {
+    rmode = 3 - rmode;
   }
 
   let t128: BidUint128 = bid_round_const_table_128![rmode, ed2];
@@ -1199,15 +1184,12 @@ pub fn bid_handle_uf_128_rem(pres: &mut BidUint128, sgn: BidUint64, expon: i32,
     shr_128!(cq, qh, amount);
   }
 
-  if cfg!(not(feature = "ieee-round-nearest-ties-away")) {
-    let feature_gate = if cfg!(feature = "ieee-round-nearest") { true } else { rounding == 0 };
-    if feature_gate && (cq.w[0] & 1) > 0 {
-      // check whether fractional part of initial_P/10^ed1 is exactly .5
-      // get remainder
-      shl_128_long!(qh1, qh, (128 - amount));
-      if qh1.w[1] == 0 && qh1.w[0] == 0 && (ql.w[1] < bid_reciprocals10_128![ed2].w[1] || (ql.w[1] == bid_reciprocals10_128![ed2].w[1] && ql.w[0] < bid_reciprocals10_128![ed2].w[0])) {
-        dec!(cq.w[0]);
-      }
+  if rounding == BID_ROUNDING_TO_NEAREST && (cq.w[0] & 1) > 0 {
+    // check whether fractional part of initial_P/10^ed1 is exactly .5
+    // get remainder
+    shl_128_long!(qh1, qh, (128 - amount));
+    if qh1.w[1] == 0 && qh1.w[0] == 0 && (ql.w[1] < bid_reciprocals10_128![ed2].w[1] || (ql.w[1] == bid_reciprocals10_128![ed2].w[1] && ql.w[0] < bid_reciprocals10_128![ed2].w[0])) {
+      dec!(cq.w[0]);
     }
   }
 
@@ -1270,7 +1252,6 @@ pub fn handle_uf_128(pres: &mut BidUint128, sgn: BidUint64, expon: &mut i32, mut
   let mut ql: BidUint128 = Default::default();
   let mut carry: BidUint64;
   let cy: BidUint64;
-  let rmode: u32;
   let mut status: u32;
 
   // Underlow occurs.
@@ -1280,26 +1261,15 @@ pub fn handle_uf_128(pres: &mut BidUint128, sgn: BidUint64, expon: &mut i32, mut
     }
     pres.w[1] = sgn;
     pres.w[0] = 0;
-    if !cfg!(feature = "ieee-round-nearest-ties-away") && !cfg!(feature = "ieee-round-nearest") {
-      use crate::bid_functions::{BID_ROUNDING_DOWN, BID_ROUNDING_UP};
-      if (sgn > 0 && rnd_mode == BID_ROUNDING_DOWN) || (sgn == 0 && rnd_mode == BID_ROUNDING_UP) {
-        pres.w[0] = 1;
-      }
+    if (sgn > 0 && rnd_mode == BID_ROUNDING_DOWN) || (sgn == 0 && rnd_mode == BID_ROUNDING_UP) {
+      pres.w[0] = 1;
     }
     return *pres;
   }
 
   let ed2 = 0 - *expon;
   // Add rounding constant to 'cq'.
-  if !cfg!(feature = "ieee-round-nearest-ties-away") {
-    if !cfg!(feature = "ieee-round-nearest") {
-      rmode = if sgn > 0 && (rnd_mode.wrapping_sub(1)) < 2 { 3 - rnd_mode } else { rnd_mode }
-    } else {
-      rmode = 0;
-    }
-  } else {
-    rmode = 0;
-  }
+  let rmode = if sgn > 0 && (rnd_mode.wrapping_sub(1)) < 2 { 3 - rnd_mode } else { rnd_mode };
 
   let t128 = bid_round_const_table_128!(rmode, ed2);
   add_carry_out!(cq.w[0], carry, t128.w[0], cq.w[0]);
@@ -1318,15 +1288,12 @@ pub fn handle_uf_128(pres: &mut BidUint128, sgn: BidUint64, expon: &mut i32, mut
 
   *expon = 0;
 
-  if !cfg!(feature = "ieee-round-nearest-ties-away") {
-    let feature_gate = { if cfg!(feature = "ieee-round-nearest") { true } else { rnd_mode == 0 } };
-    if feature_gate && cq.w[0] & 1 > 0 {
-      // Check whether fractional part of initial_P / 10 ^ ed1 is exactly .5
-      // Get the remainder.
-      shl_128_long!(qh1, qh, 128 - amount);
-      if qh1.w[1] == 0 && qh1.w[0] == 0 && (ql.w[1] < bid_reciprocals10_128!(ed2).w[1] || (ql.w[1] == bid_reciprocals10_128!(ed2).w[1] && ql.w[0] < bid_reciprocals10_128!(ed2).w[0])) {
-        cq.w[0] -= 1;
-      }
+  if rnd_mode == BID_ROUNDING_TO_NEAREST && cq.w[0] & 1 > 0 {
+    // Check whether fractional part of initial_P / 10 ^ ed1 is exactly .5
+    // Get the remainder.
+    shl_128_long!(qh1, qh, 128 - amount);
+    if qh1.w[1] == 0 && qh1.w[0] == 0 && (ql.w[1] < bid_reciprocals10_128!(ed2).w[1] || (ql.w[1] == bid_reciprocals10_128!(ed2).w[1] && ql.w[0] < bid_reciprocals10_128!(ed2).w[0])) {
+      cq.w[0] -= 1;
     }
   }
 
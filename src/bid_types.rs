@@ -44,6 +44,72 @@ impl Debug for BidUint128 {
   }
 }
 
+impl BidUint128 {
+  /// Returns the memory representation of the raw encoding as a byte array in little-endian byte order.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use decimus::BidUint128;
+  ///
+  /// let x = BidUint128 { w: [0x0000000000000001, 0x3040000000000000] };
+  /// assert_eq!([1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x40, 0x30], x.to_le_bytes());
+  /// ```
+  pub const fn to_le_bytes(self) -> [u8; 16] {
+    self.to_u128().to_le_bytes()
+  }
+
+  /// Returns the memory representation of the raw encoding as a byte array in big-endian byte order.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use decimus::BidUint128;
+  ///
+  /// let x = BidUint128 { w: [0x0000000000000001, 0x3040000000000000] };
+  /// assert_eq!([0x30, 0x40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1], x.to_be_bytes());
+  /// ```
+  pub const fn to_be_bytes(self) -> [u8; 16] {
+    self.to_u128().to_be_bytes()
+  }
+
+  /// Creates a value from its raw encoding stored as a byte array in little-endian byte order.
+  pub const fn from_le_bytes(bytes: [u8; 16]) -> Self {
+    Self::from_u128(u128::from_le_bytes(bytes))
+  }
+
+  /// Creates a value from its raw encoding stored as a byte array in big-endian byte order.
+  pub const fn from_be_bytes(bytes: [u8; 16]) -> Self {
+    Self::from_u128(u128::from_be_bytes(bytes))
+  }
+
+  /// Returns the raw encoding as an unsigned integer.
+  const fn to_u128(self) -> u128 {
+    ((self.w[1] as u128) << 64) | self.w[0] as u128
+  }
+
+  /// Creates a value from the raw encoding stored in an unsigned integer.
+  const fn from_u128(x: u128) -> Self {
+    Self { w: [x as BidUint64, (x >> 64) as BidUint64] }
+  }
+}
+
+impl From<u128> for BidUint128 {
+  /// Creates a value from the raw encoding stored in an unsigned integer,
+  /// the most significant bits of the integer become `w[1]`.
+  fn from(x: u128) -> Self {
+    Self::from_u128(x)
+  }
+}
+
+impl From<BidUint128> for u128 {
+  /// Returns the raw encoding as an unsigned integer,
+  /// `w[1]` becomes the most significant bits of the integer.
+  fn from(x: BidUint128) -> Self {
+    x.to_u128()
+  }
+}
+
 /// 256-bit decimal floating-point in binary format.
 #[repr(C, align(16))]
 #[derive(Default, Copy, Clone, PartialEq, Eq)]
@@ -5,38 +5,73 @@
 #![deny(rustdoc::broken_intra_doc_links)]
 #![deny(rustdoc::missing_crate_level_docs)]
 
+#[cfg(feature = "alloc")]
 extern crate alloc;
 
 mod bid128;
 mod bid128_2_str_macros;
 mod bid128_2_str_tables;
 mod bid128_add;
+mod bid128_arrow;
+mod bid128_bson;
+mod bid128_checked;
 mod bid128_common;
 mod bid128_div;
 mod bid128_fma;
+mod bid128_format;
+mod bid128_literal;
+mod bid128_locale;
 mod bid128_mul;
 mod bid128_noncomp;
+mod bid128_parts;
+mod bid128_pg_numeric;
+mod bid128_precision;
+mod bid128_raw;
+#[cfg(feature = "serde")]
+pub mod bid128_serde;
 mod bid128_string;
 mod bid_conf;
+mod bid_context;
 mod bid_convert_data;
 mod bid_decimal_data;
 mod bid_div_macros;
+mod bid_dpd;
 mod bid_from_int;
 mod bid_functions;
 mod bid_internal;
 mod bid_round;
 mod bid_types;
 
-pub use bid_conf::{IdecFlags, IdecRound};
+pub use bid_conf::{IdecFlags, IdecRound, IdecTininess};
+pub use bid_context::Context;
+pub use bid_dpd::{bid_dpd_to_bid32, bid_dpd_to_bid64, bid_dpd_to_bid128, bid32_to_dpd32, bid64_to_dpd64, bid128_to_dpd128};
 pub use bid_from_int::bid128_from_int32;
 pub use bid_functions::{
   BID_DENORMAL_EXCEPTION, BID_EXACT_STATUS, BID_INEXACT_EXCEPTION, BID_INVALID_EXCEPTION, BID_NO_EXCEPTION, BID_OVERFLOW_EXCEPTION, BID_OVERFLOW_INEXACT_EXCEPTION, BID_ROUNDING_DOWN, BID_ROUNDING_TIES_AWAY, BID_ROUNDING_TO_NEAREST,
-  BID_ROUNDING_TO_ZERO, BID_ROUNDING_UP, BID_UNDERFLOW_EXCEPTION, BID_UNDERFLOW_INEXACT_EXCEPTION, BID_ZERO_DIVIDE_EXCEPTION,
+  BID_ROUNDING_TO_ZERO, BID_ROUNDING_UP, BID_TININESS_AFTER_ROUNDING, BID_TININESS_BEFORE_ROUNDING, BID_TININESS_DEFAULT, BID_UNDERFLOW_EXCEPTION, BID_UNDERFLOW_INEXACT_EXCEPTION, BID_ZERO_DIVIDE_EXCEPTION,
 };
 pub use bid_types::{BidUint32, BidUint64, BidUint128, BidUint192, BidUint256};
 pub use bid128_add::{bid128_add, bid128_sub};
+pub use bid128_arrow::{bid128_from_decimal128_array, bid128_from_decimal256_array, bid128_to_decimal128_array, bid128_to_decimal256_array};
+#[cfg(feature = "alloc")]
+pub use bid128_bson::bid128_to_bson_string;
+pub use bid128_bson::{bid128_from_bson, bid128_from_bson_string, bid128_to_bson, bid128_write_bson_string};
+pub use bid128_checked::{DecimalError, Operation, bid128_checked_add, bid128_checked_div, bid128_checked_fma, bid128_checked_mul, bid128_checked_sub};
 pub use bid128_div::bid128_div;
-pub use bid128_fma::bid128_fma;
-pub use bid128_mul::bid128_mul;
-pub use bid128_noncomp::bid128_is_zero;
-pub use bid128_string::{bid128_from_string, bid128_to_string};
+pub use bid128_fma::{bid128_fma, bid128_fma_with_tininess};
+#[cfg(feature = "alloc")]
+pub use bid128_format::{bid128_to_eng_string, bid128_to_sci_string};
+pub use bid128_format::{bid128_write_eng_string, bid128_write_engineering, bid128_write_fixed, bid128_write_sci_string, bid128_write_scientific};
+pub use bid128_literal::bid128_from_literal;
+pub use bid128_locale::{NumberFormat, bid128_from_localized_string, bid128_write_localized};
+pub use bid128_mul::{bid128_mul, bid128_mul_with_tininess};
+pub use bid128_noncomp::{ClassTypes, bid128_class, bid128_get_payload, bid128_is_finite, bid128_is_inf, bid128_is_nan, bid128_is_signaling, bid128_is_zero, bid128_nan, bid128_set_payload, bid128_set_payload_signaling};
+pub use bid128_parts::{bid128_from_i128, bid128_from_parts, bid128_to_i128_scaled, bid128_to_parts};
+pub use bid128_pg_numeric::{BID128_MAX_PG_NUMERIC_LENGTH, PgNumericError, bid128_from_pg_numeric, bid128_to_pg_numeric};
+pub use bid128_precision::{bid128_add_with_precision, bid128_div_with_precision, bid128_fma_with_precision, bid128_mul_with_precision, bid128_sub_with_precision};
+pub use bid128_raw::{DecodedBid128, bid128_decode, bid128_from_raw_string, bid128_write_raw};
+#[cfg(feature = "alloc")]
+pub use bid128_string::bid128_to_string;
+pub use bid128_string::{
+  BID128_MAX_STRING_LENGTH, ParseDecimalError, ParseDecimalErrorKind, bid128_from_bytes, bid128_from_string, bid128_from_string_const, bid128_from_string_strict, bid128_from_string_unicode, bid128_to_str_buf, bid128_write_string,
+};
@@ -1,7 +1,16 @@
 use decimus::IdecFlags;
 
 mod test_bid128;
+mod test_bson;
+mod test_bytes;
+mod test_context;
 mod test_debug;
+mod test_dec;
+mod test_display;
+mod test_dpd;
+mod test_pg_numeric;
+#[cfg(feature = "serde")]
+mod test_serde;
 
 type BidArray = [u64; 2];
 
@@ -1,11 +1,50 @@
 use super::*;
 
 mod test_bid128_add;
+mod test_bid128_checked;
+mod test_bid128_class;
+mod test_bid128_const;
+mod test_bid128_decode;
 mod test_bid128_div;
 mod test_bid128_fma;
+mod test_bid128_from_bytes;
+mod test_bid128_from_decimal128_array;
+mod test_bid128_from_decimal256_array;
+mod test_bid128_from_i128;
 mod test_bid128_from_int32;
+mod test_bid128_from_localized_string;
+mod test_bid128_from_parts;
+mod test_bid128_from_raw_string;
 mod test_bid128_from_string;
+mod test_bid128_from_string_const;
+mod test_bid128_from_string_strict;
+mod test_bid128_from_string_unicode;
+mod test_bid128_get_payload;
+mod test_bid128_is_finite;
+mod test_bid128_is_inf;
+mod test_bid128_is_nan;
+mod test_bid128_is_signaling;
 mod test_bid128_is_zero;
 mod test_bid128_mul;
+mod test_bid128_nan;
+mod test_bid128_precision;
+mod test_bid128_set_payload;
+mod test_bid128_set_payload_signaling;
 mod test_bid128_sub;
+mod test_bid128_tininess;
+mod test_bid128_to_decimal128_array;
+mod test_bid128_to_decimal256_array;
+#[cfg(feature = "alloc")]
+mod test_bid128_to_eng_string;
+mod test_bid128_to_i128_scaled;
+mod test_bid128_to_parts;
+#[cfg(feature = "alloc")]
+mod test_bid128_to_sci_string;
+mod test_bid128_to_str_buf;
+#[cfg(feature = "alloc")]
 mod test_bid128_to_string;
+mod test_bid128_write_engineering;
+mod test_bid128_write_fixed;
+mod test_bid128_write_localized;
+mod test_bid128_write_raw;
+mod test_bid128_write_scientific;
@@ -2440,3 +2440,8 @@ fn _0482() {
 fn _0483() {
   check!(0, 0, 0, "+6875897.879876979566658996675E6133", "+8.464777979989329969757976492E6138", [0x5ffe0000f985fe93, 0x59e4745fb3d1594a], F_00_00, F_00_00, F_00_00);
 }
+
+#[test]
+fn _0484() {
+  check!(0, 0, 0, "46223E-34", "895157807350000000000E-16", [0x3007b958ccba276b, 0x530c094149800000], F_20_20, F_00_00, F_00_00);
+}
@@ -0,0 +1,188 @@
+use decimus::*;
+
+fn d(s: &str) -> BidUint128 {
+  let mut flags = BID_EXACT_STATUS;
+  bid128_from_string(s, BID_ROUNDING_TO_NEAREST, &mut flags)
+}
+
+#[test]
+fn _0001() {
+  let mut flags = BID_EXACT_STATUS;
+  let traps = BID_INVALID_EXCEPTION | BID_ZERO_DIVIDE_EXCEPTION | BID_OVERFLOW_EXCEPTION;
+  let res = bid128_checked_add(d("1.5"), d("2.25"), BID_ROUNDING_TO_NEAREST, BID_TININESS_DEFAULT, traps, &mut flags);
+  assert_eq!(Ok(d("3.75")), res);
+  assert_eq!(BID_EXACT_STATUS, flags);
+}
+
+#[test]
+fn _0002() {
+  let mut flags = BID_EXACT_STATUS;
+  let err = bid128_checked_div(d("1"), d("0"), BID_ROUNDING_TO_NEAREST, BID_TININESS_DEFAULT, BID_ZERO_DIVIDE_EXCEPTION, &mut flags).unwrap_err();
+  assert_eq!(Operation::Div(d("1"), d("0")), err.operation);
+  assert_eq!(d("Inf"), err.result);
+  assert_eq!(BID_ZERO_DIVIDE_EXCEPTION, err.flags);
+  assert_eq!(BID_ZERO_DIVIDE_EXCEPTION, err.trapped);
+  assert_eq!(BID_ZERO_DIVIDE_EXCEPTION, flags);
+  assert_eq!("division raised division by zero", err.to_string());
+}
+
+#[test]
+fn _0003() {
+  let mut flags = BID_EXACT_STATUS;
+  let res = bid128_checked_div(d("-1"), d("0"), BID_ROUNDING_TO_NEAREST, BID_TININESS_DEFAULT, BID_INVALID_EXCEPTION, &mut flags);
+  assert_eq!(Ok(d("-Inf")), res);
+  assert_eq!(BID_ZERO_DIVIDE_EXCEPTION, flags);
+}
+
+#[test]
+fn _0004() {
+  let mut flags = BID_EXACT_STATUS;
+  let err = bid128_checked_div(d("0"), d("0"), BID_ROUNDING_TO_NEAREST, BID_TININESS_DEFAULT, BID_INVALID_EXCEPTION, &mut flags).unwrap_err();
+  assert!(bid128_is_nan(err.result));
+  assert_eq!(BID_INVALID_EXCEPTION, err.trapped);
+}
+
+#[test]
+fn _0005() {
+  let mut flags = BID_EXACT_STATUS;
+  let err = bid128_checked_fma(d("Inf"), d("0"), d("1"), BID_ROUNDING_TO_NEAREST, BID_TININESS_DEFAULT, BID_INVALID_EXCEPTION, &mut flags).unwrap_err();
+  assert_eq!(Operation::Fma(d("Inf"), d("0"), d("1")), err.operation);
+  assert_eq!(BID_INVALID_EXCEPTION, err.trapped);
+  assert_eq!("fused multiply-add raised invalid operation", err.to_string());
+}
+
+#[test]
+fn _0006() {
+  let mut flags = BID_EXACT_STATUS;
+  let err = bid128_checked_sub(d("SNaN"), d("1"), BID_ROUNDING_TO_NEAREST, BID_TININESS_DEFAULT, BID_INVALID_EXCEPTION, &mut flags).unwrap_err();
+  assert_eq!(Operation::Sub(d("SNaN"), d("1")), err.operation);
+  assert_eq!(BID_INVALID_EXCEPTION, err.trapped);
+}
+
+#[test]
+fn _0007() {
+  let mut flags = BID_EXACT_STATUS;
+  let res = bid128_checked_add(d("NaN"), d("1"), BID_ROUNDING_TO_NEAREST, BID_TININESS_DEFAULT, BID_INVALID_EXCEPTION, &mut flags);
+  assert!(bid128_is_nan(res.unwrap()));
+  assert_eq!(BID_EXACT_STATUS, flags);
+}
+
+#[test]
+fn _0008() {
+  let mut flags = BID_EXACT_STATUS;
+  let err = bid128_checked_mul(d("1E+6000"), d("1E+6000"), BID_ROUNDING_TO_NEAREST, BID_TININESS_DEFAULT, BID_OVERFLOW_EXCEPTION, &mut flags).unwrap_err();
+  assert_eq!(d("Inf"), err.result);
+  assert_eq!(BID_OVERFLOW_EXCEPTION | BID_INEXACT_EXCEPTION, err.flags);
+  assert_eq!(BID_OVERFLOW_EXCEPTION, err.trapped);
+  assert_eq!("multiplication raised overflow", err.to_string());
+}
+
+#[test]
+fn _0009() {
+  let mut flags = BID_EXACT_STATUS;
+  let x = d("0.3333333333333333333333333333333333");
+  let err = bid128_checked_mul(x, d("3.3"), BID_ROUNDING_TO_NEAREST, BID_TININESS_DEFAULT, BID_INEXACT_EXCEPTION | BID_INVALID_EXCEPTION, &mut flags).unwrap_err();
+  assert_eq!(d("1.100000000000000000000000000000000"), err.result);
+  assert_eq!(BID_INEXACT_EXCEPTION, err.trapped);
+  assert_eq!("multiplication raised inexact", err.to_string());
+}
+
+#[test]
+fn _0010() {
+  let mut ctx = Context::default();
+  ctx.set_traps(BID_ZERO_DIVIDE_EXCEPTION);
+  let one = ctx.from_string("1");
+  let zero = ctx.from_string("0");
+  assert_eq!(Ok(one), ctx.checked_div(one, one));
+  assert!(ctx.checked_div(one, zero).is_err());
+  assert_eq!(BID_ZERO_DIVIDE_EXCEPTION, ctx.flags());
+  assert!(ctx.checked_mul(one, zero).is_ok());
+  assert!(ctx.checked_fma(one, one, zero).is_ok());
+  assert!(ctx.checked_sub(one, zero).is_ok());
+  assert!(ctx.checked_add(one, zero).is_ok());
+}
+
+#[test]
+fn _0011() {
+  let mut flags = BID_EXACT_STATUS;
+  let err = bid128_checked_div(d("1"), d("3"), BID_ROUNDING_TO_NEAREST, BID_TININESS_DEFAULT, BID_INEXACT_EXCEPTION, &mut flags).unwrap_err();
+  assert_eq!(d("0.3333333333333333333333333333333333"), err.result);
+  assert_eq!(BID_INEXACT_EXCEPTION, err.trapped);
+  assert_eq!(BID_INEXACT_EXCEPTION, flags);
+  assert_eq!("division raised inexact", err.to_string());
+}
+
+#[test]
+fn _0012() {
+  let mut flags = BID_EXACT_STATUS;
+  let err = bid128_checked_mul(d("3E-3100"), d("3E-3100"), BID_ROUNDING_TO_NEAREST, BID_TININESS_DEFAULT, BID_UNDERFLOW_EXCEPTION, &mut flags).unwrap_err();
+  assert_eq!(d("0E-6176"), err.result);
+  assert_eq!(BID_UNDERFLOW_EXCEPTION | BID_INEXACT_EXCEPTION, err.flags);
+  assert_eq!(BID_UNDERFLOW_EXCEPTION, err.trapped);
+  assert_eq!("multiplication raised underflow", err.to_string());
+}
+
+#[test]
+fn _0013() {
+  let mut ctx = Context::default();
+  ctx.set_traps(BID_INEXACT_EXCEPTION);
+  let one = ctx.from_string("1");
+  let three = ctx.from_string("3");
+  assert_eq!(Ok(one), ctx.checked_div(three, three));
+  let err = ctx.checked_div(one, three).unwrap_err();
+  assert_eq!(BID_INEXACT_EXCEPTION, err.trapped);
+  assert_eq!(BID_INEXACT_EXCEPTION, ctx.flags());
+}
+
+#[test]
+fn _0014() {
+  let mut ctx = Context::default();
+  ctx.set_traps(BID_UNDERFLOW_EXCEPTION);
+  let x = ctx.from_string("1E-6170");
+  let y = ctx.from_string("1E+10");
+  assert!(ctx.checked_div(x, y).is_err());
+  assert_eq!(BID_UNDERFLOW_EXCEPTION | BID_INEXACT_EXCEPTION, ctx.flags());
+}
+
+#[test]
+fn _0015() {
+  let x = d("9999999999999999999999999999999990E-6176");
+  let y = d("1000000000000000000000000000000001E-34");
+  let traps = BID_UNDERFLOW_EXCEPTION;
+  let mut flags = BID_EXACT_STATUS;
+  let err = bid128_checked_mul(x, y, BID_ROUNDING_TO_NEAREST, BID_TININESS_BEFORE_ROUNDING, traps, &mut flags).unwrap_err();
+  assert_eq!(d("1.000000000000000000000000000000000E-6143"), err.result);
+  assert_eq!(BID_UNDERFLOW_EXCEPTION | BID_INEXACT_EXCEPTION, err.flags);
+  let mut flags = BID_EXACT_STATUS;
+  let res = bid128_checked_mul(x, y, BID_ROUNDING_TO_NEAREST, BID_TININESS_AFTER_ROUNDING, traps, &mut flags);
+  assert_eq!(Ok(d("1.000000000000000000000000000000000E-6143")), res);
+  assert_eq!(BID_INEXACT_EXCEPTION, flags);
+}
+
+#[test]
+fn _0016() {
+  let x = d("9999999999999999999999999999999999E-6176");
+  let y = d("0.1");
+  let traps = BID_UNDERFLOW_EXCEPTION;
+  let mut flags = BID_EXACT_STATUS;
+  let err = bid128_checked_mul(x, y, BID_ROUNDING_TO_NEAREST, BID_TININESS_AFTER_ROUNDING, traps, &mut flags).unwrap_err();
+  assert_eq!(d("1.000000000000000000000000000000000E-6143"), err.result);
+  assert_eq!(BID_UNDERFLOW_EXCEPTION | BID_INEXACT_EXCEPTION, err.flags);
+  let mut flags = BID_EXACT_STATUS;
+  let res = bid128_checked_mul(x, y, BID_ROUNDING_TO_ZERO, BID_TININESS_AFTER_ROUNDING, traps, &mut flags);
+  assert!(res.is_err());
+  assert_eq!(BID_UNDERFLOW_EXCEPTION | BID_INEXACT_EXCEPTION, flags);
+}
+
+#[test]
+fn _0017() {
+  let mut flags = BID_EXACT_STATUS;
+  let traps = BID_INEXACT_EXCEPTION | BID_UNDERFLOW_EXCEPTION;
+  let res = bid128_checked_sub(d("1E-6176"), d("1E-6176"), BID_ROUNDING_DOWN, BID_TININESS_DEFAULT, traps, &mut flags);
+  assert_eq!(Ok(d("-0E-6176")), res);
+  let res = bid128_checked_div(d("6E-6170"), d("2"), BID_ROUNDING_TO_NEAREST, BID_TININESS_DEFAULT, traps, &mut flags);
+  assert_eq!(Ok(d("3E-6170")), res);
+  let res = bid128_checked_fma(d("3E-3100"), d("3E-3076"), d("1E-6176"), BID_ROUNDING_TO_NEAREST, BID_TININESS_DEFAULT, traps, &mut flags);
+  assert_eq!(Ok(d("1.0E-6175")), res);
+  assert_eq!(BID_EXACT_STATUS, flags);
+}
@@ -0,0 +1,88 @@
+use decimus::{BidUint128, ClassTypes, bid128_class};
+
+macro_rules! check {
+  ($x:expr, $expected:expr) => {
+    let x = BidUint128 { w: [$x[1], $x[0]] };
+    assert_eq!($expected, bid128_class(x));
+  };
+}
+
+#[test]
+fn _0001() {
+  check!([0x7e00000000000000, 0x0000000000000000], ClassTypes::SignalingNaN);
+}
+
+#[test]
+fn _0002() {
+  check!([0xfc00000000000000, 0x0000000000000001], ClassTypes::QuietNaN);
+}
+
+#[test]
+fn _0003() {
+  check!([0xf800000000000000, 0x0000000000000000], ClassTypes::NegativeInfinity);
+}
+
+#[test]
+fn _0004() {
+  check!([0x7800000000000000, 0x0000000000000000], ClassTypes::PositiveInfinity);
+}
+
+#[test]
+fn _0005() {
+  check!([0x3040000000000000, 0x0000000000000000], ClassTypes::PositiveZero);
+}
+
+#[test]
+fn _0006() {
+  check!([0xb040000000000000, 0x0000000000000000], ClassTypes::NegativeZero);
+}
+
+#[test]
+fn _0007() {
+  check!([0x3041ed09bead87c0, 0x378d8e6400000000], ClassTypes::PositiveZero);
+}
+
+#[test]
+fn _0008() {
+  check!([0xe000000000000000, 0x0000000000000001], ClassTypes::NegativeZero);
+}
+
+#[test]
+fn _0009() {
+  check!([0x3040000000000000, 0x0000000000000001], ClassTypes::PositiveNormal);
+}
+
+#[test]
+fn _0010() {
+  check!([0xb040000000000000, 0x0000000000000001], ClassTypes::NegativeNormal);
+}
+
+#[test]
+fn _0011() {
+  check!([0x0000000000000000, 0x0000000000000001], ClassTypes::PositiveSubnormal);
+}
+
+#[test]
+fn _0012() {
+  check!([0x8000000000000000, 0x0000000000000001], ClassTypes::NegativeSubnormal);
+}
+
+#[test]
+fn _0013() {
+  check!([0x0000314dc6448d93, 0x38c15b09ffffffff], ClassTypes::PositiveSubnormal);
+}
+
+#[test]
+fn _0014() {
+  check!([0x0000314dc6448d93, 0x38c15b0a00000000], ClassTypes::PositiveNormal);
+}
+
+#[test]
+fn _0015() {
+  check!([0x0042000000000000, 0x0000000000000001], ClassTypes::PositiveNormal);
+}
+
+#[test]
+fn _0016() {
+  check!([0x0040000000000000, 0x0000000000000001], ClassTypes::PositiveSubnormal);
+}
@@ -0,0 +1,42 @@
+use decimus::{
+  BidUint128, ClassTypes, bid128_class, bid128_from_int32, bid128_from_string_const, bid128_get_payload, bid128_is_finite, bid128_is_inf, bid128_is_nan, bid128_is_signaling, bid128_is_zero, bid128_nan, bid128_set_payload,
+  bid128_set_payload_signaling,
+};
+
+const HUNDRED: BidUint128 = bid128_from_int32(100);
+const MINUS_ONE: BidUint128 = bid128_from_int32(-1);
+const INF: BidUint128 = bid128_from_string_const("-Inf");
+const NAN: BidUint128 = bid128_nan(42);
+
+#[test]
+fn _0001() {
+  assert_eq!(BidUint128 { w: [100, 0x3040000000000000] }, HUNDRED);
+  assert_eq!(BidUint128 { w: [1, 0xb040000000000000] }, MINUS_ONE);
+}
+
+#[test]
+fn _0002() {
+  const {
+    assert!(bid128_is_zero(bid128_from_int32(0)));
+    assert!(bid128_is_finite(HUNDRED));
+    assert!(bid128_is_inf(INF));
+    assert!(bid128_is_nan(NAN));
+    assert!(!bid128_is_signaling(NAN));
+  }
+}
+
+#[test]
+fn _0003() {
+  const CLASS: ClassTypes = bid128_class(MINUS_ONE);
+  assert_eq!(ClassTypes::NegativeNormal, CLASS);
+}
+
+#[test]
+fn _0004() {
+  const PAYLOAD: BidUint128 = bid128_get_payload(NAN);
+  const QUIET: BidUint128 = bid128_set_payload(HUNDRED);
+  const SIGNALING: BidUint128 = bid128_set_payload_signaling(HUNDRED);
+  assert_eq!(BidUint128 { w: [42, 0x3040000000000000] }, PAYLOAD);
+  assert_eq!(BidUint128 { w: [100, 0x7c00000000000000] }, QUIET);
+  assert_eq!(BidUint128 { w: [100, 0x7e00000000000000] }, SIGNALING);
+}
@@ -0,0 +1,84 @@
+use decimus::{BidUint128, ClassTypes, bid128_decode};
+
+macro_rules! check {
+  ($x:expr, $negative:expr, $biased_exponent:expr, $exponent:expr, $coefficient:expr, $canonical:expr, $class:expr) => {
+    let x = BidUint128 { w: [$x[1], $x[0]] };
+    let decoded = bid128_decode(x);
+    assert_eq!(x, decoded.value);
+    assert_eq!($negative, decoded.negative);
+    assert_eq!($biased_exponent, decoded.biased_exponent);
+    assert_eq!($exponent, decoded.exponent);
+    assert_eq!($coefficient, decoded.coefficient);
+    assert_eq!($canonical, decoded.canonical);
+    assert_eq!($class, decoded.class);
+  };
+  ($x:expr, $expected:expr) => {
+    let x = BidUint128 { w: [$x[1], $x[0]] };
+    assert_eq!($expected, format!("{}", bid128_decode(x)));
+  };
+}
+
+#[test]
+fn _0001() {
+  check!([0x3040000000000000, 0x0000000000000001], false, 6176, 0, 1, true, ClassTypes::PositiveNormal);
+}
+
+#[test]
+fn _0002() {
+  check!([0xb03e000000000000, 0x000000000000007b], true, 6175, -1, 123, true, ClassTypes::NegativeNormal);
+}
+
+#[test]
+fn _0003() {
+  check!([0x0000000000000000, 0x0000000000000001], false, 0, -6176, 1, true, ClassTypes::PositiveSubnormal);
+}
+
+#[test]
+fn _0004() {
+  check!([0x5ffe000000000000, 0x0000000000000000], false, 12287, 6111, 0, true, ClassTypes::PositiveZero);
+}
+
+#[test]
+fn _0005() {
+  check!([0x3041ed09bead87c0, 0x378d8e63ffffffff], false, 6176, 0, 9999999999999999999999999999999999, true, ClassTypes::PositiveNormal);
+}
+
+#[test]
+fn _0006() {
+  check!([0x3041ed09bead87c0, 0x378d8e6400000000], false, 6176, 0, 0, false, ClassTypes::PositiveZero);
+}
+
+#[test]
+fn _0007() {
+  check!([0x6c10000000000000, 0x0000000000000000], false, 6176, 0, 0, false, ClassTypes::PositiveZero);
+}
+
+#[test]
+fn _0008() {
+  check!([0xf800000000000000, 0x0000000000000000], true, 0, 0, 0, true, ClassTypes::NegativeInfinity);
+}
+
+#[test]
+fn _0009() {
+  check!([0x7c00000000000000, 0x000000000000002a], false, 0, 0, 42, true, ClassTypes::QuietNaN);
+}
+
+#[test]
+fn _0010() {
+  check!([0xfe00000000000000, 0x0000000000000000], true, 0, 0, 0, true, ClassTypes::SignalingNaN);
+}
+
+#[test]
+fn _0011() {
+  check!([0x7c003fffffffffff, 0xffffffffffffffff], false, 0, 0, 0, false, ClassTypes::QuietNaN);
+}
+
+#[test]
+fn _0012() {
+  check!([0x3040000000000000, 0x0000000000000001], "0x3040000000000000_0000000000000001 (sign: +, exponent: 0, biased exponent: 6176, coefficient: 1, class: PositiveNormal)");
+}
+
+#[test]
+fn _0013() {
+  check!([0x3041ed09bead87c0, 0x378d8e6400000000], "0x3041ed09bead87c0_378d8e6400000000 (sign: +, exponent: 0, biased exponent: 6176, coefficient: 0 (non-canonical), class: PositiveZero)");
+}
@@ -509,12 +509,7 @@ fn _0088() {
 
 #[test]
 fn _0089() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest-ties-away") {
-    [0x0000000000000000, 0x0000000000000001]
-  } else {
-    [0x0000000000000000, 0x0000000000000000]
-  };
-  check!(0, 0, 0, "1E-6176", "2", expected, F_30_00, F_00_00, F_00_00);
+  check!(0, 0, 0, "1E-6176", "2", [0x0000000000000000, 0x0000000000000000], F_30_00, F_00_00, F_00_00);
 }
 
 #[test]
@@ -664,11 +659,7 @@ fn _0115() {
 
 #[test]
 fn _0116() {
-  if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    check!(1, [0x1ea47d723a258782, 0x56018cb55e2192ef], [0xdaf1c0016d4dff25, 0xd69a2d819e52b625], [0x8000000000000000, 0x0000000000000000], F_30_00);
-  } else {
-    check!(1, [0x1ea47d723a258782, 0x56018cb55e2192ef], [0xdaf1c0016d4dff25, 0xd69a2d819e52b625], [0x8000000000000000, 0x0000000000000001], F_30_00);
-  }
+  check!(1, [0x1ea47d723a258782, 0x56018cb55e2192ef], [0xdaf1c0016d4dff25, 0xd69a2d819e52b625], [0x8000000000000000, 0x0000000000000001], F_30_00);
 }
 
 #[test]
@@ -693,11 +684,7 @@ fn _0120() {
 
 #[test]
 fn _0121() {
-  if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    check!(1, [0x40a46a3aae3793cf, 0xb072d3a233765dd6], [0xdfdab50a18d577e9, 0xd23d5a48965dd11f], [0x90c7214d4793b5e3, 0xf7dc4bf5d1b4c8a5], F_20_00);
-  } else {
-    check!(1, [0x40a46a3aae3793cf, 0xb072d3a233765dd6], [0xdfdab50a18d577e9, 0xd23d5a48965dd11f], [0x90c7214d4793b5e3, 0xf7dc4bf5d1b4c8a6], F_20_00);
-  }
+  check!(1, [0x40a46a3aae3793cf, 0xb072d3a233765dd6], [0xdfdab50a18d577e9, 0xd23d5a48965dd11f], [0x90c7214d4793b5e3, 0xf7dc4bf5d1b4c8a6], F_20_00);
 }
 
 #[test]
@@ -707,20 +694,12 @@ fn _0122() {
 
 #[test]
 fn _0123() {
-  if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    check!(1, [0x4f0b131761a5ebfe, 0xe17445ef98b870b9], [0x1c7fb95118314088, 0x0316f0c8237c5127], [0x7800000000000000, 0x0000000000000000], F_28_00);
-  } else {
-    check!(1, [0x4f0b131761a5ebfe, 0xe17445ef98b870b9], [0x1c7fb95118314088, 0x0316f0c8237c5127], [0x5fffed09bead87c0, 0x378d8e63ffffffff], F_28_00);
-  }
+  check!(1, [0x4f0b131761a5ebfe, 0xe17445ef98b870b9], [0x1c7fb95118314088, 0x0316f0c8237c5127], [0x5fffed09bead87c0, 0x378d8e63ffffffff], F_28_00);
 }
 
 #[test]
 fn _0124() {
-  if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    check!(1, [0x52b36974100f4928, 0x0f1bae6a614be06f], [0x1e304bbb94d4425c, 0x1b1a50112a2e1cb1], [0x7800000000000000, 0x0000000000000000], F_28_00);
-  } else {
-    check!(1, [0x52b36974100f4928, 0x0f1bae6a614be06f], [0x1e304bbb94d4425c, 0x1b1a50112a2e1cb1], [0x5fffed09bead87c0, 0x378d8e63ffffffff], F_28_00);
-  }
+  check!(1, [0x52b36974100f4928, 0x0f1bae6a614be06f], [0x1e304bbb94d4425c, 0x1b1a50112a2e1cb1], [0x5fffed09bead87c0, 0x378d8e63ffffffff], F_28_00);
 }
 
 #[test]
@@ -735,11 +714,7 @@ fn _0126() {
 
 #[test]
 fn _0127() {
-  if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    check!(1, [0x5f8cd8f1e56c6b3d, 0x541da77d81887813], [0x1c99d16b5a33a985, 0x2809b8660d9d69f0], [0x7800000000000000, 0x0000000000000000], F_28_00);
-  } else {
-    check!(1, [0x5f8cd8f1e56c6b3d, 0x541da77d81887813], [0x1c99d16b5a33a985, 0x2809b8660d9d69f0], [0x5fffed09bead87c0, 0x378d8e63ffffffff], F_28_00);
-  }
+  check!(1, [0x5f8cd8f1e56c6b3d, 0x541da77d81887813], [0x1c99d16b5a33a985, 0x2809b8660d9d69f0], [0x5fffed09bead87c0, 0x378d8e63ffffffff], F_28_00);
 }
 
 #[test]
@@ -754,20 +729,12 @@ fn _0129() {
 
 #[test]
 fn _0130() {
-  if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    check!(1, [0x8c40c590549957ea, 0x4a9a7cb1e9380813], [0x41153cd932ac2b46, 0x7ac11d6b17ae4462], [0x8000000000000000, 0x0000000000000000], F_30_00);
-  } else {
-    check!(1, [0x8c40c590549957ea, 0x4a9a7cb1e9380813], [0x41153cd932ac2b46, 0x7ac11d6b17ae4462], [0x8000000000000000, 0x0000000000000001], F_30_00);
-  }
+  check!(1, [0x8c40c590549957ea, 0x4a9a7cb1e9380813], [0x41153cd932ac2b46, 0x7ac11d6b17ae4462], [0x8000000000000000, 0x0000000000000001], F_30_00);
 }
 
 #[test]
 fn _0131() {
-  if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    check!(1, [0x8c750c6d27ff163d, 0xf024d4457485fa3c], [0x3cc5d0621a99f027, 0xeadaa3acfbc6eb50], [0x8000000000000000, 0x0000000000000000], F_30_00);
-  } else {
-    check!(1, [0x8c750c6d27ff163d, 0xf024d4457485fa3c], [0x3cc5d0621a99f027, 0xeadaa3acfbc6eb50], [0x8000000000000000, 0x0000000000000001], F_30_00);
-  }
+  check!(1, [0x8c750c6d27ff163d, 0xf024d4457485fa3c], [0x3cc5d0621a99f027, 0xeadaa3acfbc6eb50], [0x8000000000000000, 0x0000000000000001], F_30_00);
 }
 
 #[test]
@@ -777,11 +744,7 @@ fn _0132() {
 
 #[test]
 fn _0133() {
-  if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    check!(1, [0x8e2403cdcba75263, 0x0629b6f7a7010602], [0x5a3980ee7baa631d, 0x3708928990f473e4], [0x8000000000000000, 0x0000000000000000], F_30_00);
-  } else {
-    check!(1, [0x8e2403cdcba75263, 0x0629b6f7a7010602], [0x5a3980ee7baa631d, 0x3708928990f473e4], [0x8000000000000000, 0x0000000000000001], F_30_00);
-  }
+  check!(1, [0x8e2403cdcba75263, 0x0629b6f7a7010602], [0x5a3980ee7baa631d, 0x3708928990f473e4], [0x8000000000000000, 0x0000000000000001], F_30_00);
 }
 
 #[test]
@@ -801,11 +764,7 @@ fn _0136() {
 
 #[test]
 fn _0137() {
-  if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    check!(1, [0x9b5b494c1b0b648a, 0x37a1bb32e5a95fa9], [0xa93a0d7850c02072, 0xd381713d3707026a], [0x2220788822fcbd2b, 0xc4d2d1bab2fdc4f7], F_20_00);
-  } else {
-    check!(1, [0x9b5b494c1b0b648a, 0x37a1bb32e5a95fa9], [0xa93a0d7850c02072, 0xd381713d3707026a], [0x2220788822fcbd2b, 0xc4d2d1bab2fdc4f6], F_20_00);
-  }
+  check!(1, [0x9b5b494c1b0b648a, 0x37a1bb32e5a95fa9], [0xa93a0d7850c02072, 0xd381713d3707026a], [0x2220788822fcbd2b, 0xc4d2d1bab2fdc4f6], F_20_00);
 }
 
 #[test]
@@ -815,29 +774,17 @@ fn _0138() {
 
 #[test]
 fn _0139() {
-  if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    check!(1, [0xca315abb5eb7928a, 0xf36c452c38ae05b0], [0x926e4674bba1c31c, 0xd8256ba008e56abd], [0x7800000000000000, 0x0000000000000000], F_28_00);
-  } else {
-    check!(1, [0xca315abb5eb7928a, 0xf36c452c38ae05b0], [0x926e4674bba1c31c, 0xd8256ba008e56abd], [0x5fffed09bead87c0, 0x378d8e63ffffffff], F_28_00);
-  }
+  check!(1, [0xca315abb5eb7928a, 0xf36c452c38ae05b0], [0x926e4674bba1c31c, 0xd8256ba008e56abd], [0x5fffed09bead87c0, 0x378d8e63ffffffff], F_28_00);
 }
 
 #[test]
 fn _0140() {
-  if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    check!(1, [0xce4eaaae52c725db, 0xa236e46671e9e4d1], [0x1f5d2bf1866d7bd9, 0xa8bed133ede1421e], [0xdeef188f6b4e4710, 0x5d84efb1a381eb09], F_20_00);
-  } else {
-    check!(1, [0xce4eaaae52c725db, 0xa236e46671e9e4d1], [0x1f5d2bf1866d7bd9, 0xa8bed133ede1421e], [0xdeef188f6b4e4710, 0x5d84efb1a381eb0a], F_20_00);
-  }
+  check!(1, [0xce4eaaae52c725db, 0xa236e46671e9e4d1], [0x1f5d2bf1866d7bd9, 0xa8bed133ede1421e], [0xdeef188f6b4e4710, 0x5d84efb1a381eb0a], F_20_00);
 }
 
 #[test]
 fn _0141() {
-  if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    check!(1, [0xcf9fe06cec065c37, 0x1baf1e0586ff927d], [0x80c9b1ce835e3cb1, 0x9b9600b33d3c5041], [0x7800000000000000, 0x0000000000000000], F_28_00);
-  } else {
-    check!(1, [0xcf9fe06cec065c37, 0x1baf1e0586ff927d], [0x80c9b1ce835e3cb1, 0x9b9600b33d3c5041], [0x5fffed09bead87c0, 0x378d8e63ffffffff], F_28_00);
-  }
+  check!(1, [0xcf9fe06cec065c37, 0x1baf1e0586ff927d], [0x80c9b1ce835e3cb1, 0x9b9600b33d3c5041], [0x5fffed09bead87c0, 0x378d8e63ffffffff], F_28_00);
 }
 
 #[test]
@@ -897,12 +844,7 @@ fn _0152() {
 
 #[test]
 fn _0153() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    [0xb00667678cd085a5, 0xdc88e14678177cab]
-  } else {
-    [0xb00667678cd085a5, 0xdc88e14678177cac]
-  };
-  check!(1, 0, 0, "+1100101.01100E0", "-52.45339558946E0", expected, F_20_00, F_00_00, F_00_00);
+  check!(1, 0, 0, "+1100101.01100E0", "-52.45339558946E0", [0xb00667678cd085a5, 0xdc88e14678177cac], F_20_00, F_00_00, F_00_00);
 }
 
 #[test]
@@ -912,12 +854,7 @@ fn _0154() {
 
 #[test]
 fn _0155() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest-ties-away") {
-    [0x0000000000000000, 0x0000000000000001]
-  } else {
-    [0x0000000000000000, 0x0000000000000000]
-  };
-  check!(1, 0, 0, "1E-6176", "2", expected, F_30_00, F_00_00, F_00_00);
+  check!(1, 0, 0, "1E-6176", "2", [0x0000000000000000, 0x0000000000000000], F_30_00, F_00_00, F_00_00);
 }
 
 #[test]
@@ -987,12 +924,7 @@ fn _0168() {
 
 #[test]
 fn _0169() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    [0x0000000000000000, 0x0000000000000000]
-  } else {
-    [0x0000000000000000, 0x0000000000000001]
-  };
-  check!(2, [0x10d580b2040866df, 0x5418d5cf1ad87f60], [0x47fc8a037c585432, 0xc56d954eeae84dbc], expected, F_30_00);
+  check!(2, [0x10d580b2040866df, 0x5418d5cf1ad87f60], [0x47fc8a037c585432, 0xc56d954eeae84dbc], [0x0000000000000000, 0x0000000000000001], F_30_00);
 }
 
 #[test]
@@ -1007,22 +939,12 @@ fn _0171() {
 
 #[test]
 fn _0172() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    [0xc5e05c8cfe06e9a5, 0x05fe87d0b754ee76]
-  } else {
-    [0xc5e05c8cfe06e9a5, 0x05fe87d0b754ee75]
-  };
-  check!(2, [0x333f35ef131e4d4e, 0x28976a21359307a5], [0x9d5e1082c7650142, 0x4409851f173e99aa], expected, F_20_00);
+  check!(2, [0x333f35ef131e4d4e, 0x28976a21359307a5], [0x9d5e1082c7650142, 0x4409851f173e99aa], [0xc5e05c8cfe06e9a5, 0x05fe87d0b754ee75], F_20_00);
 }
 
 #[test]
 fn _0173() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    [0xcfe4848bd6adddef, 0x6e5040816616a3a0]
-  } else {
-    [0xcfe4848bd6adddef, 0x6e5040816616a39f]
-  };
-  check!(2, [0x33e63513719d9e30, 0x16fb2087e309f4e1], [0x93fec56dcf2fa39b, 0x009abaa41270f373], expected, F_20_00);
+  check!(2, [0x33e63513719d9e30, 0x16fb2087e309f4e1], [0x93fec56dcf2fa39b, 0x009abaa41270f373], [0xcfe4848bd6adddef, 0x6e5040816616a39f], F_20_00);
 }
 
 #[test]
@@ -1037,12 +959,7 @@ fn _0175() {
 
 #[test]
 fn _0176() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    [0xc1125c9b81bd7b51, 0xe01e5f389b29900f]
-  } else {
-    [0xc1125c9b81bd7b51, 0xe01e5f389b29900e]
-  };
-  check!(2, [0x4601b16cefe9effd, 0x31c9d1fb957f827d], [0xb4ece6c0fecdbce3, 0xd82f9af606824e86], expected, F_20_00);
+  check!(2, [0x4601b16cefe9effd, 0x31c9d1fb957f827d], [0xb4ece6c0fecdbce3, 0xd82f9af606824e86], [0xc1125c9b81bd7b51, 0xe01e5f389b29900e], F_20_00);
 }
 
 #[test]
@@ -1057,12 +974,7 @@ fn _0178() {
 
 #[test]
 fn _0179() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    [0xF800000000000000, 0x0000000000000000]
-  } else {
-    [0xdfffed09bead87c0, 0x378d8e63ffffffff]
-  };
-  check!(2, [0x5530164e902e8437, 0xb288c0ee62a18589], [0x8ffac0e4688b1011, 0x8f918b0e12fb95cb], expected, F_28_00);
+  check!(2, [0x5530164e902e8437, 0xb288c0ee62a18589], [0x8ffac0e4688b1011, 0x8f918b0e12fb95cb], [0xdfffed09bead87c0, 0x378d8e63ffffffff], F_28_00);
 }
 
 #[test]
@@ -1077,32 +989,17 @@ fn _0181() {
 
 #[test]
 fn _0182() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    [0x0000000000000000, 0x0000000000000000]
-  } else {
-    [0x0000000000000000, 0x0000000000000001]
-  };
-  check!(2, [0x806032960a142709, 0xd1a9eae619261fe6], [0xd747094621e87bdf, 0x42930ddffd2d2bb6], expected, F_30_00);
+  check!(2, [0x806032960a142709, 0xd1a9eae619261fe6], [0xd747094621e87bdf, 0x42930ddffd2d2bb6], [0x0000000000000000, 0x0000000000000001], F_30_00);
 }
 
 #[test]
 fn _0183() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    [0x8000000000000000, 0x000000005cb31723]
-  } else {
-    [0x8000000000000000, 0x000000005cb31722]
-  };
-  check!(2, [0x8210008002958108, 0x0000000000000000], [0x32394184dc68c8c9, 0xdf7fe7fcf7ffbba7], expected, F_30_00);
+  check!(2, [0x8210008002958108, 0x0000000000000000], [0x32394184dc68c8c9, 0xdf7fe7fcf7ffbba7], [0x8000000000000000, 0x000000005cb31722], F_30_00);
 }
 
 #[test]
 fn _0184() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    [0xa7fcaa09ef582623, 0xbe20e2e1d16ad69c]
-  } else {
-    [0xa7fcaa09ef582623, 0xbe20e2e1d16ad69b]
-  };
-  check!(2, [0x89cf2ff12ec78d42, 0x42674691aeda0f44], [0x11d0582141400402, 0xfffffffffffffe7f], expected, F_20_00);
+  check!(2, [0x89cf2ff12ec78d42, 0x42674691aeda0f44], [0x11d0582141400402, 0xfffffffffffffe7f], [0xa7fcaa09ef582623, 0xbe20e2e1d16ad69b], F_20_00);
 }
 
 #[test]
@@ -1117,12 +1014,7 @@ fn _0186() {
 
 #[test]
 fn _0187() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    [0x90cc87a97793653f, 0x41c5b0843e759c4d]
-  } else {
-    [0x90cc87a97793653f, 0x41c5b0843e759c4c]
-  };
-  check!(2, [0x8ccc407e66eb7261, 0xbf95795f91b67333], [0x2bfcea63f6e985b9, 0x385025a162db63fc], expected, F_20_00);
+  check!(2, [0x8ccc407e66eb7261, 0xbf95795f91b67333], [0x2bfcea63f6e985b9, 0x385025a162db63fc], [0x90cc87a97793653f, 0x41c5b0843e759c4c], F_20_00);
 }
 
 #[test]
@@ -1132,22 +1024,12 @@ fn _0188() {
 
 #[test]
 fn _0189() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    [0x3af23f3e6cf2fe9a, 0x91863d9c1fe83531]
-  } else {
-    [0x3af23f3e6cf2fe9a, 0x91863d9c1fe83532]
-  };
-  check!(2, [0x9346134cb5d53576, 0xd5519b525d5ffa30], [0x88509674eb409001, 0xca9b988529db58b0], expected, F_20_00);
+  check!(2, [0x9346134cb5d53576, 0xd5519b525d5ffa30], [0x88509674eb409001, 0xca9b988529db58b0], [0x3af23f3e6cf2fe9a, 0x91863d9c1fe83532], F_20_00);
 }
 
 #[test]
 fn _0190() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    [0x0000000000000000, 0x0000000000000000]
-  } else {
-    [0x0000000000000000, 0x0000000000000001]
-  };
-  check!(2, [0x9356239fd6f61f54, 0x3c82a573dd68c8f7], [0xd007306e51b05889, 0x7ada8064b6679c41], expected, F_30_00);
+  check!(2, [0x9356239fd6f61f54, 0x3c82a573dd68c8f7], [0xd007306e51b05889, 0x7ada8064b6679c41], [0x0000000000000000, 0x0000000000000001], F_30_00);
 }
 
 #[test]
@@ -1182,32 +1064,17 @@ fn _0196() {
 
 #[test]
 fn _0197() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    [0xF800000000000000, 0x0000000000000000]
-  } else {
-    [0xdfffed09bead87c0, 0x378d8e63ffffffff]
-  };
-  check!(2, [0xd45a2d71f2fc5187, 0x26c22326f2005835], [0x102b679d08833f6d, 0x870cf5e9f920c0d5], expected, F_28_00);
+  check!(2, [0xd45a2d71f2fc5187, 0x26c22326f2005835], [0x102b679d08833f6d, 0x870cf5e9f920c0d5], [0xdfffed09bead87c0, 0x378d8e63ffffffff], F_28_00);
 }
 
 #[test]
 fn _0198() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    [0xF800000000000000, 0x0000000000000000]
-  } else {
-    [0xdfffed09bead87c0, 0x378d8e63ffffffff]
-  };
-  check!(2, [0xdba033e002d88475, 0x7fde7d86b7ed34d2], [0x04673668347f5053, 0xac5aac9ee0505f1b], expected, F_28_00);
+  check!(2, [0xdba033e002d88475, 0x7fde7d86b7ed34d2], [0x04673668347f5053, 0xac5aac9ee0505f1b], [0xdfffed09bead87c0, 0x378d8e63ffffffff], F_28_00);
 }
 
 #[test]
 fn _0199() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    [0x44c447d78c0580aa, 0x85f7db30b7388987]
-  } else {
-    [0x44c447d78c0580aa, 0x85f7db30b7388988]
-  };
-  check!(2, [0xdd4fbf6532b84f73, 0x3c4c5763f08dca6b], [0xc8893309fd8b3667, 0x8a3434e6ff1c8ed9], expected, F_20_00);
+  check!(2, [0xdd4fbf6532b84f73, 0x3c4c5763f08dca6b], [0xc8893309fd8b3667, 0x8a3434e6ff1c8ed9], [0x44c447d78c0580aa, 0x85f7db30b7388988], F_20_00);
 }
 
 #[test]
@@ -1242,12 +1109,7 @@ fn _0205() {
 
 #[test]
 fn _0206() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    [0xaff843c84e0b05df, 0x35d5dd63a34a7f51]
-  } else {
-    [0xaff843c84e0b05df, 0x35d5dd63a34a7f50]
-  };
-  check!(2, 0, 0, "-1.010E0", "+734.657E0", expected, F_20_00, F_00_00, F_00_00);
+  check!(2, 0, 0, "-1.010E0", "+734.657E0", [0xaff843c84e0b05df, 0x35d5dd63a34a7f50], F_20_00, F_00_00, F_00_00);
 }
 
 #[test]
@@ -1262,32 +1124,17 @@ fn _0208() {
 
 #[test]
 fn _0209() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest") && !cfg!(feature = "ieee-round-nearest-ties-away") {
-    [0x0000000000000000, 0x0000000000000000]
-  } else {
-    [0x0000000000000000, 0x0000000000000001]
-  };
-  check!(2, 0, 0, "1E-6176", "2", expected, F_30_00, F_00_00, F_00_00);
+  check!(2, 0, 0, "1E-6176", "2", [0x0000000000000000, 0x0000000000000001], F_30_00, F_00_00, F_00_00);
 }
 
 #[test]
 fn _0210() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest-ties-away") {
-    [0x8000000000000000, 0x0000000000000001]
-  } else {
-    [0x8000000000000000, 0x0000000000000000]
-  };
-  check!(2, 0, 0, "1E-6176", "-2", expected, F_30_00, F_00_00, F_00_00);
+  check!(2, 0, 0, "1E-6176", "-2", [0x8000000000000000, 0x0000000000000000], F_30_00, F_00_00, F_00_00);
 }
 
 #[test]
 fn _0211() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    [0x0000000000000000, 0x0000000000000000]
-  } else {
-    [0x0000000000000000, 0x0000000000000001]
-  };
-  check!(2, 0, 0, "1E-6176", "4294967296", expected, F_30_00, F_00_00, F_00_00);
+  check!(2, 0, 0, "1E-6176", "4294967296", [0x0000000000000000, 0x0000000000000001], F_30_00, F_00_00, F_00_00);
 }
 
 #[test]
@@ -1297,22 +1144,12 @@ fn _0212() {
 
 #[test]
 fn _0213() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    [0xb001ec63b7d685d4, 0xe49c39a1b82c7906]
-  } else {
-    [0xb001ec63b7d685d4, 0xe49c39a1b82c7905]
-  };
-  check!(2, 0, 0, "+98858.678996557986769E0", "-989.888889E0", expected, F_20_00, F_00_00, F_00_00);
+  check!(2, 0, 0, "+98858.678996557986769E0", "-989.888889E0", [0xb001ec63b7d685d4, 0xe49c39a1b82c7905], F_20_00, F_00_00, F_00_00);
 }
 
 #[test]
 fn _0214() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    [0xaff857f6c8fae298, 0xe46f125bb81b162d]
-  } else {
-    [0xaff857f6c8fae298, 0xe46f125bb81b162c]
-  };
-  check!(2, 0, 0, "+99.8998888898E0", "-55993.8675252E0", expected, F_20_00, F_00_00, F_00_00);
+  check!(2, 0, 0, "+99.8998888898E0", "-55993.8675252E0", [0xaff857f6c8fae298, 0xe46f125bb81b162c], F_20_00, F_00_00, F_00_00);
 }
 
 #[test]
@@ -1387,22 +1224,12 @@ fn _0228() {
 
 #[test]
 fn _0229() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    [0xc5e05c8cfe06e9a5, 0x05fe87d0b754ee76]
-  } else {
-    [0xc5e05c8cfe06e9a5, 0x05fe87d0b754ee75]
-  };
-  check!(3, [0x333f35ef131e4d4e, 0x28976a21359307a5], [0x9d5e1082c7650142, 0x4409851f173e99aa], expected, F_20_00);
+  check!(3, [0x333f35ef131e4d4e, 0x28976a21359307a5], [0x9d5e1082c7650142, 0x4409851f173e99aa], [0xc5e05c8cfe06e9a5, 0x05fe87d0b754ee75], F_20_00);
 }
 
 #[test]
 fn _0230() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    [0xcfe4848bd6adddef, 0x6e5040816616a3a0]
-  } else {
-    [0xcfe4848bd6adddef, 0x6e5040816616a39f]
-  };
-  check!(3, [0x33e63513719d9e30, 0x16fb2087e309f4e1], [0x93fec56dcf2fa39b, 0x009abaa41270f373], expected, F_20_00);
+  check!(3, [0x33e63513719d9e30, 0x16fb2087e309f4e1], [0x93fec56dcf2fa39b, 0x009abaa41270f373], [0xcfe4848bd6adddef, 0x6e5040816616a39f], F_20_00);
 }
 
 #[test]
@@ -1417,42 +1244,22 @@ fn _0232() {
 
 #[test]
 fn _0233() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    [0xc1125c9b81bd7b51, 0xe01e5f389b29900f]
-  } else {
-    [0xc1125c9b81bd7b51, 0xe01e5f389b29900e]
-  };
-  check!(3, [0x4601b16cefe9effd, 0x31c9d1fb957f827d], [0xb4ece6c0fecdbce3, 0xd82f9af606824e86], expected, F_20_00);
+  check!(3, [0x4601b16cefe9effd, 0x31c9d1fb957f827d], [0xb4ece6c0fecdbce3, 0xd82f9af606824e86], [0xc1125c9b81bd7b51, 0xe01e5f389b29900e], F_20_00);
 }
 
 #[test]
 fn _0234() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    [0x7800000000000000, 0x0000000000000000]
-  } else {
-    [0x5fffed09bead87c0, 0x378d8e63ffffffff]
-  };
-  check!(3, [0x4f0b131761a5ebfe, 0xe17445ef98b870b9], [0x1c7fb95118314088, 0x0316f0c8237c5127], expected, F_28_00);
+  check!(3, [0x4f0b131761a5ebfe, 0xe17445ef98b870b9], [0x1c7fb95118314088, 0x0316f0c8237c5127], [0x5fffed09bead87c0, 0x378d8e63ffffffff], F_28_00);
 }
 
 #[test]
 fn _0235() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    [0x7800000000000000, 0x0000000000000000]
-  } else {
-    [0x5fffed09bead87c0, 0x378d8e63ffffffff]
-  };
-  check!(3, [0x52b36974100f4928, 0x0f1bae6a614be06f], [0x1e304bbb94d4425c, 0x1b1a50112a2e1cb1], expected, F_28_00);
+  check!(3, [0x52b36974100f4928, 0x0f1bae6a614be06f], [0x1e304bbb94d4425c, 0x1b1a50112a2e1cb1], [0x5fffed09bead87c0, 0x378d8e63ffffffff], F_28_00);
 }
 
 #[test]
 fn _0236() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    [0xF800000000000000, 0x0000000000000000]
-  } else {
-    [0xdfffed09bead87c0, 0x378d8e63ffffffff]
-  };
-  check!(3, [0x5530164e902e8437, 0xb288c0ee62a18589], [0x8ffac0e4688b1011, 0x8f918b0e12fb95cb], expected, F_28_00);
+  check!(3, [0x5530164e902e8437, 0xb288c0ee62a18589], [0x8ffac0e4688b1011, 0x8f918b0e12fb95cb], [0xdfffed09bead87c0, 0x378d8e63ffffffff], F_28_00);
 }
 
 #[test]
@@ -1462,12 +1269,7 @@ fn _0237() {
 
 #[test]
 fn _0238() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    [0x7800000000000000, 0x0000000000000000]
-  } else {
-    [0x5fffed09bead87c0, 0x378d8e63ffffffff]
-  };
-  check!(3, [0x5f8cd8f1e56c6b3d, 0x541da77d81887813], [0x1c99d16b5a33a985, 0x2809b8660d9d69f0], expected, F_28_00);
+  check!(3, [0x5f8cd8f1e56c6b3d, 0x541da77d81887813], [0x1c99d16b5a33a985, 0x2809b8660d9d69f0], [0x5fffed09bead87c0, 0x378d8e63ffffffff], F_28_00);
 }
 
 #[test]
@@ -1487,12 +1289,7 @@ fn _0241() {
 
 #[test]
 fn _0242() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    [0x90cc87a97793653f, 0x41c5b0843e759c4d]
-  } else {
-    [0x90cc87a97793653f, 0x41c5b0843e759c4c]
-  };
-  check!(3, [0x8ccc407e66eb7261, 0xbf95795f91b67333], [0x2bfcea63f6e985b9, 0x385025a162db63fc], expected, F_20_00);
+  check!(3, [0x8ccc407e66eb7261, 0xbf95795f91b67333], [0x2bfcea63f6e985b9, 0x385025a162db63fc], [0x90cc87a97793653f, 0x41c5b0843e759c4c], F_20_00);
 }
 
 #[test]
@@ -1517,12 +1314,7 @@ fn _0246() {
 
 #[test]
 fn _0247() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    [0x2220788822fcbd2b, 0xc4d2d1bab2fdc4f7]
-  } else {
-    [0x2220788822fcbd2b, 0xc4d2d1bab2fdc4f6]
-  };
-  check!(3, [0x9b5b494c1b0b648a, 0x37a1bb32e5a95fa9], [0xa93a0d7850c02072, 0xd381713d3707026a], expected, F_20_00);
+  check!(3, [0x9b5b494c1b0b648a, 0x37a1bb32e5a95fa9], [0xa93a0d7850c02072, 0xd381713d3707026a], [0x2220788822fcbd2b, 0xc4d2d1bab2fdc4f6], F_20_00);
 }
 
 #[test]
@@ -1532,12 +1324,7 @@ fn _0248() {
 
 #[test]
 fn _0249() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    [0x7800000000000000, 0x0000000000000000]
-  } else {
-    [0x5fffed09bead87c0, 0x378d8e63ffffffff]
-  };
-  check!(3, [0xca315abb5eb7928a, 0xf36c452c38ae05b0], [0x926e4674bba1c31c, 0xd8256ba008e56abd], expected, F_28_00);
+  check!(3, [0xca315abb5eb7928a, 0xf36c452c38ae05b0], [0x926e4674bba1c31c, 0xd8256ba008e56abd], [0x5fffed09bead87c0, 0x378d8e63ffffffff], F_28_00);
 }
 
 #[test]
@@ -1547,32 +1334,17 @@ fn _0250() {
 
 #[test]
 fn _0251() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    [0x7800000000000000, 0x0000000000000000]
-  } else {
-    [0x5fffed09bead87c0, 0x378d8e63ffffffff]
-  };
-  check!(3, [0xcf9fe06cec065c37, 0x1baf1e0586ff927d], [0x80c9b1ce835e3cb1, 0x9b9600b33d3c5041], expected, F_28_00);
+  check!(3, [0xcf9fe06cec065c37, 0x1baf1e0586ff927d], [0x80c9b1ce835e3cb1, 0x9b9600b33d3c5041], [0x5fffed09bead87c0, 0x378d8e63ffffffff], F_28_00);
 }
 
 #[test]
 fn _0252() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    [0xF800000000000000, 0x0000000000000000]
-  } else {
-    [0xdfffed09bead87c0, 0x378d8e63ffffffff]
-  };
-  check!(3, [0xd45a2d71f2fc5187, 0x26c22326f2005835], [0x102b679d08833f6d, 0x870cf5e9f920c0d5], expected, F_28_00);
+  check!(3, [0xd45a2d71f2fc5187, 0x26c22326f2005835], [0x102b679d08833f6d, 0x870cf5e9f920c0d5], [0xdfffed09bead87c0, 0x378d8e63ffffffff], F_28_00);
 }
 
 #[test]
 fn _0253() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    [0xF800000000000000, 0x0000000000000000]
-  } else {
-    [0xdfffed09bead87c0, 0x378d8e63ffffffff]
-  };
-  check!(3, [0xdba033e002d88475, 0x7fde7d86b7ed34d2], [0x04673668347f5053, 0xac5aac9ee0505f1b], expected, F_28_00);
+  check!(3, [0xdba033e002d88475, 0x7fde7d86b7ed34d2], [0x04673668347f5053, 0xac5aac9ee0505f1b], [0xdfffed09bead87c0, 0x378d8e63ffffffff], F_28_00);
 }
 
 #[test]
@@ -1612,12 +1384,7 @@ fn _0260() {
 
 #[test]
 fn _0261() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    [0xaff843c84e0b05df, 0x35d5dd63a34a7f51]
-  } else {
-    [0xaff843c84e0b05df, 0x35d5dd63a34a7f50]
-  };
-  check!(3, 0, 0, "-1.010E0", "+734.657E0", expected, F_20_00, F_00_00, F_00_00);
+  check!(3, 0, 0, "-1.010E0", "+734.657E0", [0xaff843c84e0b05df, 0x35d5dd63a34a7f50], F_20_00, F_00_00, F_00_00);
 }
 
 #[test]
@@ -1637,22 +1404,12 @@ fn _0264() {
 
 #[test]
 fn _0265() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    [0xb001ec63b7d685d4, 0xe49c39a1b82c7906]
-  } else {
-    [0xb001ec63b7d685d4, 0xe49c39a1b82c7905]
-  };
-  check!(3, 0, 0, "+98858.678996557986769E0", "-989.888889E0", expected, F_20_00, F_00_00, F_00_00);
+  check!(3, 0, 0, "+98858.678996557986769E0", "-989.888889E0", [0xb001ec63b7d685d4, 0xe49c39a1b82c7905], F_20_00, F_00_00, F_00_00);
 }
 
 #[test]
 fn _0266() {
-  let expected: BidArray = if cfg!(feature = "ieee-round-nearest-ties-away") || cfg!(feature = "ieee-round-nearest") {
-    [0xaff857f6c8fae298, 0xe46f125bb81b162d]
-  } else {
-    [0xaff857f6c8fae298, 0xe46f125bb81b162c]
-  };
-  check!(3, 0, 0, "+99.8998888898E0", "-55993.8675252E0", expected, F_20_00, F_00_00, F_00_00);
+  check!(3, 0, 0, "+99.8998888898E0", "-55993.8675252E0", [0xaff857f6c8fae298, 0xe46f125bb81b162c], F_20_00, F_00_00, F_00_00);
 }
 
 #[test]
@@ -1954,3 +1711,28 @@ fn _0325() {
 fn _0326() {
   check!(4, 0, 0, "QNaN", "QNaN", [0x7c00000000000000, 0x0000000000000000], F_00_00, F_00_00, F_00_00);
 }
+
+#[test]
+fn _0327() {
+  check!(4, 0, 0, "1E-6176", "2", [0x0000000000000000, 0x0000000000000001], F_30_00, F_00_00, F_00_00);
+}
+
+#[test]
+fn _0328() {
+  check!(4, 0, 0, "1E-6176", "-2", [0x8000000000000000, 0x0000000000000001], F_30_00, F_00_00, F_00_00);
+}
+
+#[test]
+fn _0329() {
+  check!(4, 0, 0, "2", "3", [0x2ffd48b129c9052a, 0xcfb3b442aaaaaaab], F_20_00, F_00_00, F_00_00);
+}
+
+#[test]
+fn _0330() {
+  check!(4, 0, 0, "5E-6176", "10", [0x0000000000000000, 0x0000000000000001], F_30_00, F_00_00, F_00_00);
+}
+
+#[test]
+fn _0331() {
+  check!(0, 0, 0, "5E-6176", "10", [0x0000000000000000, 0x0000000000000000], F_30_00, F_00_00, F_00_00);
+}
@@ -0,0 +1,114 @@
+use super::*;
+use decimus::{BidUint128, IdecFlags, bid128_from_bytes};
+
+macro_rules! check {
+  ($rnd_mode:expr, $x:expr, $expected:expr, $expected_consumed:expr, $expected_flags:expr) => {
+    let mut actual_flags: IdecFlags = 0;
+    let expected = BidUint128 { w: [$expected[1], $expected[0]] };
+    let (actual, actual_consumed) = bid128_from_bytes($x, $rnd_mode, &mut actual_flags);
+    assert_eq!(expected, actual);
+    assert_eq!($expected_consumed, actual_consumed, "Consumed bytes error");
+    assert_eq!($expected_flags, actual_flags, "Flags error, expected = {:02X}, actual = {:02X}", $expected_flags, actual_flags);
+  };
+}
+
+#[test]
+fn _0001() {
+  check!(0, b"", [0x7c00000000000000, 0x0000000000000000], 0, F_00_00);
+}
+
+#[test]
+fn _0002() {
+  check!(0, b"abc", [0x7c00000000000000, 0x0000000000000000], 0, F_00_00);
+}
+
+#[test]
+fn _0003() {
+  check!(0, b"123", [0x3040000000000000, 0x000000000000007b], 3, F_00_00);
+}
+
+#[test]
+fn _0004() {
+  check!(0, b"  -12.3;", [0xb03e000000000000, 0x000000000000007b], 7, F_00_00);
+}
+
+#[test]
+fn _0005() {
+  check!(0, b"1.23E+2,EUR", [0x3040000000000000, 0x000000000000007b], 7, F_00_00);
+}
+
+#[test]
+fn _0006() {
+  // exponent without digits is not consumed
+  check!(0, b"123e", [0x3040000000000000, 0x000000000000007b], 3, F_00_00);
+}
+
+#[test]
+fn _0007() {
+  check!(0, b"123E+x", [0x3040000000000000, 0x000000000000007b], 3, F_00_00);
+}
+
+#[test]
+fn _0008() {
+  check!(0, b"1.2.3", [0x303e000000000000, 0x000000000000000c], 3, F_00_00);
+}
+
+#[test]
+fn _0009() {
+  check!(0, b".5|", [0x303e000000000000, 0x0000000000000005], 2, F_00_00);
+}
+
+#[test]
+fn _0010() {
+  check!(0, b".", [0x7c00000000000000, 0x0000000000000000], 0, F_00_00);
+}
+
+#[test]
+fn _0011() {
+  check!(0, b"-.e1", [0x7c00000000000000, 0x0000000000000000], 0, F_00_00);
+}
+
+#[test]
+fn _0012() {
+  check!(0, b"0.000\x00", [0x303a000000000000, 0x0000000000000000], 5, F_00_00);
+}
+
+#[test]
+fn _0013() {
+  check!(0, b"-Infinity!", [0xf800000000000000, 0x0000000000000000], 9, F_00_00);
+}
+
+#[test]
+fn _0014() {
+  check!(0, b"+infinite", [0x7800000000000000, 0x0000000000000000], 4, F_00_00);
+}
+
+#[test]
+fn _0015() {
+  check!(0, b"NaN,", [0x7c00000000000000, 0x0000000000000000], 3, F_00_00);
+}
+
+#[test]
+fn _0016() {
+  check!(0, b" sNaN ", [0x7e00000000000000, 0x0000000000000000], 5, F_00_00);
+}
+
+#[test]
+fn _0017() {
+  check!(0, b"-snan", [0xfe00000000000000, 0x0000000000000000], 5, F_00_00);
+}
+
+#[test]
+fn _0018() {
+  check!(0, b"12345678901234567890123456789012345 ", [0x30423cde6fff9732, 0xde825cd07e96aff2], 35, F_20_00);
+}
+
+#[test]
+fn _0019() {
+  check!(0, b"NaN42;x", [0x7c00000000000000, 0x000000000000002a], 5, F_00_00);
+}
+
+#[test]
+fn _0020() {
+  check!(0, b"-sNaN7 ", [0xfe00000000000000, 0x0000000000000007], 6, F_00_00);
+}
@@ -0,0 +1,70 @@
+use super::*;
+use decimus::{BidUint128, IdecFlags, bid128_from_decimal128_array};
+
+macro_rules! check {
+  ($rnd_mode:expr, $x:expr, $scale:expr, $expected:expr, $expected_flags:expr) => {
+    let mut actual_flags: IdecFlags = 0;
+    let mut output = [BidUint128::default()];
+    bid128_from_decimal128_array(&[$x], $scale, $rnd_mode, &mut output, &mut actual_flags);
+    assert_eq!(BidUint128 { w: [$expected[1], $expected[0]] }, output[0]);
+    assert_eq!($expected_flags, actual_flags, "Result flags error, expected = 0x{:02X}, actual = 0x{:02X}", $expected_flags, actual_flags);
+  };
+}
+
+#[test]
+fn _0001() {
+  check!(0, 12345, 4, [0x3038000000000000, 0x0000000000003039], F_00_00);
+}
+
+#[test]
+fn _0002() {
+  check!(0, -12345, 4, [0xb038000000000000, 0x0000000000003039], F_00_00);
+}
+
+#[test]
+fn _0003() {
+  check!(0, 12345, -40, [0x3090000000000000, 0x0000000000003039], F_00_00);
+}
+
+#[test]
+fn _0004() {
+  check!(0, 0, 2, [0x303c000000000000, 0x0000000000000000], F_00_00);
+}
+
+#[test]
+fn _0005() {
+  check!(0, i128::MAX, 0, [0x304a53e2d6238da3, 0xc21187e7c06e19b9], F_20_00);
+}
+
+#[test]
+fn _0006() {
+  check!(2, i128::MAX, 0, [0x304a53e2d6238da3, 0xc21187e7c06e19ba], F_20_00);
+}
+
+#[test]
+fn _0007() {
+  check!(0, i128::MIN, 0, [0xb04a53e2d6238da3, 0xc21187e7c06e19b9], F_20_00);
+}
+
+#[test]
+fn _0008() {
+  let mut flags: IdecFlags = 0;
+  let mut output = [BidUint128::default(); 3];
+  bid128_from_decimal128_array(&[1, -1, 100], 2, 0, &mut output, &mut flags);
+  assert_eq!(
+    [
+      BidUint128 { w: [0x0000000000000001, 0x303c000000000000] },
+      BidUint128 { w: [0x0000000000000001, 0xb03c000000000000] },
+      BidUint128 { w: [0x0000000000000064, 0x303c000000000000] }
+    ],
+    output
+  );
+  assert_eq!(F_00_00, flags);
+}
+
+#[test]
+#[should_panic(expected = "output buffer is too small")]
+fn _0009() {
+  let mut flags: IdecFlags = 0;
+  bid128_from_decimal128_array(&[1, 2], 0, 0, &mut [BidUint128::default()], &mut flags);
+}
@@ -0,0 +1,78 @@
+use super::*;
+use decimus::{BidUint128, IdecFlags, bid128_from_decimal256_array};
+
+/// Returns 256-bit integer as 32 bytes in little-endian order.
+fn i256(hi: u128, lo: u128) -> [u8; 32] {
+  let mut bytes = [0; 32];
+  bytes[..16].copy_from_slice(&lo.to_le_bytes());
+  bytes[16..].copy_from_slice(&hi.to_le_bytes());
+  bytes
+}
+
+macro_rules! check {
+  ($rnd_mode:expr, $x:expr, $scale:expr, $expected:expr, $expected_flags:expr) => {
+    let mut actual_flags: IdecFlags = 0;
+    let mut output = [BidUint128::default()];
+    bid128_from_decimal256_array(&[$x], $scale, $rnd_mode, &mut output, &mut actual_flags);
+    assert_eq!(BidUint128 { w: [$expected[1], $expected[0]] }, output[0]);
+    assert_eq!($expected_flags, actual_flags, "Result flags error, expected = 0x{:02X}, actual = 0x{:02X}", $expected_flags, actual_flags);
+  };
+}
+
+#[test]
+fn _0001() {
+  check!(0, i256(0, 12345), 4, [0x3038000000000000, 0x0000000000003039], F_00_00);
+}
+
+#[test]
+fn _0002() {
+  check!(0, i256(0xffffffffffffffffffffffffffffffff, 0xffffffffffffffffffffffffffffcfc7), 4, [0xb038000000000000, 0x0000000000003039], F_00_00);
+}
+
+#[test]
+fn _0003() {
+  check!(0, i256(0x7fffffffffffffffffffffffffffffff, 0xffffffffffffffffffffffffffffffff), 0, [0x30971d7314f534b6, 0x09c6efe6c11d255b], F_20_00);
+}
+
+#[test]
+fn _0004() {
+  check!(0, i256(0x80000000000000000000000000000000, 0), 0, [0xb0971d7314f534b6, 0x09c6efe6c11d255b], F_20_00);
+}
+
+#[test]
+fn _0005() {
+  check!(0, i256(0x161bcca7119915b50764b4abe8652979, 0x7775a5f171950fffffffffffffffffff), 0, [0x3096314dc6448d93, 0x38c15b0a00000000], F_20_00);
+}
+
+#[test]
+fn _0006() {
+  check!(3, i256(0x161bcca7119915b50764b4abe8652979, 0x7775a5f171950fffffffffffffffffff), 0, [0x3095ed09bead87c0, 0x378d8e63ffffffff], F_20_00);
+}
+
+#[test]
+fn _0007() {
+  check!(0, i256(0x8727f6369, 0xaaf83ca15026747af8c7f196ce3f0ad2), 10, [0x304c3cde6fff9732, 0xde825cd07e96aff3], F_20_00);
+}
+
+#[test]
+fn _0008() {
+  check!(0, i256(0x58922, 0xf1ab75de849ee01a69d2990000000000), 0, [0x30563cdd94a2325b, 0xc2dd4a1da0000000], F_00_00);
+}
+
+#[test]
+fn _0009() {
+  check!(0, i256(0xaf298d05, 0xe4395d69670b12b7f42c6bf52634001), 0, [0x305e314dc6448d93, 0x38c15b0a00000001], F_20_00);
+}
+
+#[test]
+#[should_panic(expected = "output buffer is too small")]
+fn _0010() {
+  let mut flags: IdecFlags = 0;
+  bid128_from_decimal256_array(&[[0; 32]; 2], 0, 0, &mut [BidUint128::default()], &mut flags);
+}
+
+#[test]
+fn _0011() {
+  // (2^128 - 1) * 10 + 1, after removing the last digit the coefficient is u128::MAX with sticky digit.
+  check!(0, i256(9, 0xfffffffffffffffffffffffffffffff7), 0, [0x304ca7c5ac471b47, 0x84230fcf80dc3372], F_20_00);
+}
@@ -0,0 +1,66 @@
+use super::*;
+use decimus::{BidUint128, IdecFlags, bid128_from_i128};
+
+macro_rules! check {
+  ($rnd_mode:expr, $x:expr, $expected:expr, $expected_flags:expr) => {
+    let mut actual_flags: IdecFlags = 0;
+    let expected = BidUint128 { w: [$expected[1], $expected[0]] };
+    assert_eq!(expected, bid128_from_i128($x, $rnd_mode, &mut actual_flags));
+    assert_eq!($expected_flags, actual_flags, "Result flags error, expected = 0x{:02X}, actual = 0x{:02X}", $expected_flags, actual_flags);
+  };
+}
+
+#[test]
+fn _0001() {
+  check!(0, 0, [0x3040000000000000, 0x0000000000000000], F_00_00);
+}
+
+#[test]
+fn _0002() {
+  check!(0, 1, [0x3040000000000000, 0x0000000000000001], F_00_00);
+}
+
+#[test]
+fn _0003() {
+  check!(0, -1, [0xb040000000000000, 0x0000000000000001], F_00_00);
+}
+
+#[test]
+fn _0004() {
+  check!(0, 9999999999999999999999999999999999, [0x3041ed09bead87c0, 0x378d8e63ffffffff], F_00_00);
+}
+
+#[test]
+fn _0005() {
+  check!(0, -10000000000000000000000000000000000, [0xb042314dc6448d93, 0x38c15b0a00000000], F_00_00);
+}
+
+#[test]
+fn _0006() {
+  check!(0, i128::MAX, [0x304a53e2d6238da3, 0xc21187e7c06e19b9], F_20_00);
+}
+
+#[test]
+fn _0007() {
+  check!(0, i128::MIN, [0xb04a53e2d6238da3, 0xc21187e7c06e19b9], F_20_00);
+}
+
+#[test]
+fn _0008() {
+  check!(3, i128::MAX, [0x304a53e2d6238da3, 0xc21187e7c06e19b9], F_20_00);
+}
+
+#[test]
+fn _0009() {
+  check!(2, i128::MAX, [0x304a53e2d6238da3, 0xc21187e7c06e19ba], F_20_00);
+}
+
+#[test]
+fn _0010() {
+  check!(1, i128::MIN, [0xb04a53e2d6238da3, 0xc21187e7c06e19ba], F_20_00);
+}
+
+#[test]
+fn _0011() {
+  check!(4, 12345678901234567890123456789012345, [0x30423cde6fff9732, 0xde825cd07e96aff3], F_20_00);
+}
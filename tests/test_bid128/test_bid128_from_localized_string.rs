@@ -0,0 +1,150 @@
+use decimus::{BidUint128, NumberFormat, ParseDecimalErrorKind, bid128_from_localized_string};
+
+macro_rules! check {
+  ($x:expr, $format:expr, $expected:expr) => {
+    let mut actual_flags = 0;
+    let expected = BidUint128 { w: [$expected[1], $expected[0]] };
+    assert_eq!(Ok(expected), bid128_from_localized_string($x, &$format, 0, &mut actual_flags));
+  };
+  ($x:expr, $format:expr, $expected_kind:expr, $expected_position:expr) => {
+    let mut actual_flags = 0;
+    let err = bid128_from_localized_string($x, &$format, 0, &mut actual_flags).unwrap_err();
+    assert_eq!($expected_kind, err.kind());
+    assert_eq!($expected_position, err.position());
+  };
+}
+
+#[test]
+fn _0001() {
+  check!("1.234.567,89", NumberFormat::new(',', Some('.')), [0x303c000000000000, 0x00000000075bcd15]);
+}
+
+#[test]
+fn _0002() {
+  check!("1234567,89", NumberFormat::new(',', Some('.')), [0x303c000000000000, 0x00000000075bcd15]);
+}
+
+#[test]
+fn _0003() {
+  check!(",5", NumberFormat::new(',', Some('.')), [0x303e000000000000, 0x0000000000000005]);
+}
+
+#[test]
+fn _0004() {
+  check!("1.234,", NumberFormat::new(',', Some('.')), [0x3040000000000000, 0x00000000000004d2]);
+}
+
+#[test]
+fn _0005() {
+  check!("-0,000", NumberFormat::new(',', Some('.')), [0xb03a000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0006() {
+  check!("1'234'567.89", NumberFormat::new('.', Some('\'')), [0x303c000000000000, 0x00000000075bcd15]);
+}
+
+#[test]
+fn _0007() {
+  check!("1\u{2019}234", NumberFormat::new('.', Some('\u{2019}')), [0x3040000000000000, 0x00000000000004d2]);
+}
+
+#[test]
+fn _0008() {
+  check!("12 345,6", NumberFormat::new(',', Some(' ')), [0x303e000000000000, 0x000000000001e240]);
+}
+
+#[test]
+fn _0009() {
+  check!("-inf", NumberFormat::new(',', Some('.')), [0xf800000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0010() {
+  check!("NaN", NumberFormat::new(',', Some('.')), [0x7c00000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0011() {
+  check!("1.234.567.890.123.456.789.012.345.678.901.234,5", NumberFormat::new(',', Some('.')), [0x30403cde6fff9732, 0xde825cd07e96aff2]);
+}
+
+#[test]
+fn _0012() {
+  check!("1.234.567.890.123.456.789.012.345.678.901.235,5", NumberFormat::new(',', Some('.')), [0x30403cde6fff9732, 0xde825cd07e96aff4]);
+}
+
+#[test]
+fn _0013() {
+  check!("0,000000000000000000000000000000000000000000000000001", NumberFormat::new(',', Some('.')), [0x2fda000000000000, 0x0000000000000001]);
+}
+
+#[test]
+fn _0014() {
+  check!("", NumberFormat::new(',', Some('.')), ParseDecimalErrorKind::Empty, 0);
+}
+
+#[test]
+fn _0015() {
+  check!("1.23.567", NumberFormat::new(',', Some('.')), ParseDecimalErrorKind::InvalidGrouping, 4);
+}
+
+#[test]
+fn _0016() {
+  check!("1.2345", NumberFormat::new(',', Some('.')), ParseDecimalErrorKind::InvalidGrouping, 6);
+}
+
+#[test]
+fn _0017() {
+  check!("1234.567", NumberFormat::new(',', Some('.')), ParseDecimalErrorKind::InvalidGrouping, 4);
+}
+
+#[test]
+fn _0018() {
+  check!("1..234", NumberFormat::new(',', Some('.')), ParseDecimalErrorKind::InvalidGrouping, 2);
+}
+
+#[test]
+fn _0019() {
+  check!(".234", NumberFormat::new(',', Some('.')), ParseDecimalErrorKind::InvalidGrouping, 0);
+}
+
+#[test]
+fn _0020() {
+  check!("1,2,3", NumberFormat::new(',', Some('.')), ParseDecimalErrorKind::TrailingCharacters, 3);
+}
+
+#[test]
+fn _0021() {
+  check!("+", NumberFormat::new(',', Some('.')), ParseDecimalErrorKind::InvalidDigit, 1);
+}
+
+#[test]
+fn _0022() {
+  check!("1.234.567,89", NumberFormat::new('.', Some('\'')), ParseDecimalErrorKind::TrailingCharacters, 5);
+}
+
+#[test]
+fn _0023() {
+  check!("infinite", NumberFormat::new(',', Some('.')), ParseDecimalErrorKind::TrailingCharacters, 3);
+}
+
+#[test]
+fn _0024() {
+  check!("1\u{2019}23", NumberFormat::new('.', Some('\u{2019}')), ParseDecimalErrorKind::InvalidGrouping, 6);
+}
+
+#[test]
+fn _0025() {
+  check!("12 345", NumberFormat::PLAIN, ParseDecimalErrorKind::TrailingCharacters, 2);
+}
+
+#[test]
+fn _0026() {
+  check!("NaN123", NumberFormat::PLAIN, [0x7c00000000000000, 0x000000000000007b]);
+}
+
+#[test]
+fn _0027() {
+  check!("NaN1000000000000000000000000000000000", NumberFormat::PLAIN, ParseDecimalErrorKind::PayloadOverflow, 3);
+}
@@ -0,0 +1,211 @@
+use super::*;
+use decimus::{BidUint128, IdecFlags, bid128_from_parts};
+
+macro_rules! check {
+  ($rnd_mode:expr, $sign:expr, $coefficient:expr, $exponent:expr, $expected:expr, $expected_flags:expr) => {
+    let mut actual_flags: IdecFlags = 0;
+    let expected = BidUint128 { w: [$expected[1], $expected[0]] };
+    assert_eq!(expected, bid128_from_parts($sign, $coefficient, $exponent, $rnd_mode, &mut actual_flags));
+    assert_eq!($expected_flags, actual_flags, "Result flags error, expected = 0x{:02X}, actual = 0x{:02X}", $expected_flags, actual_flags);
+  };
+}
+
+#[test]
+fn _0001() {
+  check!(0, false, 0, 0, [0x3040000000000000, 0x0000000000000000], F_00_00);
+}
+
+#[test]
+fn _0002() {
+  check!(0, false, 123456789012, -10, [0x302c000000000000, 0x0000001cbe991a14], F_00_00);
+}
+
+#[test]
+fn _0003() {
+  check!(0, true, 1, 0, [0xb040000000000000, 0x0000000000000001], F_00_00);
+}
+
+#[test]
+fn _0004() {
+  check!(0, false, 9999999999999999999999999999999999, 0, [0x3041ed09bead87c0, 0x378d8e63ffffffff], F_00_00);
+}
+
+#[test]
+fn _0005() {
+  check!(0, false, 10000000000000000000000000000000000, 0, [0x3042314dc6448d93, 0x38c15b0a00000000], F_00_00);
+}
+
+#[test]
+fn _0006() {
+  check!(0, false, 100000000000000000000000000000000000000, -2, [0x3046314dc6448d93, 0x38c15b0a00000000], F_00_00);
+}
+
+#[test]
+fn _0007() {
+  check!(0, false, u128::MAX, 0, [0x304aa7c5ac471b47, 0x84230fcf80dc3372], F_20_00);
+}
+
+#[test]
+fn _0008() {
+  check!(0, true, u128::MAX, 0, [0xb04aa7c5ac471b47, 0x84230fcf80dc3372], F_20_00);
+}
+
+#[test]
+fn _0009() {
+  check!(0, false, 12345678901234567890123456789012345, 0, [0x30423cde6fff9732, 0xde825cd07e96aff2], F_20_00);
+}
+
+#[test]
+fn _0010() {
+  check!(4, false, 12345678901234567890123456789012345, 0, [0x30423cde6fff9732, 0xde825cd07e96aff3], F_20_00);
+}
+
+#[test]
+fn _0011() {
+  check!(0, false, 12345678901234567890123456789012355, 0, [0x30423cde6fff9732, 0xde825cd07e96aff4], F_20_00);
+}
+
+#[test]
+fn _0012() {
+  check!(4, true, 12345678901234567890123456789012355, 0, [0xb0423cde6fff9732, 0xde825cd07e96aff4], F_20_00);
+}
+
+#[test]
+fn _0013() {
+  check!(1, false, 12345678901234567890123456789012341, 0, [0x30423cde6fff9732, 0xde825cd07e96aff2], F_20_00);
+}
+
+#[test]
+fn _0014() {
+  check!(1, true, 12345678901234567890123456789012341, 0, [0xb0423cde6fff9732, 0xde825cd07e96aff3], F_20_00);
+}
+
+#[test]
+fn _0015() {
+  check!(2, false, 12345678901234567890123456789012341, 0, [0x30423cde6fff9732, 0xde825cd07e96aff3], F_20_00);
+}
+
+#[test]
+fn _0016() {
+  check!(2, true, 12345678901234567890123456789012341, 0, [0xb0423cde6fff9732, 0xde825cd07e96aff2], F_20_00);
+}
+
+#[test]
+fn _0017() {
+  check!(3, false, 12345678901234567890123456789012349, 0, [0x30423cde6fff9732, 0xde825cd07e96aff2], F_20_00);
+}
+
+#[test]
+fn _0018() {
+  check!(3, true, 12345678901234567890123456789012349, 0, [0xb0423cde6fff9732, 0xde825cd07e96aff2], F_20_00);
+}
+
+#[test]
+fn _0019() {
+  check!(0, false, 99999999999999999999999999999999995, 0, [0x3044314dc6448d93, 0x38c15b0a00000000], F_20_00);
+}
+
+#[test]
+fn _0020() {
+  check!(0, false, 1, -6176, [0x0000000000000000, 0x0000000000000001], F_00_00);
+}
+
+#[test]
+fn _0021() {
+  check!(0, false, 123, -6178, [0x0000000000000000, 0x0000000000000001], F_30_00);
+}
+
+#[test]
+fn _0022() {
+  check!(0, false, 150, -6178, [0x0000000000000000, 0x0000000000000002], F_30_00);
+}
+
+#[test]
+fn _0023() {
+  check!(0, false, 250, -6178, [0x0000000000000000, 0x0000000000000002], F_30_00);
+}
+
+#[test]
+fn _0024() {
+  check!(0, false, 1, -7000, [0x0000000000000000, 0x0000000000000000], F_30_00);
+}
+
+#[test]
+fn _0025() {
+  check!(2, false, 1, -7000, [0x0000000000000000, 0x0000000000000001], F_30_00);
+}
+
+#[test]
+fn _0026() {
+  check!(1, true, 1, -7000, [0x8000000000000000, 0x0000000000000001], F_30_00);
+}
+
+#[test]
+fn _0027() {
+  check!(0, false, 5, i32::MIN, [0x0000000000000000, 0x0000000000000000], F_30_00);
+}
+
+#[test]
+fn _0028() {
+  check!(2, false, 5, i32::MIN, [0x0000000000000000, 0x0000000000000001], F_30_00);
+}
+
+#[test]
+fn _0029() {
+  check!(0, false, 1, 6111, [0x5ffe000000000000, 0x0000000000000001], F_00_00);
+}
+
+#[test]
+fn _0030() {
+  check!(0, false, 1, 6112, [0x5ffe000000000000, 0x000000000000000a], F_00_00);
+}
+
+#[test]
+fn _0031() {
+  check!(0, false, 1, 6144, [0x5ffe314dc6448d93, 0x38c15b0a00000000], F_00_00);
+}
+
+#[test]
+fn _0032() {
+  check!(0, false, 1, 6145, [0x7800000000000000, 0x0000000000000000], F_28_00);
+}
+
+#[test]
+fn _0033() {
+  check!(3, false, 1, 6145, [0x5fffed09bead87c0, 0x378d8e63ffffffff], F_28_00);
+}
+
+#[test]
+fn _0034() {
+  check!(1, false, 1, 6145, [0x5fffed09bead87c0, 0x378d8e63ffffffff], F_28_00);
+}
+
+#[test]
+fn _0035() {
+  check!(2, true, 1, 6145, [0xdfffed09bead87c0, 0x378d8e63ffffffff], F_28_00);
+}
+
+#[test]
+fn _0036() {
+  check!(0, false, u128::MAX, 6200, [0x7800000000000000, 0x0000000000000000], F_28_00);
+}
+
+#[test]
+fn _0037() {
+  check!(0, true, 1, i32::MAX, [0xf800000000000000, 0x0000000000000000], F_28_00);
+}
+
+#[test]
+fn _0038() {
+  check!(0, false, 0, i32::MAX, [0x5ffe000000000000, 0x0000000000000000], F_00_00);
+}
+
+#[test]
+fn _0039() {
+  check!(0, true, 0, i32::MIN, [0x8000000000000000, 0x0000000000000000], F_00_00);
+}
+
+#[test]
+fn _0040() {
+  check!(0, false, 0, -6177, [0x0000000000000000, 0x0000000000000000], F_00_00);
+}
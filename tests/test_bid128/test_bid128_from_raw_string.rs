@@ -0,0 +1,98 @@
+use decimus::{BidUint128, ParseDecimalErrorKind, bid128_from_raw_string};
+
+macro_rules! check {
+  ($x:expr, $expected:expr) => {
+    let expected = BidUint128 { w: [$expected[1], $expected[0]] };
+    assert_eq!(Ok(expected), bid128_from_raw_string($x));
+  };
+  ($x:expr, $expected_kind:expr, $expected_position:expr) => {
+    let err = bid128_from_raw_string($x).unwrap_err();
+    assert_eq!($expected_kind, err.kind());
+    assert_eq!($expected_position, err.position());
+  };
+}
+
+#[test]
+fn _0001() {
+  check!("0x3040000000000000_0000000000000001", [0x3040000000000000, 0x0000000000000001]);
+}
+
+#[test]
+fn _0002() {
+  check!("0X3040000000000000_0000000000000001", [0x3040000000000000, 0x0000000000000001]);
+}
+
+#[test]
+fn _0003() {
+  check!("3040000000000000_0000000000000001", [0x3040000000000000, 0x0000000000000001]);
+}
+
+#[test]
+fn _0004() {
+  check!("0xFC00000000000000_ABCDEF0123456789", [0xfc00000000000000, 0xabcdef0123456789]);
+}
+
+#[test]
+fn _0005() {
+  check!("0x0_1", [0x0000000000000000, 0x0000000000000001]);
+}
+
+#[test]
+fn _0006() {
+  check!("[3040000000000000 0000000000000001]", [0x3040000000000000, 0x0000000000000001]);
+}
+
+#[test]
+fn _0007() {
+  check!("", ParseDecimalErrorKind::Empty, 0);
+}
+
+#[test]
+fn _0008() {
+  check!("0x", ParseDecimalErrorKind::InvalidDigit, 2);
+}
+
+#[test]
+fn _0009() {
+  check!("0x3040000000000000", ParseDecimalErrorKind::InvalidDigit, 18);
+}
+
+#[test]
+fn _0010() {
+  check!("0x3040000000000000_", ParseDecimalErrorKind::InvalidDigit, 19);
+}
+
+#[test]
+fn _0011() {
+  check!("0x30400000000000000_1", ParseDecimalErrorKind::InvalidDigit, 18);
+}
+
+#[test]
+fn _0012() {
+  check!("0x1_2_3", ParseDecimalErrorKind::TrailingCharacters, 5);
+}
+
+#[test]
+fn _0013() {
+  check!("0x1_2 ", ParseDecimalErrorKind::TrailingCharacters, 5);
+}
+
+#[test]
+fn _0014() {
+  check!("0xg_1", ParseDecimalErrorKind::InvalidDigit, 2);
+}
+
+#[test]
+fn _0015() {
+  check!("[1 2", ParseDecimalErrorKind::InvalidDigit, 4);
+}
+
+#[test]
+fn _0016() {
+  check!("[1_2]", ParseDecimalErrorKind::InvalidDigit, 2);
+}
+
+#[test]
+fn _0017() {
+  check!("-0x1_2", ParseDecimalErrorKind::InvalidDigit, 0);
+}
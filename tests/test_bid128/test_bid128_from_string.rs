@@ -965,3 +965,110 @@ fn _0190() {
   let input = format!("0.{}0", "1".repeat(99));
   check!(0, &input, [0x2FFC36C831A180DC, 0x77F348B5C71C71C7], F_20_00);
 }
+
+#[test]
+fn _0191() {
+  check!(0, " \tSNaN", [0x7e00000000000000, 0x0000000000000000], F_00_00);
+}
+
+#[test]
+fn _0192() {
+  let input = format!("{}5{}1", "1".repeat(34), "0".repeat(200));
+  check!(0, &input, [0x31d436c831a180dc, 0x77f348b5c71c71c8], F_20_00);
+}
+
+#[test]
+fn _0193() {
+  let input = format!("{}5{}", "1".repeat(34), "0".repeat(200));
+  check!(0, &input, [0x31d236c831a180dc, 0x77f348b5c71c71c8], F_20_00);
+}
+
+#[test]
+fn _0194() {
+  let input = format!("{}{}1", "1".repeat(34), "0".repeat(500));
+  check!(2, &input, [0x342a36c831a180dc, 0x77f348b5c71c71c8], F_20_00);
+}
+
+#[test]
+fn _0195() {
+  let input = format!("-{}{}1", "1".repeat(34), "0".repeat(500));
+  check!(1, &input, [0xb42a36c831a180dc, 0x77f348b5c71c71c8], F_20_00);
+}
+
+#[test]
+fn _0196() {
+  let input = format!("{}{}1", "9".repeat(34), "0".repeat(500));
+  check!(3, &input, [0x342bed09bead87c0, 0x378d8e63ffffffff], F_20_00);
+}
+
+#[test]
+fn _0197() {
+  let input = format!("0.{}1", "0".repeat(300));
+  check!(0, &input, [0x2de6000000000000, 0x0000000000000001], F_00_00);
+}
+
+#[test]
+fn _0198() {
+  let input = format!("{}{}E-100", "1".repeat(20), "0".repeat(100));
+  check!(0, &input, [0x302436c831a180dc, 0x77f33e9ac547c000], F_00_00);
+}
+
+#[test]
+fn _0199() {
+  check!(0, "12€", [0x7c00000000000000, 0x0000000000000000], F_00_00);
+}
+
+#[test]
+fn _0200() {
+  check!(0, "sNaN€", [0x7c00000000000000, 0x0000000000000000], F_00_00);
+}
+
+#[test]
+fn _0201() {
+  check!(0, "１２", [0x7c00000000000000, 0x0000000000000000], F_00_00);
+}
+
+#[test]
+fn _0202() {
+  check!(0, "−5", [0x7c00000000000000, 0x0000000000000000], F_00_00);
+}
+
+#[test]
+fn _0203() {
+  check!(0, "NaN123", [0x7c00000000000000, 0x000000000000007b], F_00_00);
+}
+
+#[test]
+fn _0204() {
+  check!(0, "-sNaN456", [0xfe00000000000000, 0x00000000000001c8], F_00_00);
+}
+
+#[test]
+fn _0205() {
+  check!(0, "+nan0012", [0x7c00000000000000, 0x000000000000000c], F_00_00);
+}
+
+#[test]
+fn _0206() {
+  check!(0, "SNAN7", [0x7e00000000000000, 0x0000000000000007], F_00_00);
+}
+
+#[test]
+fn _0207() {
+  check!(0, "NaN999999999999999999999999999999999", [0x7c00314dc6448d93, 0x38c15b09ffffffff], F_00_00);
+}
+
+#[test]
+fn _0208() {
+  check!(0, "NaN1000000000000000000000000000000000", [0x7c00000000000000, 0x0000000000000000], F_00_00);
+}
+
+#[test]
+fn _0209() {
+  check!(0, "NaN999999999999999999999999999999999999999999999999999999999999", [0x7c00000000000000, 0x0000000000000000], F_00_00);
+}
+
+#[test]
+fn _0210() {
+  check!(0, "NaN0", [0x7c00000000000000, 0x0000000000000000], F_00_00);
+}
@@ -0,0 +1,305 @@
+use decimus::{BID_ROUNDING_TO_NEAREST, BidUint128, IdecFlags, bid128_from_string_const, bid128_from_string_strict, bid128_is_inf};
+
+macro_rules! check {
+  ($x:expr, $expected:expr) => {
+    let expected = BidUint128 { w: [$expected[1], $expected[0]] };
+    assert_eq!(expected, bid128_from_string_const($x));
+  };
+  ($x:expr) => {
+    bid128_from_string_const($x);
+  };
+}
+
+#[test]
+fn _0001() {
+  check!("0.0025", [0x3038000000000000, 0x0000000000000019]);
+}
+
+#[test]
+fn _0002() {
+  check!("-7.50", [0xb03c000000000000, 0x00000000000002ee]);
+}
+
+#[test]
+fn _0003() {
+  check!("+100", [0x3040000000000000, 0x0000000000000064]);
+}
+
+#[test]
+fn _0004() {
+  check!("1E+3", [0x3046000000000000, 0x0000000000000001]);
+}
+
+#[test]
+fn _0005() {
+  check!("1e-3", [0x303a000000000000, 0x0000000000000001]);
+}
+
+#[test]
+fn _0006() {
+  check!(".5", [0x303e000000000000, 0x0000000000000005]);
+}
+
+#[test]
+fn _0007() {
+  check!("5.", [0x3040000000000000, 0x0000000000000005]);
+}
+
+#[test]
+fn _0008() {
+  check!("000.000", [0x303a000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0009() {
+  check!("12345678901234567890123456789012345", [0x30423cde6fff9732, 0xde825cd07e96aff2]);
+}
+
+#[test]
+fn _0010() {
+  check!("12345678901234567890123456789012355", [0x30423cde6fff9732, 0xde825cd07e96aff4]);
+}
+
+#[test]
+fn _0011() {
+  check!("99999999999999999999999999999999995", [0x3044314dc6448d93, 0x38c15b0a00000000]);
+}
+
+#[test]
+fn _0012() {
+  check!("3.14159265358979323846264338327950288", [0x2ffe9ae4795796a7, 0xbabe5564e6f39f8f]);
+}
+
+#[test]
+fn _0013() {
+  check!("9999999999999999999999999999999999E6111", [0x5fffed09bead87c0, 0x378d8e63ffffffff]);
+}
+
+#[test]
+fn _0014() {
+  check!("1E6144", [0x5ffe314dc6448d93, 0x38c15b0a00000000]);
+}
+
+#[test]
+fn _0015() {
+  check!("0E+99999", [0x5ffe000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0016() {
+  check!("0E-99999", [0x0000000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0017() {
+  check!("15E-6177", [0x0000000000000000, 0x0000000000000002]);
+}
+
+#[test]
+fn _0018() {
+  check!("-1E-99999", [0x8000000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0019() {
+  check!("Inf", [0x7800000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0020() {
+  check!("-infinity", [0xf800000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0021() {
+  check!("NaN", [0x7c00000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0022() {
+  check!("-sNaN123", [0xfe00000000000000, 0x000000000000007b]);
+}
+
+#[test]
+#[should_panic(expected = "empty decimal literal")]
+fn _0023() {
+  check!("");
+}
+
+#[test]
+#[should_panic(expected = "invalid decimal literal")]
+fn _0024() {
+  check!("-");
+}
+
+#[test]
+#[should_panic(expected = "invalid decimal literal")]
+fn _0025() {
+  check!(".");
+}
+
+#[test]
+#[should_panic(expected = "invalid character")]
+fn _0026() {
+  check!("1.2.3");
+}
+
+#[test]
+#[should_panic(expected = "invalid character")]
+fn _0027() {
+  check!("1,5");
+}
+
+#[test]
+#[should_panic(expected = "invalid decimal literal")]
+fn _0028() {
+  check!(" 1");
+}
+
+#[test]
+#[should_panic(expected = "invalid character")]
+fn _0029() {
+  check!("1 ");
+}
+
+#[test]
+#[should_panic(expected = "invalid exponent")]
+fn _0030() {
+  check!("1E");
+}
+
+#[test]
+#[should_panic(expected = "invalid exponent")]
+fn _0031() {
+  check!("1E+");
+}
+
+#[test]
+#[should_panic(expected = "invalid decimal literal")]
+fn _0032() {
+  check!("Infx");
+}
+
+#[test]
+#[should_panic(expected = "invalid character")]
+fn _0033() {
+  check!("NaN12a");
+}
+
+#[test]
+#[should_panic(expected = "NaN payload too large")]
+fn _0034() {
+  check!("NaN9999999999999999999999999999999999");
+}
+
+#[test]
+#[should_panic(expected = "out of range")]
+fn _0035() {
+  check!("1E6145");
+}
+
+#[test]
+#[should_panic(expected = "out of range")]
+fn _0036() {
+  check!("99999999999999999999999999999999995E6110");
+}
+
+#[test]
+fn _0037() {
+  const FEE_RATE: BidUint128 = bid128_from_string_const("0.0025");
+  assert_eq!(BidUint128 { w: [0x0000000000000019, 0x3038000000000000] }, FEE_RATE);
+}
+
+/// Pseudo-random number generator (xorshift), makes the generated inputs reproducible.
+struct Random(u64);
+
+impl Random {
+  fn next(&mut self) -> u64 {
+    self.0 ^= self.0 << 13;
+    self.0 ^= self.0 >> 7;
+    self.0 ^= self.0 << 17;
+    self.0
+  }
+
+  fn below(&mut self, n: u64) -> u64 {
+    self.next() % n
+  }
+
+  /// Returns digits that are random or close to rounding boundaries, like 4999..., 5000...1 or 9999....
+  fn digits(&mut self, s: &mut String, count: u64) {
+    let pattern = self.below(4);
+    for i in 0..count {
+      let digit = match pattern {
+        0 => self.below(10),
+        1 => 9,
+        2 if i == 0 => 4 + self.below(2),
+        2 if i + 1 == count => self.below(2),
+        2 => 0,
+        _ => [0, 4, 5, 9][self.below(4) as usize],
+      };
+      s.push((b'0' + digit as u8) as char);
+    }
+  }
+
+  /// Returns the exponent, mostly close to the limits of normal and subnormal values.
+  fn exponent(&mut self) -> i64 {
+    match self.below(5) {
+      0 => self.below(12400) as i64 - 6200,
+      1 => -6176 - 40 + self.below(80) as i64,
+      2 => 6111 - 40 + self.below(80) as i64,
+      3 => -(self.below(100) as i64),
+      _ => [0, -99_999_999, 99_999_999, -1_000_000_000][self.below(4) as usize],
+    }
+  }
+}
+
+#[test]
+fn _0038() {
+  // Compile-time parser gives the same results as the strict runtime parser rounding to nearest,
+  // for inputs with more than 34 digits, rounding boundaries, extreme exponents and subnormal results,
+  // and panics on inputs rejected by the strict parser or rounded to infinity.
+  let default_hook = std::panic::take_hook();
+  std::panic::set_hook(Box::new(move |info| {
+    // expected panics in this test are not reported
+    if !std::thread::current().name().is_some_and(|name| name.ends_with("_0038")) {
+      default_hook(info);
+    }
+  }));
+  let mut random = Random(0x2545f4914f6cdd1d);
+  for _ in 0..50_000 {
+    let mut s = String::new();
+    s.push_str(["", "+", "-"][random.below(3) as usize]);
+    if random.below(4) == 0 {
+      s.push_str("000");
+    }
+    let count = random.below(45);
+    random.digits(&mut s, count);
+    if count == 0 || random.below(2) == 0 {
+      s.push('.');
+      let count = if count == 0 { 1 + random.below(45) } else { random.below(45) };
+      random.digits(&mut s, count);
+    }
+    if random.below(4) != 0 {
+      s.push_str(["E", "e"][random.below(2) as usize]);
+      let exponent = random.exponent();
+      s.push_str(if exponent < 0 { "-" } else { ["", "+"][random.below(2) as usize] });
+      s.push_str(&exponent.unsigned_abs().to_string());
+    }
+    let mut flags: IdecFlags = 0;
+    match bid128_from_string_strict(&s, BID_ROUNDING_TO_NEAREST, &mut flags) {
+      Ok(expected) if !bid128_is_inf(expected) => assert_eq!(expected, bid128_from_string_const(&s), "input: {s}"),
+      _ => assert!(std::panic::catch_unwind(|| bid128_from_string_const(&s)).is_err(), "input: {s}"),
+    }
+  }
+}
+
+#[test]
+#[should_panic(expected = "exponent too large")]
+fn _0039() {
+  check!("0E123456789");
+}
+
+#[test]
+fn _0040() {
+  check!("0E-000999999", [0x0000000000000000, 0x0000000000000000]);
+}
@@ -0,0 +1,164 @@
+use decimus::{BidUint128, IdecFlags, ParseDecimalErrorKind, bid128_from_string_strict};
+
+macro_rules! check {
+  ($x:expr, $expected:expr) => {
+    let mut actual_flags: IdecFlags = 0;
+    let expected = BidUint128 { w: [$expected[1], $expected[0]] };
+    assert_eq!(Ok(expected), bid128_from_string_strict($x, 0, &mut actual_flags));
+  };
+  ($x:expr, $expected_kind:expr, $expected_position:expr) => {
+    let mut actual_flags: IdecFlags = 0;
+    let err = bid128_from_string_strict($x, 0, &mut actual_flags).unwrap_err();
+    assert_eq!($expected_kind, err.kind());
+    assert_eq!($expected_position, err.position());
+  };
+}
+
+#[test]
+fn _0001() {
+  check!("", ParseDecimalErrorKind::Empty, 0);
+}
+
+#[test]
+fn _0002() {
+  check!("12,5", ParseDecimalErrorKind::TrailingCharacters, 2);
+}
+
+#[test]
+fn _0003() {
+  check!("1e", ParseDecimalErrorKind::InvalidDigit, 2);
+}
+
+#[test]
+fn _0004() {
+  check!("1E+", ParseDecimalErrorKind::InvalidDigit, 3);
+}
+
+#[test]
+fn _0005() {
+  check!("--3", ParseDecimalErrorKind::InvalidDigit, 1);
+}
+
+#[test]
+fn _0006() {
+  check!(" 1", ParseDecimalErrorKind::InvalidDigit, 0);
+}
+
+#[test]
+fn _0007() {
+  check!("1 ", ParseDecimalErrorKind::TrailingCharacters, 1);
+}
+
+#[test]
+fn _0008() {
+  check!(".", ParseDecimalErrorKind::InvalidDigit, 1);
+}
+
+#[test]
+fn _0009() {
+  check!("1.2.3", ParseDecimalErrorKind::TrailingCharacters, 3);
+}
+
+#[test]
+fn _0010() {
+  check!("1E1234567", ParseDecimalErrorKind::ExponentOverflow, 2);
+}
+
+#[test]
+fn _0011() {
+  check!("NaNs", ParseDecimalErrorKind::TrailingCharacters, 3);
+}
+
+#[test]
+fn _0012() {
+  check!("+infinit", ParseDecimalErrorKind::TrailingCharacters, 4);
+}
+
+#[test]
+fn _0013() {
+  check!("Decimal", ParseDecimalErrorKind::InvalidDigit, 0);
+}
+
+#[test]
+fn _0014() {
+  check!("123", [0x3040000000000000, 0x000000000000007b]);
+}
+
+#[test]
+fn _0015() {
+  check!("-1.23E+2", [0xb040000000000000, 0x000000000000007b]);
+}
+
+#[test]
+fn _0016() {
+  check!(".5", [0x303e000000000000, 0x0000000000000005]);
+}
+
+#[test]
+fn _0017() {
+  check!("1E-000000000000001", [0x303e000000000000, 0x0000000000000001]);
+}
+
+#[test]
+fn _0018() {
+  check!("-Infinity", [0xf800000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0019() {
+  check!("NaN", [0x7c00000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0020() {
+  check!("-sNaN", [0xfe00000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0021() {
+  let mut flags: IdecFlags = 0;
+  let err = bid128_from_string_strict("12,5", 0, &mut flags).unwrap_err();
+  assert_eq!("unexpected characters at position 2", err.to_string());
+  let err = bid128_from_string_strict("", 0, &mut flags).unwrap_err();
+  assert_eq!("cannot parse decimal from empty string", err.to_string());
+}
+
+#[test]
+fn _0022() {
+  check!("1€", ParseDecimalErrorKind::NonAsciiCharacter, 1);
+}
+
+#[test]
+fn _0023() {
+  check!("−5", ParseDecimalErrorKind::NonAsciiCharacter, 0);
+}
+
+#[test]
+fn _0024() {
+  check!("NaN123", [0x7c00000000000000, 0x000000000000007b]);
+}
+
+#[test]
+fn _0025() {
+  check!("-sNaN999999999999999999999999999999999", [0xfe00314dc6448d93, 0x38c15b09ffffffff]);
+}
+
+#[test]
+fn _0026() {
+  check!("NaN000999999999999999999999999999999999", [0x7c00314dc6448d93, 0x38c15b09ffffffff]);
+}
+
+#[test]
+fn _0027() {
+  check!("NaN1000000000000000000000000000000000", ParseDecimalErrorKind::PayloadOverflow, 3);
+}
+
+#[test]
+fn _0028() {
+  check!("NaN12a", ParseDecimalErrorKind::TrailingCharacters, 5);
+}
+
+#[test]
+fn _0029() {
+  check!("Inf12", ParseDecimalErrorKind::TrailingCharacters, 3);
+}
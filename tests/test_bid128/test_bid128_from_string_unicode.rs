@@ -0,0 +1,87 @@
+use decimus::{BidUint128, IdecFlags, ParseDecimalErrorKind, bid128_from_string, bid128_from_string_strict, bid128_from_string_unicode};
+
+macro_rules! check {
+  ($x:expr, $expected:expr) => {
+    let mut actual_flags: IdecFlags = 0;
+    let expected = BidUint128 { w: [$expected[1], $expected[0]] };
+    assert_eq!(Ok(expected), bid128_from_string_unicode($x, 0, &mut actual_flags));
+  };
+  ($x:expr, $expected_kind:expr, $expected_position:expr) => {
+    let mut actual_flags: IdecFlags = 0;
+    let err = bid128_from_string_unicode($x, 0, &mut actual_flags).unwrap_err();
+    assert_eq!($expected_kind, err.kind());
+    assert_eq!($expected_position, err.position());
+  };
+}
+
+#[test]
+fn _0001() {
+  check!("１２３", [0x3040000000000000, 0x000000000000007b]);
+}
+
+#[test]
+fn _0002() {
+  check!("−1.23E+2", [0xb040000000000000, 0x000000000000007b]);
+}
+
+#[test]
+fn _0003() {
+  check!("１.２３E−１", [0x303a000000000000, 0x000000000000007b]);
+}
+
+#[test]
+fn _0004() {
+  check!("−Infinity", [0xf800000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0005() {
+  check!("-１２3", [0xb040000000000000, 0x000000000000007b]);
+}
+
+#[test]
+fn _0006() {
+  check!("−１２円", ParseDecimalErrorKind::NonAsciiCharacter, 9);
+}
+
+#[test]
+fn _0007() {
+  check!("１２x", ParseDecimalErrorKind::TrailingCharacters, 6);
+}
+
+#[test]
+fn _0008() {
+  check!("１２€", ParseDecimalErrorKind::NonAsciiCharacter, 6);
+}
+
+#[test]
+fn _0009() {
+  let mut flags: IdecFlags = 0;
+  assert_eq!(BidUint128 { w: [0x0000000000000000, 0x7c00000000000000] }, bid128_from_string("１２３", 0, &mut flags));
+  assert_eq!(ParseDecimalErrorKind::NonAsciiCharacter, bid128_from_string_strict("１２３", 0, &mut flags).unwrap_err().kind());
+}
+
+#[test]
+fn _0010() {
+  check!("NaN１２", [0x7c00000000000000, 0x000000000000000c]);
+}
+
+#[test]
+fn _0011() {
+  check!("１E−", ParseDecimalErrorKind::InvalidDigit, 7);
+}
+
+#[test]
+fn _0012() {
+  check!("", ParseDecimalErrorKind::Empty, 0);
+}
+
+#[test]
+fn _0013() {
+  check!("１２３４５６７８９０１２３４５６７８９０１２３４５６７８９０１２３４５６", [0x30443cde6fff9732, 0xde825cd07e96aff3]);
+}
+
+#[test]
+fn _0014() {
+  check!("０.０００１２３E−１２３４５６７", ParseDecimalErrorKind::ExponentOverflow, 26);
+}
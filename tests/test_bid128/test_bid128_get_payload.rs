@@ -0,0 +1,49 @@
+use decimus::{BidUint128, bid128_get_payload};
+
+macro_rules! check {
+  ($x:expr, $expected:expr) => {
+    let x = BidUint128 { w: [$x[1], $x[0]] };
+    let expected = BidUint128 { w: [$expected[1], $expected[0]] };
+    assert_eq!(expected, bid128_get_payload(x));
+  };
+}
+
+#[test]
+fn _0001() {
+  check!([0x7c00000000000000, 0x000000000000007b], [0x3040000000000000, 0x000000000000007b]);
+}
+
+#[test]
+fn _0002() {
+  check!([0xfe00000000000000, 0x00000000000001c8], [0x3040000000000000, 0x00000000000001c8]);
+}
+
+#[test]
+fn _0003() {
+  check!([0x7c00000000000000, 0x0000000000000000], [0x3040000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0004() {
+  check!([0x7c00314dc6448d93, 0x38c15b09ffffffff], [0x3040314dc6448d93, 0x38c15b09ffffffff]);
+}
+
+#[test]
+fn _0005() {
+  check!([0x7c00314dc6448d93, 0x38c15b0a00000000], [0x3040000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0006() {
+  check!([0x3040000000000000, 0x0000000000000005], [0xb040000000000000, 0x0000000000000001]);
+}
+
+#[test]
+fn _0007() {
+  check!([0x7800000000000000, 0x0000000000000000], [0xb040000000000000, 0x0000000000000001]);
+}
+
+#[test]
+fn _0008() {
+  check!([0x3040000000000000, 0x0000000000000000], [0xb040000000000000, 0x0000000000000001]);
+}
@@ -0,0 +1,58 @@
+use decimus::{BidUint128, bid128_is_finite};
+
+macro_rules! check {
+  ($x:expr, $expected:expr) => {
+    let x = BidUint128 { w: [$x[1], $x[0]] };
+    assert_eq!($expected, bid128_is_finite(x));
+  };
+}
+
+#[test]
+fn _0001() {
+  check!([0x3040000000000000, 0x0000000000000001], true);
+}
+
+#[test]
+fn _0002() {
+  check!([0x0001ed09bead87c0, 0x378d8e64ffffffff], true);
+}
+
+#[test]
+fn _0003() {
+  check!([0x6000000000000000, 0x0000000000000000], true);
+}
+
+#[test]
+fn _0004() {
+  check!([0x7800000000000000, 0x0000000000000000], false);
+}
+
+#[test]
+fn _0005() {
+  check!([0xf800000000000000, 0x0000000000000000], false);
+}
+
+#[test]
+fn _0006() {
+  check!([0x7910000000000000, 0x0000000000000000], false);
+}
+
+#[test]
+fn _0007() {
+  check!([0x7c00000000000000, 0x0000000000000000], false);
+}
+
+#[test]
+fn _0008() {
+  check!([0xfc00000000000000, 0x0000000000000001], false);
+}
+
+#[test]
+fn _0009() {
+  check!([0x7e00000000000000, 0x0000000000000000], false);
+}
+
+#[test]
+fn _0010() {
+  check!([0xfe003fffffffffff, 0x38c15b08ffffffff], false);
+}
@@ -0,0 +1,33 @@
+use decimus::{BidUint128, bid128_nan};
+
+macro_rules! check {
+  ($payload:expr, $expected:expr) => {
+    let expected = BidUint128 { w: [$expected[1], $expected[0]] };
+    assert_eq!(expected, bid128_nan($payload));
+  };
+}
+
+#[test]
+fn _0001() {
+  check!(0, [0x7c00000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0002() {
+  check!(123, [0x7c00000000000000, 0x000000000000007b]);
+}
+
+#[test]
+fn _0003() {
+  check!(999999999999999999999999999999999, [0x7c00314dc6448d93, 0x38c15b09ffffffff]);
+}
+
+#[test]
+fn _0004() {
+  check!(1000000000000000000000000000000000, [0x7c00000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0005() {
+  check!(u128::MAX, [0x7c00000000000000, 0x0000000000000000]);
+}
@@ -0,0 +1,116 @@
+use decimus::*;
+
+fn d(s: &str) -> BidUint128 {
+  let mut flags = BID_EXACT_STATUS;
+  bid128_from_string(s, BID_ROUNDING_TO_NEAREST, &mut flags)
+}
+
+#[test]
+fn _0001() {
+  // no double rounding, 1.25 + 1E-40 is above the midpoint
+  let mut flags = BID_EXACT_STATUS;
+  let res = bid128_add_with_precision(d("1.25"), d("1E-40"), 2, BID_ROUNDING_TO_NEAREST, BID_TININESS_DEFAULT, &mut flags);
+  assert_eq!(d("1.3"), res);
+  assert_eq!(BID_INEXACT_EXCEPTION, flags);
+}
+
+#[test]
+fn _0002() {
+  let mut flags = BID_EXACT_STATUS;
+  let res = bid128_add_with_precision(d("1.25"), d("0"), 2, BID_ROUNDING_TO_NEAREST, BID_TININESS_DEFAULT, &mut flags);
+  assert_eq!(d("1.2"), res);
+  assert_eq!(BID_INEXACT_EXCEPTION, flags);
+}
+
+#[test]
+fn _0003() {
+  let mut flags = BID_EXACT_STATUS;
+  let res = bid128_add_with_precision(d("1.25"), d("0"), 2, BID_ROUNDING_TIES_AWAY, BID_TININESS_DEFAULT, &mut flags);
+  assert_eq!(d("1.3"), res);
+  assert_eq!(BID_INEXACT_EXCEPTION, flags);
+}
+
+#[test]
+fn _0004() {
+  let mut flags = BID_EXACT_STATUS;
+  let res = bid128_div_with_precision(d("2"), d("3"), 16, BID_ROUNDING_TO_NEAREST, BID_TININESS_DEFAULT, &mut flags);
+  assert_eq!(d("0.6666666666666667"), res);
+  assert_eq!(BID_INEXACT_EXCEPTION, flags);
+}
+
+#[test]
+fn _0005() {
+  let mut flags = BID_EXACT_STATUS;
+  assert_eq!(d("-0.6666666666666667"), bid128_div_with_precision(d("-2"), d("3"), 16, BID_ROUNDING_DOWN, BID_TININESS_DEFAULT, &mut flags));
+  assert_eq!(d("-0.6666666666666666"), bid128_div_with_precision(d("-2"), d("3"), 16, BID_ROUNDING_UP, BID_TININESS_DEFAULT, &mut flags));
+  assert_eq!(d("-0.6666666666666666"), bid128_div_with_precision(d("-2"), d("3"), 16, BID_ROUNDING_TO_ZERO, BID_TININESS_DEFAULT, &mut flags));
+}
+
+#[test]
+fn _0006() {
+  // rounding overflow of the coefficient
+  let mut flags = BID_EXACT_STATUS;
+  let res = bid128_mul_with_precision(d("99.96"), d("1"), 3, BID_ROUNDING_TO_NEAREST, BID_TININESS_DEFAULT, &mut flags);
+  assert_eq!(d("100"), res);
+  assert_eq!(BID_INEXACT_EXCEPTION, flags);
+}
+
+#[test]
+fn _0007() {
+  // rounding toward zero crosses into the lower decade
+  let mut flags = BID_EXACT_STATUS;
+  let res = bid128_sub_with_precision(d("100"), d("1E-30"), 3, BID_ROUNDING_TO_ZERO, BID_TININESS_DEFAULT, &mut flags);
+  assert_eq!(d("99.9"), res);
+  assert_eq!(BID_INEXACT_EXCEPTION, flags);
+}
+
+#[test]
+fn _0008() {
+  // exact result fits in the precision
+  let mut flags = BID_EXACT_STATUS;
+  let res = bid128_fma_with_precision(d("1.0000000001"), d("1.0000000001"), d("-1"), 20, BID_ROUNDING_TO_NEAREST, BID_TININESS_DEFAULT, &mut flags);
+  assert_eq!(d("2.0000000001E-10"), res);
+  assert_eq!(BID_EXACT_STATUS, flags);
+}
+
+#[test]
+fn _0009() {
+  let mut flags = BID_EXACT_STATUS;
+  let res = bid128_mul_with_precision(d("123"), d("45"), 5, BID_ROUNDING_TO_NEAREST, BID_TININESS_DEFAULT, &mut flags);
+  assert_eq!(d("5535"), res);
+  assert_eq!(BID_EXACT_STATUS, flags);
+}
+
+#[test]
+fn _0010() {
+  // rounding to the precision overflows
+  let mut flags = BID_EXACT_STATUS;
+  let res = bid128_mul_with_precision(d("9.6E6144"), d("1"), 1, BID_ROUNDING_TO_NEAREST, BID_TININESS_DEFAULT, &mut flags);
+  assert_eq!(d("Inf"), res);
+  let res = bid128_mul_with_precision(d("9.6E6144"), d("1"), 1, BID_ROUNDING_TO_ZERO, BID_TININESS_DEFAULT, &mut flags);
+  assert_eq!(d("9E6144"), res);
+}
+
+#[test]
+fn _0011() {
+  // full precision
+  let mut flags = BID_EXACT_STATUS;
+  let res = bid128_div_with_precision(d("2"), d("3"), 34, BID_ROUNDING_TO_NEAREST, BID_TININESS_DEFAULT, &mut flags);
+  assert_eq!(d("0.6666666666666666666666666666666667"), res);
+}
+
+#[test]
+fn _0012() {
+  let mut ctx = Context::default();
+  assert_eq!(34, ctx.precision());
+  ctx.set_precision(20);
+  assert_eq!(20, ctx.precision());
+  let x = ctx.from_string("1");
+  let y = ctx.from_string("7");
+  assert_eq!(ctx.from_string("0.14285714285714285714"), ctx.div(x, y));
+  assert_eq!(Ok(ctx.from_string("0.14285714285714285714")), ctx.checked_div(x, y));
+  ctx.set_precision(0);
+  assert_eq!(1, ctx.precision());
+  ctx.set_precision(50);
+  assert_eq!(34, ctx.precision());
+}
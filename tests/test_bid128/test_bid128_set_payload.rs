@@ -0,0 +1,84 @@
+use decimus::{BidUint128, bid128_set_payload};
+
+macro_rules! check {
+  ($x:expr, $expected:expr) => {
+    let x = BidUint128 { w: [$x[1], $x[0]] };
+    let expected = BidUint128 { w: [$expected[1], $expected[0]] };
+    assert_eq!(expected, bid128_set_payload(x));
+  };
+}
+
+#[test]
+fn _0001() {
+  check!([0x3040000000000000, 0x000000000000007b], [0x7c00000000000000, 0x000000000000007b]);
+}
+
+#[test]
+fn _0002() {
+  check!([0x3042000000000000, 0x000000000000000c], [0x7c00000000000000, 0x0000000000000078]);
+}
+
+#[test]
+fn _0003() {
+  check!([0x303c000000000000, 0x00000000000004b0], [0x7c00000000000000, 0x000000000000000c]);
+}
+
+#[test]
+fn _0004() {
+  check!([0x3040000000000000, 0x0000000000000000], [0x7c00000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0005() {
+  check!([0xb04a000000000000, 0x0000000000000000], [0x7c00000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0006() {
+  check!([0x3036000000000000, 0x0000000000000000], [0x7c00000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0007() {
+  check!([0x3040314dc6448d93, 0x38c15b09ffffffff], [0x7c00314dc6448d93, 0x38c15b09ffffffff]);
+}
+
+#[test]
+fn _0008() {
+  check!([0x304204ee2d6d415b, 0x85acef8100000000], [0x3040000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0009() {
+  check!([0x303c000000000000, 0x00000000000004d2], [0x3040000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0010() {
+  check!([0x0000000000000000, 0x0000000000000001], [0x3040000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0011() {
+  check!([0xb040000000000000, 0x0000000000000005], [0x3040000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0012() {
+  check!([0x5f20000000000000, 0x0000000000000001], [0x3040000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0013() {
+  check!([0x7c00000000000000, 0x0000000000000005], [0x3040000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0014() {
+  check!([0x7800000000000000, 0x0000000000000000], [0x3040000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0015() {
+  check!([0x3040314dc6448d93, 0x38c15b0a00000000], [0x3040000000000000, 0x0000000000000000]);
+}
@@ -0,0 +1,79 @@
+use super::*;
+use decimus::{BID_TININESS_AFTER_ROUNDING, BID_TININESS_BEFORE_ROUNDING, BidUint128, bid128_fma_with_tininess, bid128_from_string, bid128_mul_with_tininess};
+
+macro_rules! check {
+  ($rnd_mode:expr, $tininess:expr, $x:expr, $y:expr, $expected:expr, $expected_flags:expr) => {
+    let mut actual_flags: IdecFlags = 0;
+    let expected = BidUint128 { w: [$expected[1], $expected[0]] };
+    let x = bid128_from_string($x, 0, &mut actual_flags);
+    let y = bid128_from_string($y, 0, &mut actual_flags);
+    assert_eq!(F_00_00, actual_flags, "Arguments flags error");
+    let actual = bid128_mul_with_tininess(x, y, $rnd_mode, $tininess, &mut actual_flags);
+    assert_eq!(expected, actual);
+    assert_eq!($expected_flags, actual_flags, "Result flags error, expected = 0x{:02X}, actual = 0x{:02X}", $expected_flags, actual_flags);
+  };
+  ($rnd_mode:expr, $tininess:expr, $x:expr, $y:expr, $z:expr, $expected:expr, $expected_flags:expr) => {
+    let mut actual_flags: IdecFlags = 0;
+    let expected = BidUint128 { w: [$expected[1], $expected[0]] };
+    let x = bid128_from_string($x, 0, &mut actual_flags);
+    let y = bid128_from_string($y, 0, &mut actual_flags);
+    let z = bid128_from_string($z, 0, &mut actual_flags);
+    assert_eq!(F_00_00, actual_flags, "Arguments flags error");
+    let actual = bid128_fma_with_tininess(x, y, z, $rnd_mode, $tininess, &mut actual_flags);
+    assert_eq!(expected, actual);
+    assert_eq!($expected_flags, actual_flags, "Result flags error, expected = 0x{:02X}, actual = 0x{:02X}", $expected_flags, actual_flags);
+  };
+}
+
+const X: &str = "3162277660168379331997787088865436E-6176";
+const Y: &str = "3162277660168379332000000000000001E-34";
+
+#[test]
+fn _0001() {
+  check!(0, BID_TININESS_BEFORE_ROUNDING, X, Y, [0x0000314dc6448d93, 0x38c15b0a00000000], F_30_30);
+}
+
+#[test]
+fn _0002() {
+  check!(0, BID_TININESS_AFTER_ROUNDING, X, Y, [0x0000314dc6448d93, 0x38c15b0a00000000], F_20_20);
+}
+
+#[test]
+fn _0003() {
+  check!(1, BID_TININESS_BEFORE_ROUNDING, X, Y, [0x0000314dc6448d93, 0x38c15b09ffffffff], F_30_30);
+}
+
+#[test]
+fn _0004() {
+  check!(1, BID_TININESS_AFTER_ROUNDING, X, Y, [0x0000314dc6448d93, 0x38c15b09ffffffff], F_30_30);
+}
+
+#[test]
+fn _0005() {
+  check!(2, BID_TININESS_BEFORE_ROUNDING, X, Y, [0x0000314dc6448d93, 0x38c15b0a00000000], F_30_30);
+}
+
+#[test]
+fn _0006() {
+  check!(2, BID_TININESS_AFTER_ROUNDING, X, Y, [0x0000314dc6448d93, 0x38c15b0a00000000], F_20_20);
+}
+
+#[test]
+fn _0007() {
+  check!(4, BID_TININESS_AFTER_ROUNDING, X, Y, [0x0000314dc6448d93, 0x38c15b0a00000000], F_20_20);
+}
+
+#[test]
+fn _0008() {
+  check!(0, BID_TININESS_BEFORE_ROUNDING, "+1E-6176", "-1E-6176", "+1E-6143", [0x0000314dc6448d93, 0x38c15b0a00000000], F_30_30);
+}
+
+#[test]
+fn _0009() {
+  check!(0, BID_TININESS_AFTER_ROUNDING, "+1E-6176", "-1E-6176", "+1E-6143", [0x0000314dc6448d93, 0x38c15b0a00000000], F_20_20);
+}
+
+#[test]
+fn _0010() {
+  check!(3, BID_TININESS_AFTER_ROUNDING, "+1E-6176", "-1E-6176", "+1E-6143", [0x0000314dc6448d93, 0x38c15b09ffffffff], F_30_30);
+}
@@ -0,0 +1,94 @@
+use super::*;
+use decimus::{BidUint128, IdecFlags, bid128_to_decimal128_array};
+
+macro_rules! check {
+  ($rnd_mode:expr, $x:expr, $precision:expr, $scale:expr, $expected:expr, $expected_valid:expr, $expected_flags:expr) => {
+    let mut actual_flags: IdecFlags = 0;
+    let mut output = [-1];
+    let mut validity = [0xff];
+    let nulls = bid128_to_decimal128_array(&[BidUint128 { w: [$x[1], $x[0]] }], $precision, $scale, $rnd_mode, &mut output, &mut validity, &mut actual_flags);
+    assert_eq!($expected, output[0]);
+    assert_eq!($expected_valid, validity[0] == 0xff);
+    assert_eq!(!$expected_valid as usize, nulls);
+    assert_eq!($expected_flags, actual_flags, "Result flags error, expected = 0x{:02X}, actual = 0x{:02X}", $expected_flags, actual_flags);
+  };
+}
+
+#[test]
+fn _0001() {
+  check!(0, [0x303c000000000000, 0x0000000000003039], 10, 3, 123450, true, F_00_00);
+}
+
+#[test]
+fn _0002() {
+  check!(0, [0x303c000000000000, 0x0000000000003039], 5, 3, 0, false, F_01_00);
+}
+
+#[test]
+fn _0003() {
+  check!(0, [0x303c000000000000, 0x0000000000003039], 3, 0, 123, true, F_20_00);
+}
+
+#[test]
+fn _0004() {
+  check!(2, [0x303c000000000000, 0x0000000000003039], 4, 1, 1235, true, F_20_00);
+}
+
+#[test]
+fn _0005() {
+  check!(1, [0xb038000000000000, 0x0000000000003039], 3, 2, -124, true, F_20_00);
+}
+
+#[test]
+fn _0006() {
+  check!(0, [0x7c00000000000000, 0x0000000000000000], 38, 0, 0, false, F_01_00);
+}
+
+#[test]
+fn _0007() {
+  check!(0, [0xf800000000000000, 0x0000000000000000], 38, 0, 0, false, F_01_00);
+}
+
+#[test]
+fn _0008() {
+  check!(0, [0x3041ed09bead87c0, 0x378d8e63ffffffff], 38, 4, 99999999999999999999999999999999990000, true, F_00_00);
+}
+
+#[test]
+fn _0009() {
+  check!(0, [0x3041ed09bead87c0, 0x378d8e63ffffffff], 38, 5, 0, false, F_01_00);
+}
+
+#[test]
+fn _0010() {
+  check!(0, [0x303c000000000000, 0x0000000000000000], 1, 0, 0, true, F_00_00);
+}
+
+#[test]
+fn _0011() {
+  let input: Vec<BidUint128> = (0..10)
+    .map(|i| BidUint128 {
+      w: [i, if i % 3 == 0 { 0x7c00000000000000 } else { 0x3040000000000000 }],
+    })
+    .collect();
+  let mut output = [-1; 10];
+  let mut validity = [0xff; 2];
+  let mut flags: IdecFlags = 0;
+  assert_eq!(4, bid128_to_decimal128_array(&input, 1, 0, 0, &mut output, &mut validity, &mut flags));
+  assert_eq!([0, 1, 2, 0, 4, 5, 0, 7, 8, 0], output);
+  assert_eq!([0b10110110, 0b11111101], validity);
+}
+
+#[test]
+#[should_panic(expected = "invalid precision of Decimal128")]
+fn _0012() {
+  let mut flags: IdecFlags = 0;
+  bid128_to_decimal128_array(&[BidUint128::default()], 39, 0, 0, &mut [0], &mut [0], &mut flags);
+}
+
+#[test]
+#[should_panic(expected = "validity bitmap is too small")]
+fn _0013() {
+  let mut flags: IdecFlags = 0;
+  bid128_to_decimal128_array(&[BidUint128::default(); 9], 38, 0, 0, &mut [0; 9], &mut [0], &mut flags);
+}
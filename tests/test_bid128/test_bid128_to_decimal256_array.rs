@@ -0,0 +1,100 @@
+use super::*;
+use decimus::{BidUint128, IdecFlags, bid128_to_decimal256_array};
+
+/// Returns 256-bit integer as 32 bytes in little-endian order.
+fn i256(hi: u128, lo: u128) -> [u8; 32] {
+  let mut bytes = [0; 32];
+  bytes[..16].copy_from_slice(&lo.to_le_bytes());
+  bytes[16..].copy_from_slice(&hi.to_le_bytes());
+  bytes
+}
+
+macro_rules! check {
+  ($rnd_mode:expr, $x:expr, $precision:expr, $scale:expr, $expected:expr, $expected_valid:expr, $expected_flags:expr) => {
+    let mut actual_flags: IdecFlags = 0;
+    let mut output = [[0xff; 32]];
+    let mut validity = [0xff];
+    let nulls = bid128_to_decimal256_array(&[BidUint128 { w: [$x[1], $x[0]] }], $precision, $scale, $rnd_mode, &mut output, &mut validity, &mut actual_flags);
+    assert_eq!($expected, output[0]);
+    assert_eq!($expected_valid, validity[0] == 0xff);
+    assert_eq!(!$expected_valid as usize, nulls);
+    assert_eq!($expected_flags, actual_flags, "Result flags error, expected = 0x{:02X}, actual = 0x{:02X}", $expected_flags, actual_flags);
+  };
+}
+
+#[test]
+fn _0001() {
+  check!(0, [0x303c000000000000, 0x0000000000003039], 10, 3, i256(0, 123450), true, F_00_00);
+}
+
+#[test]
+fn _0002() {
+  check!(0, [0xb03c000000000000, 0x0000000000003039], 10, 3, i256(0xffffffffffffffffffffffffffffffff, 0xfffffffffffffffffffffffffffe1dc6), true, F_00_00);
+}
+
+#[test]
+fn _0003() {
+  check!(0, [0x3095ed09bead87c0, 0x378d8e63ffffffff], 76, 0, i256(0x161bcca7119915b50764b4abe8651dfe, 0xbb1335a1413a30eb5c261c0000000000), true, F_00_00);
+}
+
+#[test]
+fn _0004() {
+  check!(0, [0xb095ed09bead87c0, 0x378d8e63ffffffff], 76, 0, i256(0xe9e43358ee66ea4af89b4b54179ae201, 0x44ecca5ebec5cf14a3d9e40000000000), true, F_00_00);
+}
+
+#[test]
+fn _0005() {
+  check!(0, [0x3095ed09bead87c0, 0x378d8e63ffffffff], 75, 0, [0; 32], false, F_01_00);
+}
+
+#[test]
+fn _0006() {
+  check!(0, [0x30d6000000000000, 0x0000000000000001], 76, 0, i256(0x235fadd81c2822bb3f07877973d50f2, 0x8bf22a31be8ee8000000000000000000), true, F_00_00);
+}
+
+#[test]
+fn _0007() {
+  check!(0, [0x303c000000000000, 0x0000000000003039], 3, 0, i256(0, 123), true, F_20_00);
+}
+
+#[test]
+fn _0008() {
+  check!(1, [0xb038000000000000, 0x0000000000003039], 3, 2, i256(0xffffffffffffffffffffffffffffffff, 0xffffffffffffffffffffffffffffff84), true, F_20_00);
+}
+
+#[test]
+fn _0009() {
+  check!(0, [0x7c00000000000000, 0x0000000000000000], 76, 0, [0; 32], false, F_01_00);
+}
+
+#[test]
+fn _0010() {
+  check!(0, [0xf800000000000000, 0x0000000000000000], 76, 0, [0; 32], false, F_01_00);
+}
+
+#[test]
+fn _0011() {
+  check!(0, [0x5f20000000000000, 0x0000000000000000], 1, 0, [0; 32], true, F_00_00);
+}
+
+#[test]
+fn _0012() {
+  let input: Vec<BidUint128> = (0..10)
+    .map(|i| BidUint128 {
+      w: [i, if i % 3 == 0 { 0x7c00000000000000 } else { 0x3040000000000000 }],
+    })
+    .collect();
+  let mut output = [[0xff; 32]; 10];
+  let mut validity = [0xff; 2];
+  let mut flags: IdecFlags = 0;
+  assert_eq!(4, bid128_to_decimal256_array(&input, 1, 0, 0, &mut output, &mut validity, &mut flags));
+  assert_eq!([0, 1, 2, 0, 4, 5, 0, 7, 8, 0].map(|i| i256(0, i)), output);
+  assert_eq!([0b10110110, 0b11111101], validity);
+}
+
+#[test]
+#[should_panic(expected = "invalid precision of Decimal256")]
+fn _0013() {
+  let mut flags: IdecFlags = 0;
+  bid128_to_decimal256_array(&[BidUint128::default()], 77, 0, 0, &mut [[0; 32]], &mut [0], &mut flags);
+}
@@ -0,0 +1,106 @@
+use decimus::{BidUint128, bid128_to_eng_string, bid128_write_eng_string};
+
+macro_rules! check {
+  ($x:expr, $expected:expr) => {
+    let x = BidUint128 { w: [$x[1], $x[0]] };
+    assert_eq!($expected, bid128_to_eng_string(x));
+    let mut actual = String::new();
+    bid128_write_eng_string(x, &mut actual).unwrap();
+    assert_eq!($expected, actual);
+  };
+}
+
+#[test]
+fn _0001() {
+  check!([0x3042000000000000, 0x000000000000007b], "1.23E+3");
+}
+
+#[test]
+fn _0002() {
+  check!([0x3046000000000000, 0x000000000000007b], "123E+3");
+}
+
+#[test]
+fn _0003() {
+  check!([0x303e000000000000, 0x000000000000007b], "12.3");
+}
+
+#[test]
+fn _0004() {
+  check!([0x302c000000000000, 0x000000000000007b], "12.3E-9");
+}
+
+#[test]
+fn _0005() {
+  check!([0xb028000000000000, 0x000000000000007b], "-123E-12");
+}
+
+#[test]
+fn _0006() {
+  check!([0x3030000000000000, 0x0000000000000005], "50E-9");
+}
+
+#[test]
+fn _0007() {
+  check!([0x3042000000000000, 0x00000000000004d2], "12.34E+3");
+}
+
+#[test]
+fn _0008() {
+  check!([0x3042000000000000, 0x000000000000000c], "120");
+}
+
+#[test]
+fn _0009() {
+  check!([0x3040000000000000, 0x0000000000000000], "0");
+}
+
+#[test]
+fn _0010() {
+  check!([0x3042000000000000, 0x0000000000000000], "0.00E+3");
+}
+
+#[test]
+fn _0011() {
+  check!([0x3044000000000000, 0x0000000000000000], "0.0E+3");
+}
+
+#[test]
+fn _0012() {
+  check!([0x3046000000000000, 0x0000000000000000], "0E+3");
+}
+
+#[test]
+fn _0013() {
+  check!([0x3032000000000000, 0x0000000000000000], "0.0E-6");
+}
+
+#[test]
+fn _0014() {
+  check!([0x3030000000000000, 0x0000000000000000], "0.00E-6");
+}
+
+#[test]
+fn _0015() {
+  check!([0xb02e000000000000, 0x0000000000000000], "-0E-9");
+}
+
+#[test]
+fn _0016() {
+  check!([0x0000000000000000, 0x0000000000000001], "10E-6177");
+}
+
+#[test]
+fn _0017() {
+  check!([0x5fffed09bead87c0, 0x378d8e63ffffffff], "9.999999999999999999999999999999999E+6144");
+}
+
+#[test]
+fn _0018() {
+  check!([0x7800000000000000, 0x0000000000000000], "Infinity");
+}
+
+#[test]
+fn _0019() {
+  check!([0x7c00000000000000, 0x000000000000002a], "NaN42");
+}
@@ -0,0 +1,85 @@
+use super::*;
+use decimus::{BidUint128, IdecFlags, bid128_to_i128_scaled};
+
+macro_rules! check {
+  ($rnd_mode:expr, $x:expr, $scale:expr, $expected:expr, $expected_flags:expr) => {
+    let mut actual_flags: IdecFlags = 0;
+    assert_eq!($expected, bid128_to_i128_scaled(BidUint128 { w: [$x[1], $x[0]] }, $scale, $rnd_mode, &mut actual_flags));
+    assert_eq!($expected_flags, actual_flags, "Result flags error, expected = 0x{:02X}, actual = 0x{:02X}", $expected_flags, actual_flags);
+  };
+}
+
+#[test]
+fn _0001() {
+  check!(0, [0x303c000000000000, 0x0000000000003039], 2, Some(12345), F_00_00);
+}
+
+#[test]
+fn _0002() {
+  check!(0, [0x303c000000000000, 0x0000000000003039], 10, Some(1234500000000), F_00_00);
+}
+
+#[test]
+fn _0003() {
+  check!(0, [0x303c000000000000, 0x0000000000003039], 0, Some(123), F_20_00);
+}
+
+#[test]
+fn _0004() {
+  check!(0, [0x303c000000000000, 0x0000000000003039], 1, Some(1234), F_20_00);
+}
+
+#[test]
+fn _0005() {
+  check!(4, [0x303c000000000000, 0x0000000000003039], 1, Some(1235), F_20_00);
+}
+
+#[test]
+fn _0006() {
+  check!(1, [0xb03c000000000000, 0x0000000000003039], 0, Some(-124), F_20_00);
+}
+
+#[test]
+fn _0007() {
+  check!(2, [0xb03c000000000000, 0x0000000000003039], 0, Some(-123), F_20_00);
+}
+
+#[test]
+fn _0008() {
+  check!(3, [0x303c000000000000, 0x0000000000003039], -3, Some(0), F_20_00);
+}
+
+#[test]
+fn _0009() {
+  check!(2, [0x0000000000000000, 0x0000000000000001], 0, Some(1), F_20_00);
+}
+
+#[test]
+fn _0010() {
+  check!(0, [0xb040000000000000, 0x0000000000000000], 38, Some(0), F_00_00);
+}
+
+#[test]
+fn _0011() {
+  check!(0, [0x5fffed09bead87c0, 0x378d8e63ffffffff], 0, None, F_01_00);
+}
+
+#[test]
+fn _0012() {
+  check!(0, [0x304853e2d6238da3, 0xc21187e7c06e19b9], 1, Some(170141183460469231731687303715884100000), F_00_00);
+}
+
+#[test]
+fn _0013() {
+  check!(0, [0xb04853e2d6238da3, 0xc21187e7c06e19b9], 2, None, F_01_00);
+}
+
+#[test]
+fn _0014() {
+  check!(0, [0x7800000000000000, 0x0000000000000000], 0, None, F_01_00);
+}
+
+#[test]
+fn _0015() {
+  check!(0, [0x7c00000000000000, 0x0000000000000000], 0, None, F_01_00);
+}
@@ -0,0 +1,59 @@
+use decimus::{BidUint128, bid128_to_parts};
+
+macro_rules! check {
+  ($x:expr, $expected:expr) => {
+    assert_eq!($expected, bid128_to_parts(BidUint128 { w: [$x[1], $x[0]] }));
+  };
+}
+
+#[test]
+fn _0001() {
+  check!([0x3040000000000000, 0x0000000000000000], (false, 0, 0));
+}
+
+#[test]
+fn _0002() {
+  check!([0xb02c000000000000, 0x0000001cbe991a14], (true, 123456789012, -10));
+}
+
+#[test]
+fn _0003() {
+  check!([0x3041ed09bead87c0, 0x378d8e63ffffffff], (false, 9999999999999999999999999999999999, 0));
+}
+
+#[test]
+fn _0004() {
+  check!([0x0000000000000000, 0x0000000000000001], (false, 1, -6176));
+}
+
+#[test]
+fn _0005() {
+  check!([0x5fffed09bead87c0, 0x378d8e63ffffffff], (false, 9999999999999999999999999999999999, 6111));
+}
+
+#[test]
+fn _0006() {
+  // Non-canonical coefficient.
+  check!([0x3041ed09bead87c0, 0x378d8e6400000000], (false, 0, 0));
+}
+
+#[test]
+fn _0007() {
+  // Non-canonical encoding with steering bits.
+  check!([0xec11ffffffffffff, 0xffffffffffffffff], (true, 0, 3));
+}
+
+#[test]
+fn _0008() {
+  check!([0xf800000000000000, 0x0000000000000000], (true, 0, 0));
+}
+
+#[test]
+fn _0009() {
+  check!([0x7c00000000000000, 0x000000000000007b], (false, 123, 0));
+}
+
+#[test]
+fn _0010() {
+  check!([0xfe00000000000000, 0x0000000000000000], (true, 0, 0));
+}
@@ -0,0 +1,131 @@
+use decimus::{BidUint128, bid128_to_sci_string, bid128_write_sci_string};
+
+macro_rules! check {
+  ($x:expr, $expected:expr) => {
+    let x = BidUint128 { w: [$x[1], $x[0]] };
+    assert_eq!($expected, bid128_to_sci_string(x));
+    let mut actual = String::new();
+    bid128_write_sci_string(x, &mut actual).unwrap();
+    assert_eq!($expected, actual);
+  };
+}
+
+#[test]
+fn _0001() {
+  check!([0x3040000000000000, 0x000000000000007b], "123");
+}
+
+#[test]
+fn _0002() {
+  check!([0xb040000000000000, 0x000000000000007b], "-123");
+}
+
+#[test]
+fn _0003() {
+  check!([0x3042000000000000, 0x000000000000007b], "1.23E+3");
+}
+
+#[test]
+fn _0004() {
+  check!([0x3046000000000000, 0x000000000000007b], "1.23E+5");
+}
+
+#[test]
+fn _0005() {
+  check!([0x303e000000000000, 0x000000000000007b], "12.3");
+}
+
+#[test]
+fn _0006() {
+  check!([0x3036000000000000, 0x000000000000007b], "0.00123");
+}
+
+#[test]
+fn _0007() {
+  check!([0x302c000000000000, 0x000000000000007b], "1.23E-8");
+}
+
+#[test]
+fn _0008() {
+  check!([0xb028000000000000, 0x000000000000007b], "-1.23E-10");
+}
+
+#[test]
+fn _0009() {
+  check!([0x3040000000000000, 0x0000000000000000], "0");
+}
+
+#[test]
+fn _0010() {
+  check!([0x303c000000000000, 0x0000000000000000], "0.00");
+}
+
+#[test]
+fn _0011() {
+  check!([0x3044000000000000, 0x0000000000000000], "0E+2");
+}
+
+#[test]
+fn _0012() {
+  check!([0xb040000000000000, 0x0000000000000000], "-0");
+}
+
+#[test]
+fn _0013() {
+  check!([0x3034000000000000, 0x0000000000000005], "0.000005");
+}
+
+#[test]
+fn _0014() {
+  check!([0x3032000000000000, 0x0000000000000005], "5E-7");
+}
+
+#[test]
+fn _0015() {
+  check!([0x3038000000000000, 0x000000000000300c], "1.2300");
+}
+
+#[test]
+fn _0016() {
+  check!([0x5ffe000000000000, 0x0000000000000001], "1E+6111");
+}
+
+#[test]
+fn _0017() {
+  check!([0x5fffed09bead87c0, 0x378d8e63ffffffff], "9.999999999999999999999999999999999E+6144");
+}
+
+#[test]
+fn _0018() {
+  check!([0x0000000000000000, 0x0000000000000001], "1E-6176");
+}
+
+#[test]
+fn _0019() {
+  check!([0x7800000000000000, 0x0000000000000000], "Infinity");
+}
+
+#[test]
+fn _0020() {
+  check!([0xf800000000000000, 0x0000000000000000], "-Infinity");
+}
+
+#[test]
+fn _0021() {
+  check!([0x7c00000000000000, 0x0000000000000000], "NaN");
+}
+
+#[test]
+fn _0022() {
+  check!([0xfe00000000000000, 0x0000000000000000], "-sNaN");
+}
+
+#[test]
+fn _0023() {
+  check!([0x7c00000000000000, 0x000000000000007b], "NaN123");
+}
+
+#[test]
+fn _0024() {
+  check!([0x7e00007e37be2022, 0xc0914b267fffffff], "sNaN9999999999999999999999999999999");
+}
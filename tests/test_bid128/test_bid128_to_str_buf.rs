@@ -0,0 +1,74 @@
+use decimus::{BID128_MAX_STRING_LENGTH, BidUint128, bid128_to_str_buf, bid128_write_string};
+
+macro_rules! check {
+  ($x:expr, $expected:expr) => {
+    let x = BidUint128 { w: [$x[1], $x[0]] };
+    let mut buf = [0; BID128_MAX_STRING_LENGTH];
+    assert_eq!($expected, bid128_to_str_buf(x, &mut buf));
+    let mut actual = String::new();
+    bid128_write_string(x, &mut actual).unwrap();
+    assert_eq!($expected, actual);
+  };
+}
+
+#[test]
+fn _0001() {
+  check!([0x3136000000000000, 0x000000000000007b], "+123E+123");
+}
+
+#[test]
+fn _0002() {
+  check!([0x0001ed09bead87c0, 0x378d8e62ffffffff], "+9999999999999999999999995705032703E-6176");
+}
+
+#[test]
+fn _0003() {
+  check!([0x8001ed09bead87c0, 0x378d8e63ffffffff], "-9999999999999999999999999999999999E-6176");
+}
+
+#[test]
+fn _0004() {
+  check!([0x3040000000000000, 0x0000000000000000], "+0E+0");
+}
+
+#[test]
+fn _0005() {
+  check!([0xf800000000000000, 0x0000000000000000], "-Inf");
+}
+
+#[test]
+fn _0006() {
+  check!([0xfe00000000000000, 0x0000000000000000], "-SNaN");
+}
+
+#[test]
+fn _0007() {
+  check!([0x7c00000000000000, 0x0000000000000000], "+NaN");
+}
+
+#[test]
+fn _0008() {
+  // buffer larger than required
+  let mut buf = [0; 64];
+  assert_eq!("+1E-1", bid128_to_str_buf(BidUint128 { w: [0x0000000000000001, 0x303e000000000000] }, &mut buf));
+}
+
+#[test]
+fn _0009() {
+  // writer with fixed capacity, no allocation
+  struct FixedWriter {
+    buf: [u8; BID128_MAX_STRING_LENGTH],
+    len: usize,
+  }
+  impl core::fmt::Write for FixedWriter {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+      let end = self.len + s.len();
+      self.buf.get_mut(self.len..end).ok_or(core::fmt::Error)?.copy_from_slice(s.as_bytes());
+      self.len = end;
+      Ok(())
+    }
+  }
+  let mut writer = FixedWriter { buf: [0; BID128_MAX_STRING_LENGTH], len: 0 };
+  bid128_write_string(BidUint128 { w: [0x000000000000007b, 0xb040000000000000] }, &mut writer).unwrap();
+  assert_eq!(b"-123E+0", &writer.buf[..writer.len]);
+}
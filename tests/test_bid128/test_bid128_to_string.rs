@@ -285,3 +285,23 @@ fn _0055() {
 fn _0056() {
   check!([0x302e000000000000, 0x0000000000000000], "+0E-9");
 }
+
+#[test]
+fn _0057() {
+  check!([0x7c00000000000000, 0x000000000000007b], "+NaN123");
+}
+
+#[test]
+fn _0058() {
+  check!([0xfe00000000000000, 0x00000000000001c8], "-SNaN456");
+}
+
+#[test]
+fn _0059() {
+  check!([0x7c00314dc6448d93, 0x38c15b09ffffffff], "+NaN999999999999999999999999999999999");
+}
+
+#[test]
+fn _0060() {
+  check!([0xfc00314dc6448d93, 0x38c15b0a00000000], "-NaN");
+}
@@ -0,0 +1,75 @@
+use decimus::{BidUint128, bid128_write_engineering};
+
+macro_rules! check {
+  ($x:expr, $significant_digits:expr, $rnd_mode:expr, $upper_case:expr, $expected:expr) => {
+    let x = BidUint128 { w: [$x[1], $x[0]] };
+    let mut actual = String::new();
+    bid128_write_engineering(x, $significant_digits, $rnd_mode, $upper_case, &mut actual).unwrap();
+    assert_eq!($expected, actual);
+  };
+}
+
+#[test]
+fn _0001() {
+  check!([0x3040000000000000, 0x0000000000003039], None, 0, false, "12.345e3");
+}
+
+#[test]
+fn _0002() {
+  check!([0x3040000000000000, 0x0000000000003039], Some(1), 0, false, "10e3");
+}
+
+#[test]
+fn _0003() {
+  check!([0x3040000000000000, 0x0000000000003039], Some(2), 0, false, "12e3");
+}
+
+#[test]
+fn _0004() {
+  check!([0xb032000000000000, 0x0000000000000001], None, 0, false, "-100e-9");
+}
+
+#[test]
+fn _0005() {
+  check!([0x303c000000000000, 0x000000000000007b], None, 0, false, "1.23e0");
+}
+
+#[test]
+fn _0006() {
+  check!([0x3040000000000000, 0x00000000000f423f], Some(3), 0, false, "1.00e6");
+}
+
+#[test]
+fn _0007() {
+  check!([0x3046000000000000, 0x0000000000000001], Some(5), 0, true, "1.0000E3");
+}
+
+#[test]
+fn _0008() {
+  check!([0x302e000000000000, 0x000000000001e240], Some(4), 3, false, "123.4e-6");
+}
+
+#[test]
+fn _0009() {
+  check!([0xb02e000000000000, 0x000000000001e240], Some(4), 1, false, "-123.5e-6");
+}
+
+#[test]
+fn _0010() {
+  check!([0x3036000000000000, 0x0000000000000000], None, 0, false, "0e-6");
+}
+
+#[test]
+fn _0011() {
+  check!([0x3036000000000000, 0x0000000000000000], Some(3), 0, false, "0.00e-6");
+}
+
+#[test]
+fn _0012() {
+  check!([0xf800000000000000, 0x0000000000000000], None, 0, false, "-inf");
+}
+
+#[test]
+fn _0013() {
+  check!([0x7e00000000000000, 0x0000000000000000], None, 0, false, "sNaN");
+}
@@ -0,0 +1,135 @@
+use decimus::{BidUint128, bid128_write_fixed};
+
+macro_rules! check {
+  ($x:expr, $fraction_digits:expr, $rnd_mode:expr, $expected:expr) => {
+    let x = BidUint128 { w: [$x[1], $x[0]] };
+    let mut actual = String::new();
+    bid128_write_fixed(x, $fraction_digits, $rnd_mode, &mut actual).unwrap();
+    assert_eq!($expected, actual);
+  };
+}
+
+#[test]
+fn _0001() {
+  check!([0x303c000000000000, 0x0000000000003039], None, 0, "123.45");
+}
+
+#[test]
+fn _0002() {
+  check!([0x303c000000000000, 0x0000000000003039], Some(1), 0, "123.4");
+}
+
+#[test]
+fn _0003() {
+  check!([0x303c000000000000, 0x0000000000003043], Some(1), 0, "123.6");
+}
+
+#[test]
+fn _0004() {
+  check!([0x303c000000000000, 0x0000000000003039], Some(1), 4, "123.5");
+}
+
+#[test]
+fn _0005() {
+  check!([0xb03c000000000000, 0x0000000000003035], Some(1), 1, "-123.5");
+}
+
+#[test]
+fn _0006() {
+  check!([0x303c000000000000, 0x0000000000003035], Some(1), 2, "123.5");
+}
+
+#[test]
+fn _0007() {
+  check!([0x303c000000000000, 0x000000000000303d], Some(1), 3, "123.4");
+}
+
+#[test]
+fn _0008() {
+  check!([0xb03c000000000000, 0x000000000000303d], Some(1), 2, "-123.4");
+}
+
+#[test]
+fn _0009() {
+  check!([0x303c000000000000, 0x0000000000003039], Some(4), 0, "123.4500");
+}
+
+#[test]
+fn _0010() {
+  check!([0x3046000000000000, 0x0000000000000001], None, 0, "1000");
+}
+
+#[test]
+fn _0011() {
+  check!([0x3046000000000000, 0x0000000000000001], Some(2), 0, "1000.00");
+}
+
+#[test]
+fn _0012() {
+  check!([0xb03a000000000000, 0x0000000000000005], Some(0), 0, "-0");
+}
+
+#[test]
+fn _0013() {
+  check!([0x3038000000000000, 0x000000000000270f], Some(2), 0, "1.00");
+}
+
+#[test]
+fn _0014() {
+  check!([0x303c000000000000, 0x0000000000000078], None, 0, "1.20");
+}
+
+#[test]
+fn _0015() {
+  check!([0x3036000000000000, 0x0000000000000000], None, 0, "0.00000");
+}
+
+#[test]
+fn _0016() {
+  check!([0x3046000000000000, 0x0000000000000000], None, 0, "0");
+}
+
+#[test]
+fn _0017() {
+  check!([0x3032000000000000, 0x0000000000000005], Some(3), 2, "0.001");
+}
+
+#[test]
+fn _0018() {
+  check!([0x3032000000000000, 0x0000000000000005], Some(3), 0, "0.000");
+}
+
+#[test]
+fn _0019() {
+  check!([0xaff0000000000000, 0x0000000000000001], None, 0, "-0.0000000000000000000000000000000000000001");
+}
+
+#[test]
+fn _0020() {
+  check!([0x2ffded09bead87c0, 0x378d8e63ffffffff], None, 0, "0.9999999999999999999999999999999999");
+}
+
+#[test]
+fn _0021() {
+  check!([0x7800000000000000, 0x0000000000000000], Some(2), 0, "inf");
+}
+
+#[test]
+fn _0022() {
+  check!([0xf800000000000000, 0x0000000000000000], None, 0, "-inf");
+}
+
+#[test]
+fn _0023() {
+  check!([0x7c00000000000000, 0x0000000000000000], None, 0, "NaN");
+}
+
+#[test]
+fn _0024() {
+  check!([0x7e00000000000000, 0x0000000000000000], None, 0, "sNaN");
+}
+
+#[test]
+fn _0025() {
+  check!([0x7c00000000000000, 0x000000000000007b], None, 0, "NaN123");
+}
@@ -0,0 +1,100 @@
+use decimus::{BidUint128, NumberFormat, bid128_write_localized};
+
+macro_rules! check {
+  ($x:expr, $format:expr, $fraction_digits:expr, $rnd_mode:expr, $expected:expr) => {
+    let x = BidUint128 { w: [$x[1], $x[0]] };
+    let mut actual = String::new();
+    bid128_write_localized(x, &$format, $fraction_digits, $rnd_mode, &mut actual).unwrap();
+    assert_eq!($expected, actual);
+  };
+}
+
+#[test]
+fn _0001() {
+  check!([0x303c000000000000, 0x00000000075bcd15], NumberFormat::new(',', Some('.')), None, 0, "1.234.567,89");
+}
+
+#[test]
+fn _0002() {
+  check!([0x303c000000000000, 0x00000000075bcd15], NumberFormat::new('.', Some('\'')), None, 0, "1'234'567.89");
+}
+
+#[test]
+fn _0003() {
+  check!([0xb03c000000000000, 0x00000000075bcd15], NumberFormat::new(',', Some('.')), None, 0, "-1.234.567,89");
+}
+
+#[test]
+fn _0004() {
+  check!([0x303c000000000000, 0x00000000075bcd15], NumberFormat::new(',', Some('.')), Some(1), 0, "1.234.567,9");
+}
+
+#[test]
+fn _0005() {
+  check!([0xb03c000000000000, 0x00000000075bcced], NumberFormat::new('.', Some('\'')), Some(0), 1, "-1'234'568");
+}
+
+#[test]
+fn _0006() {
+  check!([0x303c000000000000, 0x00000000075bcd15], NumberFormat::PLAIN, None, 0, "1234567.89");
+}
+
+#[test]
+fn _0007() {
+  check!([0x3046000000000000, 0x0000000000000001], NumberFormat::new(',', Some('.')), None, 0, "1.000");
+}
+
+#[test]
+fn _0008() {
+  check!([0x3040000000000000, 0x00000000000003e7], NumberFormat::new(',', Some('.')), Some(2), 0, "999,00");
+}
+
+#[test]
+fn _0009() {
+  check!([0x303c000000000000, 0x000000000001869f], NumberFormat::new(',', Some('.')), None, 0, "999,99");
+}
+
+#[test]
+fn _0010() {
+  check!([0x303a000000000000, 0x000000000098967b], NumberFormat::new('.', Some('\'')), Some(2), 0, "10'000.00");
+}
+
+#[test]
+fn _0011() {
+  check!([0x303c000000000000, 0x0000000000000000], NumberFormat::new(',', Some('.')), None, 0, "0,00");
+}
+
+#[test]
+fn _0012() {
+  check!([0xb03e000000000000, 0x0000000000000005], NumberFormat::new(',', Some('.')), Some(0), 0, "-0");
+}
+
+#[test]
+fn _0013() {
+  check!([0x3040000000000000, 0x00000000075bcd15], NumberFormat::new('.', Some('_')).with_group_size(4), None, 0, "1_2345_6789");
+}
+
+#[test]
+fn _0014() {
+  check!([0x3040000000000000, 0x00000000075bcd15], NumberFormat::new('.', Some(',')).with_group_size(0), None, 0, "123456789");
+}
+
+#[test]
+fn _0015() {
+  check!([0x303e000000000000, 0x000000000012d687], NumberFormat::new(',', Some('\u{202f}')), None, 0, "123\u{202f}456,7");
+}
+
+#[test]
+fn _0016() {
+  check!([0x7800000000000000, 0x0000000000000000], NumberFormat::new(',', Some('.')), None, 0, "inf");
+}
+
+#[test]
+fn _0017() {
+  check!([0xf800000000000000, 0x0000000000000000], NumberFormat::new(',', Some('.')), Some(2), 0, "-inf");
+}
+
+#[test]
+fn _0018() {
+  check!([0x7c00000000000000, 0x0000000000000000], NumberFormat::new('.', Some('\'')), None, 0, "NaN");
+}
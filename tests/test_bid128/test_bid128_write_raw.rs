@@ -0,0 +1,30 @@
+use decimus::{BidUint128, bid128_write_raw};
+
+macro_rules! check {
+  ($x:expr, $expected:expr) => {
+    let x = BidUint128 { w: [$x[1], $x[0]] };
+    let mut actual = String::new();
+    bid128_write_raw(x, &mut actual).unwrap();
+    assert_eq!($expected, actual);
+  };
+}
+
+#[test]
+fn _0001() {
+  check!([0x3040000000000000, 0x0000000000000001], "0x3040000000000000_0000000000000001");
+}
+
+#[test]
+fn _0002() {
+  check!([0x0000000000000000, 0x0000000000000000], "0x0000000000000000_0000000000000000");
+}
+
+#[test]
+fn _0003() {
+  check!([0xfc00000000000000, 0xabcdef0123456789], "0xfc00000000000000_abcdef0123456789");
+}
+
+#[test]
+fn _0004() {
+  check!([0x7800000000000000, 0x0000000000000000], "0x7800000000000000_0000000000000000");
+}
@@ -0,0 +1,95 @@
+use decimus::{BidUint128, bid128_write_scientific};
+
+macro_rules! check {
+  ($x:expr, $significant_digits:expr, $rnd_mode:expr, $upper_case:expr, $expected:expr) => {
+    let x = BidUint128 { w: [$x[1], $x[0]] };
+    let mut actual = String::new();
+    bid128_write_scientific(x, $significant_digits, $rnd_mode, $upper_case, &mut actual).unwrap();
+    assert_eq!($expected, actual);
+  };
+}
+
+#[test]
+fn _0001() {
+  check!([0x303c000000000000, 0x0000000000003039], None, 0, false, "1.2345e2");
+}
+
+#[test]
+fn _0002() {
+  check!([0x303c000000000000, 0x0000000000003039], Some(3), 0, false, "1.23e2");
+}
+
+#[test]
+fn _0003() {
+  check!([0x303c000000000000, 0x0000000000003039], Some(1), 0, false, "1e2");
+}
+
+#[test]
+fn _0004() {
+  check!([0x3040000000000000, 0x000000000001869f], Some(2), 0, false, "1.0e5");
+}
+
+#[test]
+fn _0005() {
+  check!([0x3032000000000000, 0x0000000000000001], None, 0, false, "1e-7");
+}
+
+#[test]
+fn _0006() {
+  check!([0xb03c000000000000, 0x0000000000003035], Some(3), 1, false, "-1.24e2");
+}
+
+#[test]
+fn _0007() {
+  check!([0x303c000000000000, 0x0000000000003035], Some(3), 3, false, "1.23e2");
+}
+
+#[test]
+fn _0008() {
+  check!([0x303c000000000000, 0x000000000000303e], Some(3), 0, false, "1.24e2");
+}
+
+#[test]
+fn _0009() {
+  check!([0x303c000000000000, 0x0000000000002fda], Some(3), 4, false, "1.23e2");
+}
+
+#[test]
+fn _0010() {
+  check!([0x303c000000000000, 0x0000000000000078], None, 0, false, "1.20e0");
+}
+
+#[test]
+fn _0011() {
+  check!([0x3040000000000000, 0x0000000000000005], Some(4), 0, false, "5.000e0");
+}
+
+#[test]
+fn _0012() {
+  check!([0x303c000000000000, 0x0000000000003039], None, 0, true, "1.2345E2");
+}
+
+#[test]
+fn _0013() {
+  check!([0xdffe314dc6448d93, 0x38c15b0a00000000], None, 0, true, "-1.000000000000000000000000000000000E6144");
+}
+
+#[test]
+fn _0014() {
+  check!([0x3036000000000000, 0x0000000000000000], None, 0, false, "0e-5");
+}
+
+#[test]
+fn _0015() {
+  check!([0x3036000000000000, 0x0000000000000000], Some(3), 0, false, "0.00e-5");
+}
+
+#[test]
+fn _0016() {
+  check!([0x7800000000000000, 0x0000000000000000], None, 0, false, "inf");
+}
+
+#[test]
+fn _0017() {
+  check!([0x7c00000000000000, 0x0000000000000000], Some(3), 0, true, "NaN");
+}
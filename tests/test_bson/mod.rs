@@ -0,0 +1,4 @@
+mod test_bid128_bson_bytes;
+mod test_bid128_from_bson_string;
+mod test_bid128_write_bson_string;
+mod test_bson_corpus;
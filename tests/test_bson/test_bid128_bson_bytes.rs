@@ -0,0 +1,15 @@
+use decimus::{BidUint128, bid128_from_bson, bid128_to_bson};
+
+const X: BidUint128 = BidUint128 { w: [0x00000000000004d2, 0x3034000000000000] };
+
+const BYTES: [u8; 16] = [0xd2, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x34, 0x30];
+
+#[test]
+fn _0001() {
+  assert_eq!(X, bid128_from_bson(BYTES));
+}
+
+#[test]
+fn _0002() {
+  assert_eq!(BYTES, bid128_to_bson(X));
+}
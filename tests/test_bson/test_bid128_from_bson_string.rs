@@ -0,0 +1,98 @@
+use decimus::{BidUint128, ParseDecimalErrorKind, bid128_from_bson_string};
+
+macro_rules! check {
+  ($x:expr, $expected:expr) => {
+    let expected = BidUint128 { w: [$expected[1], $expected[0]] };
+    assert_eq!(Ok(expected), bid128_from_bson_string($x));
+  };
+  ($x:expr, $expected_kind:expr, $expected_position:expr) => {
+    let err = bid128_from_bson_string($x).unwrap_err();
+    assert_eq!($expected_kind, err.kind());
+    assert_eq!($expected_position, err.position());
+  };
+}
+
+#[test]
+fn _0001() {
+  check!("0.001234", [0x3034000000000000, 0x00000000000004d2]);
+}
+
+#[test]
+fn _0002() {
+  check!("-1.00E-8", [0xb02c000000000000, 0x0000000000000064]);
+}
+
+#[test]
+fn _0003() {
+  check!("1E6112", [0x5ffe000000000000, 0x000000000000000a]);
+}
+
+#[test]
+fn _0004() {
+  check!("0E-8000", [0x0000000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0005() {
+  check!("10E-6177", [0x0000000000000000, 0x0000000000000001]);
+}
+
+#[test]
+fn _0006() {
+  check!("-Inf", [0xf800000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0007() {
+  check!("nan", [0x7c00000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0008() {
+  check!("", ParseDecimalErrorKind::Empty, 0);
+}
+
+#[test]
+fn _0009() {
+  check!("1.2.3", ParseDecimalErrorKind::TrailingCharacters, 3);
+}
+
+#[test]
+fn _0010() {
+  check!("1e+", ParseDecimalErrorKind::InvalidDigit, 3);
+}
+
+#[test]
+fn _0011() {
+  check!("sNaN", ParseDecimalErrorKind::InvalidDigit, 0);
+}
+
+#[test]
+fn _0012() {
+  check!("-snan", ParseDecimalErrorKind::InvalidDigit, 1);
+}
+
+#[test]
+fn _0013() {
+  check!("NaN12", ParseDecimalErrorKind::TrailingCharacters, 3);
+}
+
+#[test]
+fn _0014() {
+  check!("12345678901234567890123456789012345", ParseDecimalErrorKind::Inexact, 0);
+}
+
+#[test]
+fn _0015() {
+  check!("1E-6177", ParseDecimalErrorKind::Inexact, 0);
+}
+
+#[test]
+fn _0016() {
+  check!("1E6145", ParseDecimalErrorKind::Inexact, 0);
+}
+
+#[test]
+fn _0017() {
+  check!("1E9999999", ParseDecimalErrorKind::ExponentOverflow, 2);
+}
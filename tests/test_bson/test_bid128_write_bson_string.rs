@@ -0,0 +1,55 @@
+use decimus::{BidUint128, bid128_write_bson_string};
+
+macro_rules! check {
+  ($x:expr, $expected:expr) => {
+    let x = BidUint128 { w: [$x[1], $x[0]] };
+    let mut actual = String::new();
+    bid128_write_bson_string(x, &mut actual).unwrap();
+    assert_eq!($expected, actual);
+  };
+}
+
+#[test]
+fn _0001() {
+  check!([0x3034000000000000, 0x00000000000004d2], "0.001234");
+}
+
+#[test]
+fn _0002() {
+  check!([0x3042000000000000, 0x000000000000041a], "1.050E+4");
+}
+
+#[test]
+fn _0003() {
+  check!([0xb040000000000000, 0x0000000000000000], "-0");
+}
+
+#[test]
+fn _0004() {
+  check!([0x0000000000000000, 0x0000000000000001], "1E-6176");
+}
+
+#[test]
+fn _0005() {
+  check!([0xf800000000000000, 0x0000000000000000], "-Infinity");
+}
+
+#[test]
+fn _0006() {
+  check!([0xfc00000000000000, 0x0000000000000000], "NaN");
+}
+
+#[test]
+fn _0007() {
+  check!([0x7e00000000000000, 0x000000000000007b], "NaN");
+}
+
+#[test]
+fn _0008() {
+  check!([0x6c10000000000000, 0x0000000000000000], "0");
+}
+
+#[test]
+fn _0009() {
+  check!([0x3041ed09bead87c0, 0x378d8e6400000000], "0");
+}
@@ -0,0 +1,89 @@
+use decimus::{BidUint128, bid128_from_bson, bid128_from_bson_string, bid128_to_bson, bid128_write_bson_string};
+use serde_json::Value;
+use std::path::Path;
+
+/// Returns the Decimal128 value from the hexadecimal BSON document `{"d": Decimal128}`.
+fn document_value(hex: &str) -> [u8; 16] {
+  let bytes: Vec<u8> = (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap()).collect();
+  assert_eq!(24, bytes.len(), "invalid document {hex}");
+  bytes[7..23].try_into().unwrap()
+}
+
+/// Returns the string from the extended JSON document `{"d": {"$numberDecimal": "..."}}`.
+fn extjson_value(extjson: &str) -> String {
+  let document: Value = serde_json::from_str(extjson).unwrap();
+  document["d"]["$numberDecimal"].as_str().unwrap().to_string()
+}
+
+fn to_string(x: BidUint128) -> String {
+  let mut s = String::new();
+  bid128_write_bson_string(x, &mut s).unwrap();
+  s
+}
+
+fn parse(input: &str) -> Option<[u8; 16]> {
+  bid128_from_bson_string(input).ok().map(bid128_to_bson)
+}
+
+fn check_valid(case: &Value) {
+  let description = case["description"].as_str().unwrap();
+  let canonical_bson = document_value(case["canonical_bson"].as_str().unwrap());
+  let canonical_string = extjson_value(case["canonical_extjson"].as_str().unwrap());
+  let lossy = case["lossy"].as_bool().unwrap_or(false);
+  let x = bid128_from_bson(canonical_bson);
+  assert_eq!(canonical_bson, bid128_to_bson(x), "{description}");
+  assert_eq!(canonical_string, to_string(x), "{description}");
+  if !lossy {
+    assert_eq!(Some(canonical_bson), parse(&canonical_string), "{description}");
+  }
+  if let Some(degenerate_bson) = case["degenerate_bson"].as_str() {
+    let y = bid128_from_bson(document_value(degenerate_bson));
+    assert_eq!(canonical_string, to_string(y), "{description}");
+    if !lossy {
+      assert_eq!(Some(canonical_bson), parse(&to_string(y)), "{description}");
+    }
+  }
+  if let Some(degenerate_extjson) = case["degenerate_extjson"].as_str()
+    && !lossy
+  {
+    assert_eq!(Some(canonical_bson), parse(&extjson_value(degenerate_extjson)), "{description}");
+  }
+}
+
+fn check_parse_error(case: &Value) {
+  let description = case["description"].as_str().unwrap();
+  let input = case["string"].as_str().unwrap();
+  assert!(bid128_from_bson_string(input).is_err(), "{description}: {input}");
+}
+
+/// Checks all valid cases and parse errors of the file in the format of the BSON corpus, returns the number of cases.
+fn check_file(path: &Path) -> usize {
+  let content = std::fs::read_to_string(path).unwrap_or_else(|e| panic!("cannot read {}: {e}", path.display()));
+  let document: Value = serde_json::from_str(&content).unwrap();
+  let mut count = 0;
+  for case in document["valid"].as_array().into_iter().flatten() {
+    check_valid(case);
+    count += 1;
+  }
+  for case in document["parseErrors"].as_array().into_iter().flatten() {
+    check_parse_error(case);
+    count += 1;
+  }
+  count
+}
+
+#[test]
+fn _0001() {
+  let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/test_bson/decimal128_vectors.json");
+  assert!(check_file(&path) > 0);
+}
+
+#[test]
+fn _0002() {
+  let corpus = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/test_bson/corpus");
+  for i in 1..=7 {
+    let path = corpus.join(format!("decimal128-{i}.json"));
+    assert!(path.exists(), "missing BSON corpus file {}", path.display());
+    assert!(check_file(&path) > 0, "no cases in {}", path.display());
+  }
+}
@@ -0,0 +1,63 @@
+use decimus::BidUint128;
+
+const X: BidUint128 = BidUint128 { w: [0x0807060504030201, 0x100f0e0d0c0b0a09] };
+
+const LE: [u8; 16] = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f, 0x10];
+
+const BE: [u8; 16] = [0x10, 0x0f, 0x0e, 0x0d, 0x0c, 0x0b, 0x0a, 0x09, 0x08, 0x07, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01];
+
+#[test]
+fn _0001() {
+  assert_eq!(LE, X.to_le_bytes());
+}
+
+#[test]
+fn _0002() {
+  assert_eq!(BE, X.to_be_bytes());
+}
+
+#[test]
+fn _0003() {
+  assert_eq!(X, BidUint128::from_le_bytes(LE));
+}
+
+#[test]
+fn _0004() {
+  assert_eq!(X, BidUint128::from_be_bytes(BE));
+}
+
+#[test]
+fn _0005() {
+  assert_eq!(0x100f0e0d0c0b0a09_0807060504030201_u128, u128::from(X));
+}
+
+#[test]
+fn _0006() {
+  assert_eq!(X, BidUint128::from(0x100f0e0d0c0b0a09_0807060504030201_u128));
+}
+
+#[test]
+fn _0007() {
+  let x: u128 = X.into();
+  assert_eq!(X, x.into());
+}
+
+#[test]
+fn _0008() {
+  // Positive one, the sign and exponent are stored in the most significant bytes.
+  let x = BidUint128 { w: [0x0000000000000001, 0x3040000000000000] };
+  assert_eq!([0x30, 0x40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x01], x.to_be_bytes());
+  assert_eq!([0x01, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x40, 0x30], x.to_le_bytes());
+}
+
+#[test]
+fn _0009() {
+  const BYTES: [u8; 16] = BidUint128 { w: [0, 0x7c00000000000000] }.to_be_bytes();
+  assert_eq!(BidUint128 { w: [0, 0x7c00000000000000] }, BidUint128::from_be_bytes(BYTES));
+}
+
+#[test]
+fn _0010() {
+  assert_eq!(X.to_le_bytes(), u128::from(X).to_le_bytes());
+  assert_eq!(X.to_be_bytes(), u128::from(X).to_be_bytes());
+}
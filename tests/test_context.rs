@@ -0,0 +1,78 @@
+use decimus::*;
+
+#[test]
+fn _0001() {
+  let ctx = Context::default();
+  assert_eq!(BID_ROUNDING_TO_NEAREST, ctx.rounding());
+  assert_eq!(BID_TININESS_DEFAULT, ctx.tininess());
+  assert_eq!(BID_EXACT_STATUS, ctx.flags());
+  assert_eq!(BID_NO_EXCEPTION, ctx.traps());
+}
+
+#[test]
+fn _0002() {
+  let mut ctx = Context::new(BID_ROUNDING_UP);
+  ctx.set_tininess(BID_TININESS_AFTER_ROUNDING);
+  ctx.set_traps(BID_INVALID_EXCEPTION | BID_ZERO_DIVIDE_EXCEPTION);
+  assert_eq!(BID_ROUNDING_UP, ctx.rounding());
+  assert_eq!(BID_TININESS_AFTER_ROUNDING, ctx.tininess());
+  assert_eq!(BID_INVALID_EXCEPTION | BID_ZERO_DIVIDE_EXCEPTION, ctx.traps());
+  ctx.set_rounding(BID_ROUNDING_TO_ZERO);
+  assert_eq!(BID_ROUNDING_TO_ZERO, ctx.rounding());
+}
+
+#[test]
+fn _0003() {
+  let mut ctx = Context::default();
+  let x = ctx.from_string("12.5");
+  let y = ctx.from_string("-0.25");
+  assert_eq!(ctx.from_string("12.25"), ctx.add(x, y));
+  assert_eq!(ctx.from_string("12.75"), ctx.sub(x, y));
+  assert_eq!(ctx.from_string("-3.125"), ctx.mul(x, y));
+  assert_eq!(ctx.from_string("-5E+1"), ctx.div(x, y));
+  assert_eq!(ctx.from_string("9.375"), ctx.fma(x, y, x));
+  assert_eq!(BID_EXACT_STATUS, ctx.flags());
+}
+
+#[test]
+fn _0004() {
+  let mut ctx = Context::new(BID_ROUNDING_DOWN);
+  let x = ctx.from_string("2");
+  let y = ctx.from_string("-3");
+  let mut flags: IdecFlags = 0;
+  assert_eq!(bid128_div(x, y, BID_ROUNDING_DOWN, &mut flags), ctx.div(x, y));
+  assert_eq!(flags, ctx.flags());
+  ctx.set_rounding(BID_ROUNDING_UP);
+  let mut flags: IdecFlags = 0;
+  assert_eq!(bid128_div(x, y, BID_ROUNDING_UP, &mut flags), ctx.div(x, y));
+  assert_ne!(bid128_div(x, y, BID_ROUNDING_DOWN, &mut flags), ctx.div(x, y));
+}
+
+#[test]
+fn _0005() {
+  let mut ctx = Context::default();
+  let x = ctx.from_string("1.000000000000000000000000000000001");
+  ctx.mul(x, x);
+  assert_eq!(BID_INEXACT_EXCEPTION, ctx.flags());
+  ctx.add(x, x);
+  assert_eq!(BID_INEXACT_EXCEPTION, ctx.flags());
+  ctx.clear_flags();
+  assert_eq!(BID_EXACT_STATUS, ctx.flags());
+  ctx.set_flags(BID_OVERFLOW_EXCEPTION);
+  ctx.mul(x, x);
+  assert_eq!(BID_OVERFLOW_EXCEPTION | BID_INEXACT_EXCEPTION, ctx.flags());
+}
+
+#[test]
+fn _0006() {
+  let mut ctx = Context::default();
+  let x = ctx.from_string("3162277660168379331997787088865436E-6176");
+  let y = ctx.from_string("3162277660168379332000000000000001E-34");
+  ctx.set_tininess(BID_TININESS_BEFORE_ROUNDING);
+  assert_eq!(BidUint128 { w: [0x38c15b0a00000000, 0x0000314dc6448d93] }, ctx.mul(x, y));
+  assert_eq!(BID_UNDERFLOW_INEXACT_EXCEPTION, ctx.flags());
+  ctx.clear_flags();
+  ctx.set_tininess(BID_TININESS_AFTER_ROUNDING);
+  assert_eq!(BidUint128 { w: [0x38c15b0a00000000, 0x0000314dc6448d93] }, ctx.mul(x, y));
+  assert_eq!(BID_INEXACT_EXCEPTION, ctx.flags());
+}
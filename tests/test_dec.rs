@@ -0,0 +1,90 @@
+use decimus::{BID_ROUNDING_TO_NEAREST, BidUint128, bid128_from_literal, bid128_from_string, dec};
+
+macro_rules! check {
+  ($x:expr, $s:expr) => {
+    let mut flags = 0;
+    assert_eq!(bid128_from_string($s, BID_ROUNDING_TO_NEAREST, &mut flags), $x);
+  };
+}
+
+const PRICE: BidUint128 = dec!(123.45);
+
+#[test]
+fn _0001() {
+  assert_eq!(BidUint128 { w: [12345, 0x303c000000000000] }, PRICE);
+}
+
+#[test]
+fn _0002() {
+  check!(dec!(0), "0");
+  check!(dec!(-0), "-0");
+  check!(dec!(1), "1");
+  check!(dec!(-7.50), "-7.50");
+  check!(dec!(0.0025), "0.0025");
+  check!(dec!(1e-300), "1e-300");
+  check!(dec!(-1E+300), "-1E+300");
+  check!(dec!(9999999999999999999999999999999999), "9999999999999999999999999999999999");
+  check!(dec!(9999999999999999999999999999999999E6111), "9999999999999999999999999999999999E6111");
+  check!(dec!(1E-6176), "1E-6176");
+  check!(dec!(1E6144), "1E6144");
+  check!(dec!(0E9999), "0E9999");
+}
+
+#[test]
+fn _0003() {
+  check!(dec!("0.0025"), "0.0025");
+  check!(dec!("-1e-300"), "-1e-300");
+  check!(dec!(-"1e-300"), "-1e-300");
+  check!(dec!("+12.5"), "+12.5");
+  check!(dec!("Inf"), "Inf");
+  check!(dec!(-"Infinity"), "-Infinity");
+  check!(dec!("NaN"), "NaN");
+  check!(dec!("-sNaN123"), "-sNaN123");
+}
+
+#[test]
+fn _0004() {
+  check!(dec!(0.12345678901234567890123456789012345, inexact), "0.12345678901234567890123456789012345");
+  check!(dec!(0.12345678901234567890123456789012355, inexact), "0.12345678901234567890123456789012355");
+  check!(dec!(-99999999999999999999999999999999995, inexact), "-99999999999999999999999999999999995");
+  check!(dec!(1E-6177, inexact), "1E-6177");
+  check!(dec!(6E-6177, inexact), "6E-6177");
+  check!(dec!("1E-99999", inexact), "1E-99999");
+  check!(dec!(1.5, inexact), "1.5");
+}
+
+#[test]
+#[should_panic(expected = "inexact decimal literal")]
+fn _0005() {
+  bid128_from_literal("0.12345678901234567890123456789012345", false, false);
+}
+
+#[test]
+#[should_panic(expected = "inexact decimal literal")]
+fn _0006() {
+  bid128_from_literal("1E-6177", false, false);
+}
+
+#[test]
+#[should_panic(expected = "invalid decimal literal")]
+fn _0007() {
+  bid128_from_literal("\"-1\"", true, false);
+}
+
+#[test]
+#[should_panic(expected = "invalid character")]
+fn _0008() {
+  bid128_from_literal("1_000", false, false);
+}
+
+#[test]
+#[should_panic(expected = "invalid character")]
+fn _0009() {
+  bid128_from_literal("1.5f64", false, false);
+}
+
+#[test]
+#[should_panic(expected = "decimal literal out of range")]
+fn _0010() {
+  bid128_from_literal("1E6145", false, true);
+}
@@ -0,0 +1,123 @@
+use decimus::BidUint128;
+
+macro_rules! check {
+  ($format:literal, $x:expr, $expected:expr) => {
+    let x = BidUint128 { w: [$x[1], $x[0]] };
+    assert_eq!($expected, format!($format, x));
+  };
+}
+
+#[test]
+fn _0001() {
+  check!("{}", [0x303c000000000000, 0x0000000000003039], "123.45");
+}
+
+#[test]
+fn _0002() {
+  check!("{:.1}", [0x303c000000000000, 0x0000000000003039], "123.4");
+}
+
+#[test]
+fn _0003() {
+  check!("{:.0}", [0xb03e000000000000, 0x0000000000000005], "-0");
+}
+
+#[test]
+fn _0004() {
+  check!("{:+}", [0x303c000000000000, 0x0000000000003039], "+123.45");
+}
+
+#[test]
+fn _0005() {
+  check!("{:10}", [0x303c000000000000, 0x0000000000003039], "    123.45");
+}
+
+#[test]
+fn _0006() {
+  check!("{:<10}", [0x303c000000000000, 0x0000000000003039], "123.45    ");
+}
+
+#[test]
+fn _0007() {
+  check!("{:^10}", [0xb03c000000000000, 0x0000000000003039], " -123.45  ");
+}
+
+#[test]
+fn _0008() {
+  check!("{:*>10.1}", [0x303c000000000000, 0x0000000000003039], "*****123.4");
+}
+
+#[test]
+fn _0009() {
+  check!("{:010.3}", [0xb03c000000000000, 0x0000000000003039], "-00123.450");
+}
+
+#[test]
+fn _0010() {
+  check!("{:+08}", [0x303c000000000000, 0x0000000000003039], "+0123.45");
+}
+
+#[test]
+fn _0011() {
+  check!("{:e}", [0x303c000000000000, 0x0000000000003039], "1.2345e2");
+}
+
+#[test]
+fn _0012() {
+  check!("{:.2e}", [0x303c000000000000, 0x0000000000003039], "1.23e2");
+}
+
+#[test]
+fn _0013() {
+  check!("{:+.0e}", [0xb03c000000000000, 0x0000000000003039], "-1e2");
+}
+
+#[test]
+fn _0014() {
+  check!("{:E}", [0xb032000000000000, 0x0000000000000001], "-1E-7");
+}
+
+#[test]
+fn _0015() {
+  check!("{:012.3E}", [0x303c000000000000, 0x0000000000003039], "000001.234E2");
+}
+
+#[test]
+fn _0016() {
+  check!("{:>6}", [0x7800000000000000, 0x0000000000000000], "   inf");
+}
+
+#[test]
+fn _0017() {
+  check!("{:06}", [0xf800000000000000, 0x0000000000000000], "  -inf");
+}
+
+#[test]
+fn _0018() {
+  check!("{:+}", [0x7c00000000000000, 0x0000000000000000], "NaN");
+}
+
+#[test]
+fn _0019() {
+  check!("{:<6.2}", [0x7e00000000000000, 0x0000000000000000], "sNaN  ");
+}
+
+#[test]
+fn _0020() {
+  check!("{}", [0x3046000000000000, 0x0000000000000000], "0");
+}
+
+#[test]
+fn _0021() {
+  check!("{:e}", [0xb036000000000000, 0x0000000000000000], "-0e-5");
+}
+
+#[test]
+fn _0022() {
+  check!("{}", [0x7c00000000000000, 0x000000000000007b], "NaN123");
+}
+
+#[test]
+fn _0023() {
+  check!("{:>8}", [0xfe00000000000000, 0x000000000000002d], "  sNaN45");
+}
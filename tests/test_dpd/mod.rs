@@ -0,0 +1,6 @@
+mod test_bid128_to_dpd128;
+mod test_bid32_to_dpd32;
+mod test_bid64_to_dpd64;
+mod test_bid_dpd_to_bid128;
+mod test_bid_dpd_to_bid32;
+mod test_bid_dpd_to_bid64;
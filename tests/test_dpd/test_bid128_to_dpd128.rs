@@ -0,0 +1,84 @@
+use decimus::{BidUint128, bid128_to_dpd128};
+
+macro_rules! check {
+  ($x:expr, $expected:expr) => {
+    let x = BidUint128 { w: [$x[1], $x[0]] };
+    let expected = BidUint128 { w: [$expected[1], $expected[0]] };
+    assert_eq!(expected, bid128_to_dpd128(x));
+  };
+}
+
+#[test]
+fn _0001() {
+  check!([0x3040000000000000, 0x0000000000000001], [0x2208000000000000, 0x0000000000000001]);
+}
+
+#[test]
+fn _0002() {
+  check!([0xb03c000000000000, 0x00000000000002ee], [0xa207800000000000, 0x00000000000003d0]);
+}
+
+#[test]
+fn _0003() {
+  check!([0x3040000000000000, 0x0000000000000000], [0x2208000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0004() {
+  check!([0x8000000000000000, 0x0000000000000000], [0x8000000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0005() {
+  check!([0x5fffed09bead87c0, 0x378d8e63ffffffff], [0x77ffcff3fcff3fcf, 0xf3fcff3fcff3fcff]);
+}
+
+#[test]
+fn _0006() {
+  check!([0x0000000000000000, 0x0000000000000001], [0x0000000000000000, 0x0000000000000001]);
+}
+
+#[test]
+fn _0007() {
+  check!([0x30418a6e32246c99, 0xc60ad8500000007b], [0x6a08000000000000, 0x00000000000000a3]);
+}
+
+#[test]
+fn _0008() {
+  check!([0x3041ed09bead87c0, 0x378d8e63ffffffff], [0x6e080ff3fcff3fcf, 0xf3fcff3fcff3fcff]);
+}
+
+#[test]
+fn _0009() {
+  check!([0x3041ed09bead87c0, 0x378d8e6400000000], [0x2208000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0010() {
+  check!([0x7800000000000000, 0x0000000000000000], [0x7800000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0011() {
+  check!([0xf800000000000000, 0x0000000000003039], [0xf800000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0012() {
+  check!([0x7c00000000000000, 0x000000000000007b], [0x7c00000000000000, 0x00000000000000a3]);
+}
+
+#[test]
+fn _0013() {
+  check!([0xfe00000000000000, 0x00000000000001c8], [0xfe00000000000000, 0x0000000000000256]);
+}
+
+#[test]
+fn _0014() {
+  check!([0x7c00314dc6448d93, 0x38c15b0a00000000], [0x7c00000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0015() {
+  check!([0x6800000000000000, 0x0000000000000005], [0x2000000000000000, 0x0000000000000000]);
+}
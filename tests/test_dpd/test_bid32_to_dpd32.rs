@@ -0,0 +1,77 @@
+use decimus::bid32_to_dpd32;
+
+macro_rules! check {
+  ($x:expr, $expected:expr) => {
+    assert_eq!($expected, bid32_to_dpd32($x));
+  };
+}
+
+#[test]
+fn _0001() {
+  check!(0x32800001, 0x22500001);
+}
+
+#[test]
+fn _0002() {
+  check!(0xb18002ee, 0xa23003d0);
+}
+
+#[test]
+fn _0003() {
+  check!(0x32800000, 0x22500000);
+}
+
+#[test]
+fn _0004() {
+  check!(0x80000000, 0x80000000);
+}
+
+#[test]
+fn _0005() {
+  check!(0x77f8967f, 0x77f3fcff);
+}
+
+#[test]
+fn _0006() {
+  check!(0x00000001, 0x00000001);
+}
+
+#[test]
+fn _0007() {
+  check!(0x32fa127b, 0x6a5000a3);
+}
+
+#[test]
+fn _0008() {
+  check!(0x6cb8967f, 0x6e53fcff);
+}
+
+#[test]
+fn _0009() {
+  check!(0x6cb89680, 0x22500000);
+}
+
+#[test]
+fn _0010() {
+  check!(0x78000000, 0x78000000);
+}
+
+#[test]
+fn _0011() {
+  check!(0xf8003039, 0xf8000000);
+}
+
+#[test]
+fn _0012() {
+  check!(0x7c00007b, 0x7c0000a3);
+}
+
+#[test]
+fn _0013() {
+  check!(0xfe0001c8, 0xfe000256);
+}
+
+#[test]
+fn _0014() {
+  check!(0x7c0f4240, 0x7c000000);
+}
@@ -0,0 +1,77 @@
+use decimus::bid64_to_dpd64;
+
+macro_rules! check {
+  ($x:expr, $expected:expr) => {
+    assert_eq!($expected, bid64_to_dpd64($x));
+  };
+}
+
+#[test]
+fn _0001() {
+  check!(0x31c0000000000001, 0x2238000000000001);
+}
+
+#[test]
+fn _0002() {
+  check!(0xb1800000000002ee, 0xa2300000000003d0);
+}
+
+#[test]
+fn _0003() {
+  check!(0x31c0000000000000, 0x2238000000000000);
+}
+
+#[test]
+fn _0004() {
+  check!(0x8000000000000000, 0x8000000000000000);
+}
+
+#[test]
+fn _0005() {
+  check!(0x77fb86f26fc0ffff, 0x77fcff3fcff3fcff);
+}
+
+#[test]
+fn _0006() {
+  check!(0x0000000000000001, 0x0000000000000001);
+}
+
+#[test]
+fn _0007() {
+  check!(0x31dc6bf52634007b, 0x6a380000000000a3);
+}
+
+#[test]
+fn _0008() {
+  check!(0x6c7386f26fc0ffff, 0x6e38ff3fcff3fcff);
+}
+
+#[test]
+fn _0009() {
+  check!(0x6c7386f26fc10000, 0x2238000000000000);
+}
+
+#[test]
+fn _0010() {
+  check!(0x7800000000000000, 0x7800000000000000);
+}
+
+#[test]
+fn _0011() {
+  check!(0xf800000000003039, 0xf800000000000000);
+}
+
+#[test]
+fn _0012() {
+  check!(0x7c0000000000007b, 0x7c000000000000a3);
+}
+
+#[test]
+fn _0013() {
+  check!(0xfe000000000001c8, 0xfe00000000000256);
+}
+
+#[test]
+fn _0014() {
+  check!(0x7c038d7ea4c68000, 0x7c00000000000000);
+}
@@ -0,0 +1,74 @@
+use decimus::{BidUint128, bid_dpd_to_bid128};
+
+macro_rules! check {
+  ($x:expr, $expected:expr) => {
+    let x = BidUint128 { w: [$x[1], $x[0]] };
+    let expected = BidUint128 { w: [$expected[1], $expected[0]] };
+    assert_eq!(expected, bid_dpd_to_bid128(x));
+  };
+}
+
+#[test]
+fn _0001() {
+  check!([0x2208000000000000, 0x0000000000000001], [0x3040000000000000, 0x0000000000000001]);
+}
+
+#[test]
+fn _0002() {
+  check!([0xa207800000000000, 0x00000000000003d0], [0xb03c000000000000, 0x00000000000002ee]);
+}
+
+#[test]
+fn _0003() {
+  check!([0x2208000000000000, 0x0000000000000000], [0x3040000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0004() {
+  check!([0x8000000000000000, 0x0000000000000000], [0x8000000000000000, 0x0000000000000000]);
+}
+
+#[test]
+fn _0005() {
+  check!([0x77ffcff3fcff3fcf, 0xf3fcff3fcff3fcff], [0x5fffed09bead87c0, 0x378d8e63ffffffff]);
+}
+
+#[test]
+fn _0006() {
+  check!([0x0000000000000000, 0x0000000000000001], [0x0000000000000000, 0x0000000000000001]);
+}
+
+#[test]
+fn _0007() {
+  check!([0x6a08000000000000, 0x00000000000000a3], [0x30418a6e32246c99, 0xc60ad8500000007b]);
+}
+
+#[test]
+fn _0008() {
+  check!([0x6e080ff3fcff3fcf, 0xf3fcff3fcff3fcff], [0x3041ed09bead87c0, 0x378d8e63ffffffff]);
+}
+
+#[test]
+fn _0009() {
+  check!([0x7c00400000000000, 0x00000000000000ff], [0x7c00000000000000, 0x00000000000003e7]);
+}
+
+#[test]
+fn _0010() {
+  check!([0x2208000000000000, 0x0000000000000301], [0x3040000000000000, 0x0000000000000259]);
+}
+
+#[test]
+fn _0011() {
+  check!([0x2000000000000000, 0x00000000000003ff], [0x2000000000000000, 0x00000000000003e7]);
+}
+
+#[test]
+fn _0012() {
+  check!([0x2000000000000000, 0x000000000005faff], [0x2000000000000000, 0x00000000000db7b7]);
+}
+
+#[test]
+fn _0013() {
+  check!([0x7800400000000000, 0x0000000000000abc], [0x7800000000000000, 0x0000000000000000]);
+}
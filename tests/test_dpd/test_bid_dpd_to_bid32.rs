@@ -0,0 +1,72 @@
+use decimus::bid_dpd_to_bid32;
+
+macro_rules! check {
+  ($x:expr, $expected:expr) => {
+    assert_eq!($expected, bid_dpd_to_bid32($x));
+  };
+}
+
+#[test]
+fn _0001() {
+  check!(0x22500001, 0x32800001);
+}
+
+#[test]
+fn _0002() {
+  check!(0xa23003d0, 0xb18002ee);
+}
+
+#[test]
+fn _0003() {
+  check!(0x22500000, 0x32800000);
+}
+
+#[test]
+fn _0004() {
+  check!(0x80000000, 0x80000000);
+}
+
+#[test]
+fn _0005() {
+  check!(0x77f3fcff, 0x77f8967f);
+}
+
+#[test]
+fn _0006() {
+  check!(0x00000001, 0x00000001);
+}
+
+#[test]
+fn _0007() {
+  check!(0x6a5000a3, 0x32fa127b);
+}
+
+#[test]
+fn _0008() {
+  check!(0x6e53fcff, 0x6cb8967f);
+}
+
+#[test]
+fn _0009() {
+  check!(0x7c1000ff, 0x7c0003e7);
+}
+
+#[test]
+fn _0010() {
+  check!(0x22500301, 0x32800259);
+}
+
+#[test]
+fn _0011() {
+  check!(0x200003ff, 0x200003e7);
+}
+
+#[test]
+fn _0012() {
+  check!(0x2005faff, 0x200db7b7);
+}
+
+#[test]
+fn _0013() {
+  check!(0x78100abc, 0x78000000);
+}
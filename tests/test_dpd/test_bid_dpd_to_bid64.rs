@@ -0,0 +1,72 @@
+use decimus::bid_dpd_to_bid64;
+
+macro_rules! check {
+  ($x:expr, $expected:expr) => {
+    assert_eq!($expected, bid_dpd_to_bid64($x));
+  };
+}
+
+#[test]
+fn _0001() {
+  check!(0x2238000000000001, 0x31c0000000000001);
+}
+
+#[test]
+fn _0002() {
+  check!(0xa2300000000003d0, 0xb1800000000002ee);
+}
+
+#[test]
+fn _0003() {
+  check!(0x2238000000000000, 0x31c0000000000000);
+}
+
+#[test]
+fn _0004() {
+  check!(0x8000000000000000, 0x8000000000000000);
+}
+
+#[test]
+fn _0005() {
+  check!(0x77fcff3fcff3fcff, 0x77fb86f26fc0ffff);
+}
+
+#[test]
+fn _0006() {
+  check!(0x0000000000000001, 0x0000000000000001);
+}
+
+#[test]
+fn _0007() {
+  check!(0x6a380000000000a3, 0x31dc6bf52634007b);
+}
+
+#[test]
+fn _0008() {
+  check!(0x6e38ff3fcff3fcff, 0x6c7386f26fc0ffff);
+}
+
+#[test]
+fn _0009() {
+  check!(0x7c040000000000ff, 0x7c000000000003e7);
+}
+
+#[test]
+fn _0010() {
+  check!(0x2238000000000301, 0x31c0000000000259);
+}
+
+#[test]
+fn _0011() {
+  check!(0x20000000000003ff, 0x20000000000003e7);
+}
+
+#[test]
+fn _0012() {
+  check!(0x200000000005faff, 0x20000000000db7b7);
+}
+
+#[test]
+fn _0013() {
+  check!(0x7804000000000abc, 0x7800000000000000);
+}
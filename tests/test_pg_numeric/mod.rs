@@ -0,0 +1,10 @@
+use super::*;
+
+mod test_bid128_from_pg_numeric;
+mod test_bid128_to_pg_numeric;
+mod test_pg_numeric_fixtures;
+
+/// Returns bytes from the hexadecimal string, like written by PostgreSQL `encode(numeric_send(x), 'hex')`.
+fn bytes(hex: &str) -> Vec<u8> {
+  (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap()).collect()
+}
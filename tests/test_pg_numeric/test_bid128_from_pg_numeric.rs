@@ -0,0 +1,152 @@
+use super::*;
+use decimus::{BidUint128, IdecFlags, PgNumericError, bid128_from_pg_numeric};
+
+macro_rules! check {
+  ($rnd_mode:expr, $bytes:expr, $expected:expr, $expected_flags:expr) => {
+    let mut actual_flags: IdecFlags = 0;
+    assert_eq!(Ok(BidUint128 { w: [$expected[1], $expected[0]] }), bid128_from_pg_numeric(&bytes($bytes), $rnd_mode, &mut actual_flags));
+    assert_eq!($expected_flags, actual_flags, "Result flags error, expected = 0x{:02X}, actual = 0x{:02X}", $expected_flags, actual_flags);
+  };
+}
+
+macro_rules! check_err {
+  ($bytes:expr, $expected:expr) => {
+    let mut actual_flags: IdecFlags = 0;
+    assert_eq!(Err($expected), bid128_from_pg_numeric(&bytes($bytes), 0, &mut actual_flags));
+    assert_eq!(F_00_00, actual_flags);
+  };
+}
+
+#[test]
+fn _0001() {
+  // 9999999999999999999999999999999999e6111
+  check!(0, "000a0600000000000009270f270f270f270f270f270f270f270f2328", [0x5fffed09bead87c0, 0x378d8e63ffffffff], F_00_00);
+}
+
+#[test]
+fn _0002() {
+  // -1e6144
+  check!(0, "00010600400000000001", [0xdffe314dc6448d93, 0x38c15b0a00000000], F_00_00);
+}
+
+#[test]
+fn _0003() {
+  // 1e-6176
+  check!(0, "0001f9f8000018200001", [0x0000000000000000, 0x0000000000000001], F_00_00);
+}
+
+#[test]
+fn _0004() {
+  // 1.000000000000000000000000000000000e-6143
+  check!(0, "0001fa0000001820000a", [0x0000314dc6448d93, 0x38c15b0a00000000], F_00_00);
+}
+
+#[test]
+fn _0005() {
+  // -5e-6176
+  check!(0, "0001f9f8400018200005", [0x8000000000000000, 0x0000000000000005], F_00_00);
+}
+
+#[test]
+fn _0006() {
+  // 1e-6177
+  check!(0, "0001f9f70000182103e8", [0x0000000000000000, 0x0000000000000000], F_30_00);
+}
+
+#[test]
+fn _0007() {
+  // 5e-6177
+  check!(0, "0001f9f7000018211388", [0x0000000000000000, 0x0000000000000000], F_30_00);
+}
+
+#[test]
+fn _0008() {
+  // 5e-6177
+  check!(4, "0001f9f7000018211388", [0x0000000000000000, 0x0000000000000001], F_30_00);
+}
+
+#[test]
+fn _0009() {
+  // 6e-6177
+  check!(0, "0001f9f7000018211770", [0x0000000000000000, 0x0000000000000001], F_30_00);
+}
+
+#[test]
+fn _0010() {
+  // -1.5e-6176
+  check!(0, "0002f9f84000182100011388", [0x8000000000000000, 0x0000000000000002], F_30_00);
+}
+
+#[test]
+fn _0011() {
+  // 1e6145
+  check!(0, "0001060000000000000a", [0x7800000000000000, 0x0000000000000000], F_28_00);
+}
+
+#[test]
+fn _0012() {
+  // 1e6145
+  check!(3, "0001060000000000000a", [0x5fffed09bead87c0, 0x378d8e63ffffffff], F_28_00);
+}
+
+#[test]
+fn _0013() {
+  // -1e6145
+  check!(1, "0001060040000000000a", [0xf800000000000000, 0x0000000000000000], F_28_00);
+}
+
+#[test]
+fn _0014() {
+  // 9.9999999999999999999999999999999995e6144
+  check!(0, "000a0600000000000009270f270f270f270f270f270f270f270f251c", [0x7800000000000000, 0x0000000000000000], F_28_00);
+}
+
+#[test]
+fn _0015() {
+  // 9.9999999999999999999999999999999995e6144
+  check!(3, "000a0600000000000009270f270f270f270f270f270f270f270f251c", [0x5fffed09bead87c0, 0x378d8e63ffffffff], F_20_00);
+}
+
+#[test]
+fn _0016() {
+  // 1 with leading and trailing zero digits, accepted by PostgreSQL
+  check!(0, "0003000100000000000000010000", [0x3040000000000000, 0x0000000000000001], F_00_00);
+}
+
+#[test]
+fn _0017() {
+  // NaN, Infinity and -Infinity ignore the display scale
+  check!(0, "00000000c0000000", [0x7c00000000000000, 0x0000000000000000], F_00_00);
+  check!(0, "00000000d0000020", [0x7800000000000000, 0x0000000000000000], F_00_00);
+  check!(0, "00000000f0000000", [0xf800000000000000, 0x0000000000000000], F_00_00);
+}
+
+#[test]
+fn _0018() {
+  check_err!("", PgNumericError::InvalidLength);
+  check_err!("00000000000000", PgNumericError::InvalidLength);
+  check_err!("000100000000000000", PgNumericError::InvalidLength);
+  check_err!("0000000000000000000a", PgNumericError::InvalidLength);
+  check_err!("ffff0000000000000000", PgNumericError::InvalidLength);
+}
+
+#[test]
+fn _0019() {
+  check_err!("0000000080000000", PgNumericError::InvalidSign);
+  check_err!("00000000c0010000", PgNumericError::InvalidSign);
+}
+
+#[test]
+fn _0020() {
+  check_err!("0000000000004000", PgNumericError::InvalidScale);
+}
+
+#[test]
+fn _0021() {
+  check_err!("000200000000000000012710", PgNumericError::InvalidDigit);
+}
+
+#[test]
+fn _0022() {
+  assert_eq!("invalid digit in external numeric value", PgNumericError::InvalidDigit.to_string());
+}
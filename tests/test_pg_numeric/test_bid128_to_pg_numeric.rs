@@ -0,0 +1,73 @@
+use super::*;
+use decimus::{BID128_MAX_PG_NUMERIC_LENGTH, BidUint128, bid128_to_pg_numeric};
+
+macro_rules! check {
+  ($x:expr, $expected:expr) => {
+    let mut buf = [0; BID128_MAX_PG_NUMERIC_LENGTH];
+    assert_eq!(bytes($expected), bid128_to_pg_numeric(BidUint128 { w: [$x[1], $x[0]] }, &mut buf));
+  };
+}
+
+#[test]
+fn _0001() {
+  // 1.50
+  check!([0x303c000000000000, 0x0000000000000096], "000200000000000200011388");
+}
+
+#[test]
+fn _0002() {
+  // 1E+3
+  check!([0x3046000000000000, 0x0000000000000001], "000100000000000003e8");
+}
+
+#[test]
+fn _0003() {
+  // 9999999999999999999999999999999999E+6111
+  check!([0x5fffed09bead87c0, 0x378d8e63ffffffff], "000a0600000000000009270f270f270f270f270f270f270f270f2328");
+}
+
+#[test]
+fn _0004() {
+  // -1E-6176
+  check!([0x8000000000000000, 0x0000000000000001], "0001f9f8400018200001");
+}
+
+#[test]
+fn _0005() {
+  // -1.5E-6174
+  check!([0x8002000000000000, 0x000000000000000f], "0001f9f84000181f0096");
+}
+
+#[test]
+fn _0006() {
+  // -0 and -0.00
+  check!([0xb040000000000000, 0x0000000000000000], "0000000000000000");
+  check!([0xb03c000000000000, 0x0000000000000000], "0000000000000002");
+}
+
+#[test]
+fn _0007() {
+  // NaN, -NaN and sNaN with payload
+  check!([0x7c00000000000000, 0x0000000000000000], "00000000c0000000");
+  check!([0xfc00000000000000, 0x0000000000000000], "00000000c0000000");
+  check!([0x7e00000000000000, 0x000000000000007b], "00000000c0000000");
+}
+
+#[test]
+fn _0008() {
+  // Infinity and -Infinity
+  check!([0x7800000000000000, 0x0000000000000000], "00000000d0000020");
+  check!([0xf800000000000000, 0x0000000000000000], "00000000f0000020");
+}
+
+#[test]
+fn _0009() {
+  // non-canonical coefficient 10^34
+  check!([0x3041ed09bead87c0, 0x378d8e6400000000], "0000000000000000");
+}
+
+#[test]
+fn _0010() {
+  // 123456789.000123
+  check!([0x3034000000000000, 0x00007048860dafbb], "0005000200000006000109291a85000108fc");
+}
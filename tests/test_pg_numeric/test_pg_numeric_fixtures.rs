@@ -0,0 +1,34 @@
+use super::*;
+use decimus::{BID_ROUNDING_DOWN, BID_ROUNDING_UP, BID128_MAX_PG_NUMERIC_LENGTH, IdecFlags, bid128_from_pg_numeric, bid128_from_string, bid128_is_finite, bid128_to_parts, bid128_to_pg_numeric};
+
+/// Values and their binary format captured from PostgreSQL, `x::text || ',' || encode(numeric_send(x), 'hex')`.
+const FIXTURES: &str = include_str!("fixtures/numeric_send.csv");
+
+#[test]
+fn _0001() {
+  let mut count = 0;
+  for line in FIXTURES.lines() {
+    let (text, hex) = line.split_once(',').unwrap();
+    let input = bytes(hex);
+    for rnd_mode in 0..5 {
+      let mut expected_flags: IdecFlags = 0;
+      let mut actual_flags: IdecFlags = 0;
+      let expected = bid128_from_string(text, rnd_mode, &mut expected_flags);
+      assert_eq!(Ok(expected), bid128_from_pg_numeric(&input, rnd_mode, &mut actual_flags), "{text} {rnd_mode}");
+      assert_eq!(expected_flags, actual_flags, "{text} {rnd_mode}");
+    }
+    // Only values represented exactly, with exponent equal to the negated display scale,
+    // are converted back to the same bytes.
+    let mut flags: IdecFlags = 0;
+    let x = bid128_from_pg_numeric(&input, BID_ROUNDING_DOWN, &mut flags).unwrap();
+    let exact = x == bid128_from_pg_numeric(&input, BID_ROUNDING_UP, &mut flags).unwrap();
+    let dscale = u16::from_be_bytes([input[6], input[7]]) as i32;
+    let (_, _, exponent) = bid128_to_parts(x);
+    if exact && (!bid128_is_finite(x) || exponent == -dscale || (dscale == 0 && exponent > 0)) {
+      let mut buf = [0; BID128_MAX_PG_NUMERIC_LENGTH];
+      assert_eq!(input, bid128_to_pg_numeric(x, &mut buf), "{text}");
+      count += 1;
+    }
+  }
+  assert!(count > 0);
+}
@@ -0,0 +1,108 @@
+#![cfg(feature = "serde")]
+
+use decimus::{BidUint128, dec};
+use serde::{Deserialize, Serialize};
+use serde_test::{Token, assert_de_tokens, assert_de_tokens_error, assert_tokens};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Default {
+  value: BidUint128,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Bytes {
+  #[serde(with = "decimus::bid128_serde::bytes")]
+  value: BidUint128,
+}
+
+#[cfg(feature = "serde-json-number")]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Number {
+  #[serde(with = "decimus::bid128_serde::number")]
+  value: BidUint128,
+}
+
+macro_rules! check_json {
+  ($t:ident, $x:expr, $json:expr) => {
+    let value = $t { value: $x };
+    assert_eq!($json, serde_json::to_string(&value).unwrap());
+    assert_eq!(value, serde_json::from_str::<$t>($json).unwrap());
+  };
+}
+
+macro_rules! check_json_error {
+  ($t:ident, $json:expr, $message:expr) => {
+    assert_eq!($message, serde_json::from_str::<$t>($json).unwrap_err().to_string());
+  };
+}
+
+#[test]
+fn _0001() {
+  check_json!(Default, dec!(123.45), r#"{"value":"+12345E-2"}"#);
+  check_json!(Default, dec!(-0), r#"{"value":"-0E+0"}"#);
+  check_json!(Default, dec!(9999999999999999999999999999999999E6111), r#"{"value":"+9999999999999999999999999999999999E+6111"}"#);
+  check_json!(Default, dec!("-Inf"), r#"{"value":"-Inf"}"#);
+  check_json!(Default, dec!("NaN123"), r#"{"value":"+NaN123"}"#);
+}
+
+#[test]
+fn _0002() {
+  let value: Default = serde_json::from_str(r#"{"value":"0.0025"}"#).unwrap();
+  assert_eq!(dec!(0.0025), value.value);
+  let value: Default = serde_json::from_str(r#"{"value":"0.12345678901234567890123456789012345"}"#).unwrap();
+  assert_eq!(dec!(0.12345678901234567890123456789012345, inexact), value.value);
+}
+
+#[test]
+fn _0003() {
+  check_json_error!(Default, r#"{"value":"12,5"}"#, "unexpected characters at position 2 at line 1 column 15");
+  check_json_error!(Default, r#"{"value":""}"#, "cannot parse decimal from empty string at line 1 column 11");
+  let err = serde_json::from_str::<Default>(r#"{"value":12.5}"#).unwrap_err().to_string();
+  assert!(err.ends_with("expected a string containing a decimal number at line 1 column 13"));
+}
+
+#[test]
+fn _0004() {
+  assert_tokens(&dec!(123.45), &[Token::Str("+12345E-2")]);
+  assert_de_tokens(&dec!(123.45), &[Token::String("123.45")]);
+  assert_de_tokens(&dec!(123.45), &[Token::BorrowedStr("123.45")]);
+}
+
+#[test]
+fn _0005() {
+  let value = Bytes { value: dec!(1) };
+  const BYTES: &[u8] = &[1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x40, 0x30];
+  assert_tokens(&value, &[Token::Struct { name: "Bytes", len: 1 }, Token::Str("value"), Token::Bytes(BYTES), Token::StructEnd]);
+  assert_de_tokens(&value, &[Token::Struct { name: "Bytes", len: 1 }, Token::Str("value"), Token::ByteBuf(BYTES), Token::StructEnd]);
+  check_json!(Bytes, dec!(1), r#"{"value":[1,0,0,0,0,0,0,0,0,0,0,0,0,0,64,48]}"#);
+}
+
+#[test]
+fn _0006() {
+  assert_de_tokens_error::<Bytes>(&[Token::Struct { name: "Bytes", len: 1 }, Token::Str("value"), Token::Bytes(&[1, 2, 3])], "invalid length 3, expected 16 bytes of a decimal number");
+  check_json_error!(Bytes, r#"{"value":[1,2,3]}"#, "invalid length 3, expected 16 bytes of a decimal number at line 1 column 16");
+  check_json_error!(Bytes, r#"{"value":[0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0]}"#, "invalid length 17, expected 16 bytes of a decimal number at line 1 column 44");
+}
+
+#[test]
+#[cfg(feature = "serde-json-number")]
+fn _0007() {
+  check_json!(Number, dec!(123.45), r#"{"value":123.45}"#);
+  check_json!(Number, dec!(123.450), r#"{"value":123.450}"#);
+  check_json!(Number, dec!(-0.0025), r#"{"value":-0.0025}"#);
+  check_json!(Number, dec!(1E+300), r#"{"value":1E+300}"#);
+  check_json!(Number, dec!(0E-7), r#"{"value":0E-7}"#);
+  check_json!(Number, dec!(1234567890123456789012345678901234), r#"{"value":1234567890123456789012345678901234}"#);
+  check_json!(Number, dec!(0.1234567890123456789012345678901234), r#"{"value":0.1234567890123456789012345678901234}"#);
+}
+
+#[test]
+#[cfg(feature = "serde-json-number")]
+fn _0008() {
+  let value: Number = serde_json::from_str(r#"{"value":1e-300}"#).unwrap();
+  assert_eq!(dec!(1e-300), value.value);
+  assert_eq!("1.1", serde_json::from_str::<serde_json::Value>("1.10").unwrap().to_string());
+  check_json_error!(Number, r#"{"value":"12.5"}"#, "invalid type: \"12.5\", expected a JSON number at line 1 column 16");
+  assert_eq!("infinity and NaN can not be serialized as a number", serde_json::to_string(&Number { value: dec!("NaN") }).unwrap_err().to_string());
+  assert_eq!("infinity and NaN can not be serialized as a number", serde_json::to_string(&Number { value: dec!("-Inf") }).unwrap_err().to_string());
+}